@@ -0,0 +1,87 @@
+use crate::error::Error;
+use crate::GenContext;
+
+/// Continuation odds for collections: each element is followed by another
+/// unless a draw in [0, 8) comes up zero, giving eight elements on average.
+/// Exhausted input reads zeros, so collections end once the bytes run out.
+const CONTINUE_BOUND: u64 = 8;
+
+/// Types that can be built from the draws of a `GenContext`. Defining
+/// generators against this trait lets the same definitions run from a seed
+/// in property tests and from fuzzer input via `build_from_bytes`.
+pub trait FromChoices: Sized {
+    fn from_choices(ctx: &mut GenContext) -> Result<Self, Error>;
+}
+
+/// Build a value from raw bytes, eight per draw:
+///
+/// ```
+/// use hedgehog_splitmix_wasm::build_from_bytes;
+///
+/// // Continue the list, then draw 42, Some and true; the exhausted input
+/// // then reads zeros, which end the list
+/// let mut bytes = Vec::new();
+/// for draw in [u64::MAX, 42, 1, 1] {
+///     bytes.extend_from_slice(&draw.to_le_bytes());
+/// }
+/// let pairs = build_from_bytes::<Vec<(u32, Option<bool>)>>(&bytes).unwrap();
+/// assert_eq!(pairs, vec![(42, Some(true))]);
+/// assert!(build_from_bytes::<Vec<(u32, Option<bool>)>>(&[]).unwrap().is_empty());
+/// ```
+///
+/// Inside a cargo-fuzz harness:
+///
+/// ```ignore
+/// fuzz_target!(|data: &[u8]| {
+///     if let Ok(input) = build_from_bytes::<Vec<u32>>(data) {
+///         check_property(input);
+///     }
+/// });
+/// ```
+pub fn build_from_bytes<T: FromChoices>(data: &[u8]) -> Result<T, Error> {
+    T::from_choices(&mut GenContext::from_bytes(data))
+}
+
+macro_rules! from_choices_int {
+    ($($ty:ty),*) => {
+        $(impl FromChoices for $ty {
+            fn from_choices(ctx: &mut GenContext) -> Result<Self, Error> {
+                Ok(ctx.next_u64()? as $ty)
+            }
+        })*
+    };
+}
+
+from_choices_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl FromChoices for bool {
+    fn from_choices(ctx: &mut GenContext) -> Result<Self, Error> {
+        ctx.next_bool()
+    }
+}
+
+impl<T: FromChoices> FromChoices for Option<T> {
+    fn from_choices(ctx: &mut GenContext) -> Result<Self, Error> {
+        if ctx.next_bool()? {
+            Ok(Some(T::from_choices(ctx)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<T: FromChoices> FromChoices for Vec<T> {
+    fn from_choices(ctx: &mut GenContext) -> Result<Self, Error> {
+        let mut items = Vec::new();
+        while ctx.next_bounded(CONTINUE_BOUND)? != 0 {
+            items.push(T::from_choices(ctx)?);
+        }
+        Ok(items)
+    }
+}
+
+impl<A: FromChoices, B: FromChoices> FromChoices for (A, B) {
+    fn from_choices(ctx: &mut GenContext) -> Result<Self, Error> {
+        Ok((A::from_choices(ctx)?, B::from_choices(ctx)?))
+    }
+}
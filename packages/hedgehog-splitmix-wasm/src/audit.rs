@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::Seed;
+
+/// Most seeds one audit will visit, keeping memory for the sorted state
+/// and gamma columns around 64MB
+const MAX_AUDIT_SEEDS: u64 = 1 << 22;
+
+/// Fewest bit transitions a gamma needs to be considered strong; gammas with
+/// fewer produce visibly correlated low-order bits (the threshold used by
+/// Java's SplittableRandom)
+const MIN_GAMMA_TRANSITIONS: u32 = 24;
+
+/// Whether `gamma` is even or has too few adjacent bit changes
+fn is_weak_gamma(gamma: u64) -> bool {
+    gamma & 1 == 0 || (gamma ^ (gamma >> 1)).count_ones() < MIN_GAMMA_TRANSITIONS
+}
+
+/// Number of entries of a sorted slice that repeat an earlier entry
+fn count_repeats<T: PartialEq>(sorted: &[T]) -> u64 {
+    sorted.windows(2).filter(|pair| pair[0] == pair[1]).count() as u64
+}
+
+/// Collision statistics from `audit_splits`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct SplitAudit {
+    seeds: u64,
+    state_collisions: u64,
+    gamma_collisions: u64,
+    stream_collisions: u64,
+    weak_gammas: u64,
+}
+
+#[wasm_bindgen]
+impl SplitAudit {
+    /// Seeds visited, the root included
+    #[wasm_bindgen(getter)]
+    pub fn seeds(&self) -> u64 {
+        self.seeds
+    }
+
+    /// Seeds whose state repeats an earlier seed's state
+    #[wasm_bindgen(getter)]
+    pub fn state_collisions(&self) -> u64 {
+        self.state_collisions
+    }
+
+    /// Seeds whose gamma repeats an earlier seed's gamma
+    #[wasm_bindgen(getter)]
+    pub fn gamma_collisions(&self) -> u64 {
+        self.gamma_collisions
+    }
+
+    /// Seeds identical in both state and gamma to an earlier seed, which
+    /// produce exactly the same stream
+    #[wasm_bindgen(getter)]
+    pub fn stream_collisions(&self) -> u64 {
+        self.stream_collisions
+    }
+
+    /// Seeds whose gamma is even or has fewer than 24 bit transitions
+    #[wasm_bindgen(getter)]
+    pub fn weak_gammas(&self) -> u64 {
+        self.weak_gammas
+    }
+
+    /// State collisions expected by chance among this many uniformly
+    /// random states (the birthday bound n(n-1)/2^65)
+    #[wasm_bindgen(getter)]
+    pub fn expected_state_collisions(&self) -> f64 {
+        let n = self.seeds as f64;
+        n * (n - 1.0) / 2f64.powi(65)
+    }
+
+    /// Whether no two seeds share a state or a whole stream
+    #[wasm_bindgen(getter)]
+    pub fn passed(&self) -> bool {
+        self.state_collisions == 0 && self.stream_collisions == 0
+    }
+}
+
+/// Walk the split tree below `root_seed`, where every seed is split
+/// `breadth` times (continuing from the left seed each time) down to `depth`
+/// levels, and check every seed for state and gamma collisions and weak
+/// gammas. Visits `1 + breadth + ... + breadth^depth` seeds, at most 2^22.
+#[wasm_bindgen]
+pub fn audit_splits(root_seed: &Seed, depth: u32, breadth: u32) -> Result<SplitAudit, Error> {
+    if breadth == 0 {
+        return Err(Error::invalid_parameter("breadth", "0"));
+    }
+    let mut total: u64 = 1;
+    let mut level: u64 = 1;
+    for _ in 0..depth {
+        level = level.saturating_mul(breadth as u64);
+        total = total.saturating_add(level);
+        if total > MAX_AUDIT_SEEDS {
+            return Err(Error::invalid_parameter(
+                "depth",
+                &format!("{depth} at breadth {breadth} exceeds {MAX_AUDIT_SEEDS} seeds"),
+            ));
+        }
+    }
+
+    let mut streams = Vec::with_capacity(total as usize);
+    let mut pending = vec![(root_seed.clone(), 0)];
+    while let Some((seed, level)) = pending.pop() {
+        streams.push((seed.state, seed.gamma));
+        if level < depth {
+            let mut current = seed;
+            for _ in 0..breadth {
+                let pair = current.split();
+                pending.push((pair.right, level + 1));
+                current = pair.left;
+            }
+        }
+    }
+
+    let weak_gammas = streams
+        .iter()
+        .filter(|(_, gamma)| is_weak_gamma(*gamma))
+        .count() as u64;
+    // Sorting by (state, gamma) also leaves the states in order
+    streams.sort_unstable();
+    let stream_collisions = count_repeats(&streams);
+    let mut column: Vec<u64> = streams.iter().map(|(state, _)| *state).collect();
+    let state_collisions = count_repeats(&column);
+    for (slot, (_, gamma)) in column.iter_mut().zip(&streams) {
+        *slot = *gamma;
+    }
+    column.sort_unstable();
+    let gamma_collisions = count_repeats(&column);
+
+    Ok(SplitAudit {
+        seeds: streams.len() as u64,
+        state_collisions,
+        gamma_collisions,
+        stream_collisions,
+        weak_gammas,
+    })
+}
+
+/// One property's seed as recorded by `SeedIsolationAudit`
+#[derive(Debug, Clone)]
+struct PropertySeed {
+    property: String,
+    derivation: String,
+    seed: Seed,
+    /// Earlier record of another property with the same stream
+    reused_from: Option<usize>,
+}
+
+/// Record of the seed every property in a suite run started from, flagging
+/// accidental reuse: two different properties whose seeds share both state
+/// and gamma explore exactly the same stream, which usually means a seed
+/// was constructed by hand instead of derived from the run's root seed.
+/// Draw counters are not compared.
+#[derive(Debug, Clone, Default)]
+#[wasm_bindgen]
+pub struct SeedIsolationAudit {
+    records: Vec<PropertySeed>,
+    /// First record of each (state, gamma) stream per property name
+    first_use: HashMap<(u64, u64), Vec<usize>>,
+}
+
+#[wasm_bindgen]
+impl SeedIsolationAudit {
+    /// Create an empty audit
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> SeedIsolationAudit {
+        SeedIsolationAudit::default()
+    }
+
+    /// Record that `property` ran from `seed`, with `derivation` describing
+    /// how the seed was obtained, e.g. `"root 42, split 3"` or `"manual"`.
+    /// Returns the name of an earlier, different property that used the
+    /// same seed, or `undefined`. Rerunning one property from the same seed
+    /// is not reuse.
+    pub fn record(&mut self, property: &str, seed: &Seed, derivation: &str) -> Option<String> {
+        let index = self.records.len();
+        let users = self.first_use.entry((seed.state, seed.gamma)).or_default();
+        let reused_from = users
+            .iter()
+            .copied()
+            .find(|&earlier| self.records[earlier].property != property);
+        if users
+            .iter()
+            .all(|&earlier| self.records[earlier].property != property)
+        {
+            users.push(index);
+        }
+        self.records.push(PropertySeed {
+            property: property.to_string(),
+            derivation: derivation.to_string(),
+            seed: Seed::from_parts(seed.state, seed.gamma),
+            reused_from,
+        });
+        reused_from.map(|earlier| self.records[earlier].property.clone())
+    }
+
+    /// Number of records
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Records whose seed an earlier, different property already used
+    #[wasm_bindgen(getter)]
+    pub fn reused(&self) -> usize {
+        self.records
+            .iter()
+            .filter(|record| record.reused_from.is_some())
+            .count()
+    }
+
+    /// Whether every property ran from a seed of its own
+    #[wasm_bindgen(getter)]
+    pub fn passed(&self) -> bool {
+        self.reused() == 0
+    }
+
+    /// Property name of record `index`
+    pub fn property(&self, index: usize) -> Option<String> {
+        self.records
+            .get(index)
+            .map(|record| record.property.clone())
+    }
+
+    /// Seed derivation of record `index`, as passed to `record`
+    pub fn derivation(&self, index: usize) -> Option<String> {
+        self.records
+            .get(index)
+            .map(|record| record.derivation.clone())
+    }
+
+    /// Seed of record `index`
+    pub fn seed(&self, index: usize) -> Option<Seed> {
+        self.records.get(index).map(|record| record.seed.clone())
+    }
+
+    /// Index of the earlier record whose seed record `index` reused
+    pub fn reused_from(&self, index: usize) -> Option<usize> {
+        self.records
+            .get(index)
+            .and_then(|record| record.reused_from)
+    }
+
+    /// One line per reused seed naming both properties and how each seed
+    /// was derived, empty when the audit passed
+    pub fn report(&self) -> String {
+        let mut report = String::new();
+        for record in &self.records {
+            if let Some(earlier) = record.reused_from.map(|index| &self.records[index]) {
+                report.push_str(&format!(
+                    "{} ({}) reuses the seed of {} ({}): state {:#018x}, gamma {:#018x}\n",
+                    record.property,
+                    record.derivation,
+                    earlier.property,
+                    earlier.derivation,
+                    record.seed.state,
+                    record.seed.gamma,
+                ));
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,53 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::{Seed, SeedAndU32};
+
+/// Largest Unicode code point
+const MAX_CODEPOINT: u32 = 0x10ffff;
+
+#[wasm_bindgen]
+impl Seed {
+    /// Pick a code point uniformly across a set of inclusive ranges, given
+    /// flattened as `[lo0, hi0, lo1, hi1, ...]`, using a single draw. Ranges
+    /// are taken as given, so surrogates are only produced if included.
+    #[wasm_bindgen]
+    pub fn next_char_in(&self, ranges: &[u32]) -> Result<SeedAndU32, Error> {
+        if ranges.is_empty() || ranges.len() % 2 != 0 {
+            return Err(Error::invalid_parameter(
+                "ranges",
+                &format!("{} bounds", ranges.len()),
+            ));
+        }
+        for pair in ranges.chunks(2) {
+            let (lo, hi) = (pair[0], pair[1]);
+            if lo > hi || hi > MAX_CODEPOINT {
+                return Err(Error::invalid_parameter(
+                    "range",
+                    &format!("[{lo:#x}, {hi:#x}]"),
+                ));
+            }
+        }
+
+        let total: u64 = ranges
+            .chunks(2)
+            .map(|pair| (pair[1] - pair[0]) as u64 + 1)
+            .sum();
+        let result = self.next_bounded(total);
+
+        let mut offset = result.value;
+        let mut value = ranges[ranges.len() - 1];
+        for pair in ranges.chunks(2) {
+            let width = (pair[1] - pair[0]) as u64 + 1;
+            if offset < width {
+                value = pair[0] + offset as u32;
+                break;
+            }
+            offset -= width;
+        }
+        Ok(SeedAndU32 {
+            seed: result.seed,
+            value,
+        })
+    }
+}
@@ -0,0 +1,203 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{Seed, SeedAndBytes};
+
+/// Number of bytes consumed by each draw from a choice stream
+pub(crate) const WORD_SIZE: usize = 8;
+
+/// Most zero-padded draws a restored stream may already have made past the
+/// end of its input; checkpoints claiming more are rejected as corrupt
+pub(crate) const MAX_OVERRUN_WORDS: usize = 1 << 24;
+
+/// Cursor over a byte choice stream. Every draw consumes one little-endian
+/// 64-bit word; reads past the end yield zero bytes, so truncated inputs
+/// produce the simplest values rather than failing.
+pub(crate) struct ChoiceStream {
+    pub(crate) bytes: Vec<u8>,
+    pub(crate) position: usize,
+}
+
+impl ChoiceStream {
+    pub(crate) fn new(bytes: Vec<u8>) -> ChoiceStream {
+        ChoiceStream { bytes, position: 0 }
+    }
+
+    /// Consume the next word, zero-padding past the end of the input
+    pub(crate) fn next_word(&mut self) -> u64 {
+        let mut word = [0u8; WORD_SIZE];
+        let start = self.position.min(self.bytes.len());
+        let end = self
+            .position
+            .saturating_add(WORD_SIZE)
+            .min(self.bytes.len());
+        word[..end - start].copy_from_slice(&self.bytes[start..end]);
+        self.position = self.position.saturating_add(WORD_SIZE);
+        u64::from_le_bytes(word)
+    }
+
+    /// Whether any draw has read past the end of the input
+    pub(crate) fn overrun(&self) -> bool {
+        self.position > self.bytes.len()
+    }
+}
+
+/// Largest number of words a single mutation touches
+const MAX_BLOCK_WORDS: usize = 8;
+
+/// Structured mutations over a choice stream. All of them work on whole
+/// 8-byte words so each mutation lines up with individual draws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum MutationOp {
+    /// Zero a run of consecutive words, simplifying the draws they feed
+    ZeroBlock = 0,
+    /// Replace one word with a uniformly smaller value
+    ShrinkDraw = 1,
+    /// Insert a copy of a run of words directly after it
+    DuplicateSection = 2,
+    /// Exchange two non-overlapping runs of words of equal length
+    SwapBlocks = 3,
+}
+
+pub(crate) const MUTATION_OPS: [MutationOp; 4] = [
+    MutationOp::ZeroBlock,
+    MutationOp::ShrinkDraw,
+    MutationOp::DuplicateSection,
+    MutationOp::SwapBlocks,
+];
+
+/// Return type for choice-stream mutations
+#[wasm_bindgen]
+pub struct MutatedChoices {
+    seed: Seed,
+    bytes: Vec<u8>,
+    op: MutationOp,
+}
+
+#[wasm_bindgen]
+impl MutatedChoices {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn op(&self) -> MutationOp {
+        self.op
+    }
+}
+
+/// Draw an index in [0, n) for n > 0
+pub(crate) fn below(seed: &mut Seed, n: usize) -> usize {
+    ((seed.step() as u128 * n as u128) >> 64) as usize
+}
+
+/// Split `bytes` into little-endian words, zero-padding the last one
+pub(crate) fn to_words(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks(WORD_SIZE)
+        .map(|chunk| {
+            let mut word = [0u8; WORD_SIZE];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(word)
+        })
+        .collect()
+}
+
+pub(crate) fn from_words(words: &[u64]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/// Apply `op` to `words` in place, drawing positions from `seed`.
+/// Inputs too short for the operation are left unchanged.
+pub(crate) fn mutate_words(seed: &mut Seed, words: &mut Vec<u64>, op: MutationOp) {
+    let n = words.len();
+    if n == 0 {
+        return;
+    }
+
+    match op {
+        MutationOp::ZeroBlock => {
+            let start = below(seed, n);
+            let len = 1 + below(seed, (n - start).min(MAX_BLOCK_WORDS));
+            words[start..start + len].fill(0);
+        }
+        MutationOp::ShrinkDraw => {
+            let index = below(seed, n);
+            let word = words[index];
+            if word > 0 {
+                words[index] = ((seed.step() as u128 * word as u128) >> 64) as u64;
+            }
+        }
+        MutationOp::DuplicateSection => {
+            let start = below(seed, n);
+            let len = 1 + below(seed, (n - start).min(MAX_BLOCK_WORDS));
+            let section = words[start..start + len].to_vec();
+            words.splice(start + len..start + len, section);
+        }
+        MutationOp::SwapBlocks => {
+            if n < 2 {
+                return;
+            }
+            let len = 1 + below(seed, (n / 2).min(MAX_BLOCK_WORDS));
+            let first = below(seed, n - 2 * len + 1);
+            let second = first + len + below(seed, n - first - 2 * len + 1);
+            let (head, tail) = words.split_at_mut(second);
+            head[first..first + len].swap_with_slice(&mut tail[..len]);
+        }
+    }
+}
+
+/// Child of two word streams: a prefix of `first` followed by a suffix of
+/// `second`, each cut at a randomly chosen word boundary
+pub(crate) fn crossover_words(seed: &mut Seed, first: &[u64], second: &[u64]) -> Vec<u64> {
+    let head = below(seed, first.len() + 1);
+    let tail = below(seed, second.len() + 1);
+    let mut child = first[..head].to_vec();
+    child.extend_from_slice(&second[tail..]);
+    child
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Apply one randomly chosen structured mutation to a recorded choice
+    /// stream, e.g. to explore around an input that found a bug or new
+    /// coverage. The result is padded to a whole number of 8-byte words.
+    #[wasm_bindgen]
+    pub fn mutate_choices(&self, bytes: &[u8]) -> MutatedChoices {
+        let mut seed = self.clone();
+        let op = MUTATION_OPS[below(&mut seed, MUTATION_OPS.len())];
+        seed.mutate_choices_with(bytes, op)
+    }
+
+    /// Apply the mutation `op` to a recorded choice stream
+    #[wasm_bindgen]
+    pub fn mutate_choices_with(&self, bytes: &[u8], op: MutationOp) -> MutatedChoices {
+        let mut seed = self.clone();
+        let mut words = to_words(bytes);
+        mutate_words(&mut seed, &mut words, op);
+        MutatedChoices {
+            seed,
+            bytes: from_words(&words),
+            op,
+        }
+    }
+
+    /// Splice two recorded choice streams into a child: a prefix of `first`
+    /// followed by a suffix of `second`, cut at 8-byte word boundaries so
+    /// every draw in the child comes whole from one parent
+    #[wasm_bindgen]
+    pub fn crossover_choices(&self, first: &[u8], second: &[u8]) -> SeedAndBytes {
+        let mut seed = self.clone();
+        let child = crossover_words(&mut seed, &to_words(first), &to_words(second));
+        SeedAndBytes {
+            seed,
+            bytes: from_words(&child),
+        }
+    }
+}
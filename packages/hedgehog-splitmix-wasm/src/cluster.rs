@@ -0,0 +1,208 @@
+use wasm_bindgen::prelude::*;
+
+use crate::choice::{from_words, to_words};
+use crate::encoding::{fnv1a, read_bytes, read_u8, read_varint, write_varint};
+use crate::error::Error;
+
+/// Magic bytes at the start of exported failure clusters
+const CLUSTERS_MAGIC: &[u8; 4] = b"HHFC";
+
+/// Current cluster encoding version
+const CLUSTERS_VERSION: u8 = 1;
+
+/// Words of a choice stream with trailing zero words dropped, since reads
+/// past the end yield zero anyway
+fn trimmed_words(bytes: &[u8]) -> Vec<u64> {
+    let mut words = to_words(bytes);
+    let used = words
+        .iter()
+        .rposition(|&word| word != 0)
+        .map_or(0, |i| i + 1);
+    words.truncate(used);
+    words
+}
+
+/// Structural fingerprint of a choice stream: a hash of the bit length of
+/// every word, trailing zero words dropped. Counterexamples that differ only
+/// in the magnitude of their draws share a fingerprint, while ones that take
+/// different paths through a generator usually do not.
+#[wasm_bindgen]
+pub fn fingerprint_choices(bytes: &[u8]) -> u64 {
+    let shape: Vec<u8> = trimmed_words(bytes)
+        .iter()
+        .map(|word| (u64::BITS - word.leading_zeros()) as u8)
+        .collect();
+    fnv1a(&shape)
+}
+
+/// Failures sharing one fingerprint
+#[derive(Debug, Clone)]
+struct Cluster {
+    fingerprint: u64,
+    occurrences: u64,
+    /// Simplest counterexample seen: fewest words, then smallest words
+    representative: Vec<u64>,
+}
+
+impl Cluster {
+    /// Append the cluster as `[varint fingerprint][varint occurrences]
+    /// [varint length][bytes]`
+    fn write(&self, out: &mut Vec<u8>) {
+        let bytes = from_words(&self.representative);
+        write_varint(out, self.fingerprint);
+        write_varint(out, self.occurrences);
+        write_varint(out, bytes.len() as u64);
+        out.extend_from_slice(&bytes);
+    }
+
+    fn read(bytes: &[u8], position: &mut usize) -> Result<Cluster, Error> {
+        let fingerprint = read_varint(bytes, position)?;
+        let occurrences = read_varint(bytes, position)?;
+        let length = read_varint(bytes, position)? as usize;
+        let representative = to_words(read_bytes(bytes, position, length)?);
+        Ok(Cluster {
+            fingerprint,
+            occurrences,
+            representative,
+        })
+    }
+
+    /// Count `occurrences` more failures, keeping the simpler counterexample
+    fn merge(&mut self, occurrences: u64, representative: Vec<u64>) {
+        self.occurrences = self.occurrences.saturating_add(occurrences);
+        let simpler = (representative.len(), &representative)
+            < (self.representative.len(), &self.representative);
+        if simpler {
+            self.representative = representative;
+        }
+    }
+}
+
+/// Minimal counterexamples grouped by `fingerprint_choices` across runs, so
+/// a recurring bug shows up as one cluster with a growing count rather than
+/// as many distinct failures. Clusters keep first-seen order.
+#[derive(Debug, Clone, Default)]
+#[wasm_bindgen]
+pub struct FailureClusters {
+    clusters: Vec<Cluster>,
+}
+
+impl FailureClusters {
+    /// Merge into the cluster with the same fingerprint, or start a new
+    /// one, returning its index
+    fn merge(&mut self, fingerprint: u64, occurrences: u64, representative: Vec<u64>) -> usize {
+        match self
+            .clusters
+            .iter()
+            .position(|cluster| cluster.fingerprint == fingerprint)
+        {
+            Some(index) => {
+                self.clusters[index].merge(occurrences, representative);
+                index
+            }
+            None => {
+                self.clusters.push(Cluster {
+                    fingerprint,
+                    occurrences,
+                    representative,
+                });
+                self.clusters.len() - 1
+            }
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl FailureClusters {
+    /// Create an empty set of clusters
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> FailureClusters {
+        FailureClusters::default()
+    }
+
+    /// Record the minimal choice stream of a failure, returning the index
+    /// of its cluster
+    pub fn add(&mut self, choices: &[u8]) -> usize {
+        self.merge(fingerprint_choices(choices), 1, trimmed_words(choices))
+    }
+
+    /// Number of distinct clusters
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.clusters.len()
+    }
+
+    /// Fingerprint shared by the failures of cluster `index`
+    pub fn fingerprint(&self, index: usize) -> Option<u64> {
+        self.clusters.get(index).map(|cluster| cluster.fingerprint)
+    }
+
+    /// Number of failures recorded in cluster `index`
+    pub fn occurrences(&self, index: usize) -> Option<u64> {
+        self.clusters.get(index).map(|cluster| cluster.occurrences)
+    }
+
+    /// Simplest choice stream recorded in cluster `index`, trailing zero
+    /// words dropped; replay it with `GenContext.from_bytes`
+    pub fn representative(&self, index: usize) -> Option<Vec<u8>> {
+        self.clusters
+            .get(index)
+            .map(|cluster| from_words(&cluster.representative))
+    }
+
+    /// Export every cluster as one buffer:
+    ///
+    /// ```text
+    /// [4 bytes "HHFC"][1 byte version = 1][varint cluster count]
+    /// [varint fingerprint][varint occurrences][varint length][bytes]...
+    /// ```
+    pub fn export(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(CLUSTERS_MAGIC);
+        out.push(CLUSTERS_VERSION);
+        write_varint(&mut out, self.clusters.len() as u64);
+        for cluster in &self.clusters {
+            cluster.write(&mut out);
+        }
+        out
+    }
+
+    /// Merge the clusters of a buffer produced by `export`, e.g. from an
+    /// earlier run, adding up occurrences of shared fingerprints. Returns
+    /// the number of new clusters; nothing is merged if the buffer is
+    /// malformed.
+    pub fn import(&mut self, bytes: &[u8]) -> Result<usize, Error> {
+        if bytes.len() < CLUSTERS_MAGIC.len() || &bytes[..CLUSTERS_MAGIC.len()] != CLUSTERS_MAGIC {
+            return Err(Error::invalid_encoding("missing failure clusters header"));
+        }
+        let mut position = CLUSTERS_MAGIC.len();
+
+        let version = read_u8(bytes, &mut position)?;
+        if version != CLUSTERS_VERSION {
+            return Err(Error::invalid_encoding(&format!(
+                "unsupported failure clusters version {version}"
+            )));
+        }
+
+        let count = read_varint(bytes, &mut position)?;
+        let mut clusters = Vec::new();
+        for _ in 0..count {
+            clusters.push(Cluster::read(bytes, &mut position)?);
+        }
+
+        if position != bytes.len() {
+            return Err(Error::invalid_encoding(
+                "trailing bytes after failure clusters",
+            ));
+        }
+        let before = self.clusters.len();
+        for cluster in clusters {
+            self.merge(
+                cluster.fingerprint,
+                cluster.occurrences,
+                cluster.representative,
+            );
+        }
+        Ok(self.clusters.len() - before)
+    }
+}
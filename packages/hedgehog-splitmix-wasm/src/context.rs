@@ -0,0 +1,764 @@
+use js_sys::{Array, Function, Object, JSON};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+use crate::choice::{ChoiceStream, MAX_OVERRUN_WORDS, WORD_SIZE};
+use crate::encoding::{read_bytes, read_u8, read_varint, write_varint};
+use crate::error::Error;
+use crate::trace::{read_draws, write_draws, Divergence, Draw, DrawOp, Trace};
+use crate::Seed;
+
+/// Magic bytes at the start of a checkpoint token
+const CHECKPOINT_MAGIC: &[u8; 4] = b"HHCK";
+
+/// Current checkpoint encoding version
+const CHECKPOINT_VERSION: u8 = 1;
+
+/// Shared recording buffer; contexts split from a recording context append
+/// to the same buffer so the trace preserves the interleaving of draws
+type Recorder = Rc<RefCell<Vec<Draw>>>;
+
+/// Cursor over a recorded trace, shared between a replaying context and the
+/// contexts split from it
+struct Replay {
+    draws: Vec<Draw>,
+    position: usize,
+}
+
+impl Replay {
+    /// Serve the next recorded result, failing if the requested operation
+    /// does not match the recording
+    fn next(&mut self, op: DrawOp, bound: u64) -> Result<u64, Error> {
+        let index = self.position;
+        let draw = self
+            .draws
+            .get(index)
+            .ok_or_else(|| Error::trace_divergence(index, "end of trace", &describe(op, bound)))?;
+
+        if draw.op != op || draw.bound != bound {
+            return Err(Error::trace_divergence(
+                index,
+                &describe(draw.op, draw.bound),
+                &describe(op, bound),
+            ));
+        }
+
+        self.position += 1;
+        Ok(draw.result)
+    }
+}
+
+/// Compares a live run against a recorded trace, keeping the first
+/// divergence; shared between a verifying context and its split children
+struct Verifier {
+    draws: Vec<Draw>,
+    position: usize,
+    divergence: Option<Divergence>,
+}
+
+impl Verifier {
+    fn observe(&mut self, draw: &Draw) {
+        if self.divergence.is_none() {
+            self.divergence =
+                Divergence::check(self.position, self.draws.get(self.position), Some(draw));
+        }
+        self.position += 1;
+    }
+
+    /// First divergence so far, or the end of the live run if it stopped
+    /// before consuming the whole recording
+    fn divergence(&self) -> Option<Divergence> {
+        self.divergence
+            .clone()
+            .or_else(|| Divergence::check(self.position, self.draws.get(self.position), None))
+    }
+}
+
+/// JavaScript callback invoked for every `sample_every`-th draw; shared
+/// between a context and its split children
+struct Audit {
+    callback: Function,
+    sample_every: u64,
+    index: u64,
+}
+
+/// Invoke the audit callback if this draw is sampled. The borrow is released
+/// before calling into JavaScript so the callback may inspect the context.
+fn audit(audit: &RefCell<Audit>, draw: &Draw) -> Result<(), Error> {
+    let (callback, index) = {
+        let mut audit = audit.borrow_mut();
+        let index = audit.index;
+        audit.index += 1;
+        if index % audit.sample_every != 0 {
+            return Ok(());
+        }
+        (audit.callback.clone(), index)
+    };
+
+    let args = Array::of4(
+        &JsValue::from(index),
+        &JsValue::from(draw.op as u8),
+        &JsValue::from(draw.bound),
+        &JsValue::from(draw.result),
+    );
+    callback
+        .apply(&JsValue::NULL, &args)
+        .map(|_| ())
+        .map_err(|err| Error::callback_failed("audit", &format!("{err:?}")))
+}
+
+fn describe(op: DrawOp, bound: u64) -> String {
+    match op {
+        DrawOp::Bounded => format!("{op:?}({bound})"),
+        _ => format!("{op:?}"),
+    }
+}
+
+/// Where a stepping context rewinds to on every `step`
+enum Rewind {
+    Seed(Seed),
+    Position(usize),
+}
+
+/// Draw-by-draw gate for interactive debugging, shared between a stepping
+/// context and its split children so they draw from one budget
+struct Stepper {
+    start: Rewind,
+    /// Length of the recording when stepping started
+    recorded: usize,
+    /// Draws allowed to complete in each pass
+    granted: u64,
+    /// Draws completed in the current pass
+    completed: u64,
+    pending: Option<(DrawOp, u64)>,
+    last: Option<Draw>,
+}
+
+/// Where a context's values come from
+enum Source {
+    /// Fresh values from the SplitMix64 stream
+    Rng(Seed),
+    /// Values served from a recorded trace
+    Replay(Rc<RefCell<Replay>>),
+    /// Values decoded from a byte choice stream
+    Bytes(Rc<RefCell<ChoiceStream>>),
+}
+
+/// Mutable generation context wrapping a seed. Every draw advances the
+/// context in place and can optionally be recorded into a trace.
+#[wasm_bindgen]
+pub struct GenContext {
+    source: Source,
+    /// Seed the context started from, used to derive named sub-streams;
+    /// `None` when replaying
+    root: Option<Seed>,
+    recorder: Option<Recorder>,
+    verifier: Option<Rc<RefCell<Verifier>>>,
+    audit: Option<Rc<RefCell<Audit>>>,
+    stepper: Option<Rc<RefCell<Stepper>>>,
+}
+
+impl GenContext {
+    /// Let a draw through if stepping allows it, otherwise pause before it
+    /// without advancing the stream
+    fn gate(&self, op: DrawOp, bound: u64) -> Result<(), Error> {
+        if let Some(stepper) = &self.stepper {
+            let mut stepper = stepper.borrow_mut();
+            if stepper.completed == stepper.granted {
+                stepper.pending = Some((op, bound));
+                return Err(Error::step_pending(stepper.completed, &describe(op, bound)));
+            }
+            stepper.completed += 1;
+            stepper.pending = None;
+        }
+        Ok(())
+    }
+
+    /// Perform a single value-producing draw, recording it when recording is
+    /// enabled
+    fn draw(&mut self, op: DrawOp, bound: u64) -> Result<u64, Error> {
+        self.gate(op, bound)?;
+        let result = match &mut self.source {
+            Source::Rng(seed) => {
+                let result = match op {
+                    DrawOp::Bounded => seed.next_bounded(bound),
+                    _ => seed.next_u64(),
+                };
+                *seed = result.seed();
+                match op {
+                    DrawOp::Bool => result.value() & 1,
+                    _ => result.value(),
+                }
+            }
+            Source::Replay(replay) => replay.borrow_mut().next(op, bound)?,
+            Source::Bytes(stream) => {
+                let word = stream.borrow_mut().next_word();
+                match op {
+                    DrawOp::Bounded => ((word as u128 * bound as u128) >> 64) as u64,
+                    DrawOp::Bool => word & 1,
+                    _ => word,
+                }
+            }
+        };
+        self.record(op, bound, result)?;
+        Ok(result)
+    }
+
+    /// Pass a completed draw to the verifier, recorder and audit callback
+    fn record(&self, op: DrawOp, bound: u64, result: u64) -> Result<(), Error> {
+        let draw = Draw { op, bound, result };
+        if let Some(verifier) = &self.verifier {
+            verifier.borrow_mut().observe(&draw);
+        }
+        if let Some(recorder) = &self.recorder {
+            recorder.borrow_mut().push(draw);
+        }
+        if let Some(stepper) = &self.stepper {
+            stepper.borrow_mut().last = Some(draw);
+        }
+        if let Some(state) = &self.audit {
+            audit(state, &draw)?;
+        }
+        Ok(())
+    }
+}
+
+#[wasm_bindgen]
+impl GenContext {
+    /// Create a context starting from `seed`
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: &Seed) -> GenContext {
+        GenContext {
+            source: Source::Rng(seed.clone()),
+            root: Some(seed.clone()),
+            recorder: None,
+            verifier: None,
+            audit: None,
+            stepper: None,
+        }
+    }
+
+    /// Create a context that generates fresh values from `seed` while
+    /// comparing each draw against `trace`. Unlike replay, a mismatch does
+    /// not fail the draw; the first one is reported by `divergence`.
+    pub fn verifying(seed: &Seed, trace: &Trace) -> GenContext {
+        GenContext {
+            source: Source::Rng(seed.clone()),
+            root: Some(seed.clone()),
+            recorder: None,
+            verifier: Some(Rc::new(RefCell::new(Verifier {
+                draws: trace.draws().to_vec(),
+                position: 0,
+                divergence: None,
+            }))),
+            audit: None,
+            stepper: None,
+        }
+    }
+
+    /// Create a context that serves values from a recorded trace instead of
+    /// the RNG. Any draw whose operation or bound differs from the recording
+    /// fails with a `TraceDivergence` error.
+    pub fn replay(trace: &Trace) -> GenContext {
+        GenContext {
+            source: Source::Replay(Rc::new(RefCell::new(Replay {
+                draws: trace.draws().to_vec(),
+                position: 0,
+            }))),
+            root: None,
+            recorder: None,
+            verifier: None,
+            audit: None,
+            stepper: None,
+        }
+    }
+
+    /// Create a context whose draws all decode an underlying byte stream,
+    /// e.g. a fuzzer's input. Each draw consumes 8 bytes as a little-endian
+    /// u64 (bounded draws scale it into range, booleans use the low bit);
+    /// once the input is exhausted draws read zeros. Contexts split from it
+    /// continue reading the same stream.
+    pub fn from_bytes(bytes: &[u8]) -> GenContext {
+        GenContext {
+            source: Source::Bytes(Rc::new(RefCell::new(ChoiceStream::new(bytes.to_vec())))),
+            root: None,
+            recorder: None,
+            verifier: None,
+            audit: None,
+            stepper: None,
+        }
+    }
+
+    /// Create a byte-stream context over `length` choice bytes produced by
+    /// the RNG from `seed`, so the same generators run identically whether
+    /// fed by SplitMix64 or by external bytes
+    pub fn from_seed_bytes(seed: &Seed, length: u32) -> GenContext {
+        GenContext::from_bytes(&seed.clone().step_bytes(length as usize))
+    }
+
+    /// Current seed, or `undefined` when values come from a trace or bytes
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Option<Seed> {
+        match &self.source {
+            Source::Rng(seed) => Some(seed.clone()),
+            _ => None,
+        }
+    }
+
+    /// Underlying choice bytes for contexts created with `from_bytes` or
+    /// `from_seed_bytes`
+    #[wasm_bindgen(getter)]
+    pub fn choice_bytes(&self) -> Option<Vec<u8>> {
+        match &self.source {
+            Source::Bytes(stream) => Some(stream.borrow().bytes.clone()),
+            _ => None,
+        }
+    }
+
+    /// Number of choice bytes consumed so far (0 for other contexts)
+    #[wasm_bindgen(getter)]
+    pub fn bytes_consumed(&self) -> u32 {
+        match &self.source {
+            Source::Bytes(stream) => stream.borrow().position as u32,
+            _ => 0,
+        }
+    }
+
+    /// Whether draws have read past the end of the choice bytes
+    #[wasm_bindgen(getter)]
+    pub fn overrun(&self) -> bool {
+        match &self.source {
+            Source::Bytes(stream) => stream.borrow().overrun(),
+            _ => false,
+        }
+    }
+
+    /// Whether values are served from a recorded trace
+    #[wasm_bindgen(getter)]
+    pub fn replaying(&self) -> bool {
+        matches!(self.source, Source::Replay(_))
+    }
+
+    /// Generate next random u64
+    pub fn next_u64(&mut self) -> Result<u64, Error> {
+        self.draw(DrawOp::U64, 0)
+    }
+
+    /// Generate bounded random value in [0, bound)
+    pub fn next_bounded(&mut self, bound: u64) -> Result<u64, Error> {
+        self.draw(DrawOp::Bounded, bound)
+    }
+
+    /// Generate random boolean
+    pub fn next_bool(&mut self) -> Result<bool, Error> {
+        Ok(self.draw(DrawOp::Bool, 0)? == 1)
+    }
+
+    /// Split off an independent context. This context continues with the
+    /// left seed; the returned context uses the right seed and shares this
+    /// context's recording buffer. When replaying or reading choice bytes,
+    /// both contexts continue to read from the same trace or stream.
+    pub fn split(&mut self) -> Result<GenContext, Error> {
+        self.gate(DrawOp::Split, 0)?;
+        let (source, result) = match &mut self.source {
+            Source::Rng(seed) => {
+                let pair = seed.split();
+                *seed = pair.left();
+                let right = pair.right();
+                let state = right.state;
+                (Source::Rng(right), state)
+            }
+            Source::Replay(replay) => {
+                let state = replay.borrow_mut().next(DrawOp::Split, 0)?;
+                (Source::Replay(replay.clone()), state)
+            }
+            Source::Bytes(stream) => {
+                let word = stream.borrow_mut().next_word();
+                (Source::Bytes(stream.clone()), word)
+            }
+        };
+        self.record(DrawOp::Split, 0, result)?;
+        let root = match &source {
+            Source::Rng(seed) => Some(seed.clone()),
+            _ => None,
+        };
+        Ok(GenContext {
+            source,
+            root,
+            recorder: self.recorder.clone(),
+            verifier: self.verifier.clone(),
+            audit: self.audit.clone(),
+            stepper: self.stepper.clone(),
+        })
+    }
+
+    /// Start recording draws into a fresh trace buffer
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(Rc::new(RefCell::new(Vec::new())));
+    }
+
+    /// Stop recording and return the draws recorded so far
+    pub fn stop_recording(&mut self) -> Trace {
+        let draws = self
+            .recorder
+            .take()
+            .map(|recorder| recorder.borrow().clone())
+            .unwrap_or_default();
+        Trace::from_draws(draws)
+    }
+
+    /// First draw where a verifying context departed from its recording in
+    /// operation or bound, including stopping short of the recording's end.
+    /// Always `undefined` for contexts not created with `verifying`.
+    #[wasm_bindgen(getter)]
+    pub fn divergence(&self) -> Option<Divergence> {
+        self.verifier
+            .as_ref()
+            .and_then(|verifier| verifier.borrow().divergence())
+    }
+
+    /// Context for draws tagged with `name`, derived from the seed this
+    /// context started from without consuming any of its draws. Adding or
+    /// removing sub-stream draws (e.g. jitter used only for logging) therefore
+    /// leaves the main stream, and any stored repro seeds, unchanged. The
+    /// same name always yields the same sub-stream. Sub-stream draws are not
+    /// recorded, verified or audited with the main stream. Replaying and
+    /// byte-stream contexts have no seed, so their sub-streams derive from a
+    /// fixed root instead.
+    pub fn substream(&self, name: &str) -> GenContext {
+        let root = self.root.clone().unwrap_or_else(|| Seed::new(0));
+        GenContext::new(&root.named(name))
+    }
+
+    /// Capture the context's position as an opaque token that `restore` can
+    /// resume from, possibly in another session. The token covers the seed
+    /// (including draw counters), replay position or choice bytes and their
+    /// position, and any draws recorded
+    /// so far; verification, audit callbacks and stepping are not captured.
+    pub fn checkpoint(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(64);
+        out.extend_from_slice(CHECKPOINT_MAGIC);
+        out.push(CHECKPOINT_VERSION);
+
+        match &self.source {
+            Source::Rng(seed) => {
+                out.push(0);
+                write_varint(&mut out, seed.state);
+                write_varint(&mut out, seed.gamma);
+                write_varint(&mut out, seed.draws);
+                write_varint(&mut out, seed.draws_since_split);
+                let root = self.root.as_ref().unwrap_or(seed);
+                write_varint(&mut out, root.state);
+                write_varint(&mut out, root.gamma);
+            }
+            Source::Replay(replay) => {
+                let replay = replay.borrow();
+                out.push(1);
+                write_varint(&mut out, replay.position as u64);
+                write_draws(&mut out, &replay.draws);
+            }
+            Source::Bytes(stream) => {
+                let stream = stream.borrow();
+                out.push(2);
+                write_varint(&mut out, stream.position as u64);
+                write_varint(&mut out, stream.bytes.len() as u64);
+                out.extend_from_slice(&stream.bytes);
+            }
+        }
+
+        match &self.recorder {
+            Some(recorder) => {
+                out.push(1);
+                write_draws(&mut out, &recorder.borrow());
+            }
+            None => out.push(0),
+        }
+        out
+    }
+
+    /// Resume a context from a token produced by `checkpoint`
+    pub fn restore(token: &[u8]) -> Result<GenContext, Error> {
+        if token.len() < CHECKPOINT_MAGIC.len()
+            || &token[..CHECKPOINT_MAGIC.len()] != CHECKPOINT_MAGIC
+        {
+            return Err(Error::invalid_encoding("missing checkpoint header"));
+        }
+        let mut position = CHECKPOINT_MAGIC.len();
+
+        let version = read_u8(token, &mut position)?;
+        if version != CHECKPOINT_VERSION {
+            return Err(Error::invalid_encoding(&format!(
+                "unsupported checkpoint version {version}"
+            )));
+        }
+
+        let (source, root) = match read_u8(token, &mut position)? {
+            0 => {
+                let mut seed = Seed::from_parts(
+                    read_varint(token, &mut position)?,
+                    read_varint(token, &mut position)?,
+                );
+                seed.draws = read_varint(token, &mut position)?;
+                seed.draws_since_split = read_varint(token, &mut position)?;
+                let root = Seed::from_parts(
+                    read_varint(token, &mut position)?,
+                    read_varint(token, &mut position)?,
+                );
+                (Source::Rng(seed), Some(root))
+            }
+            1 => {
+                let replay_position = read_varint(token, &mut position)? as usize;
+                let draws = read_draws(token, &mut position)?;
+                if replay_position > draws.len() {
+                    return Err(Error::invalid_encoding("replay position past end of trace"));
+                }
+                let replay = Replay {
+                    draws,
+                    position: replay_position,
+                };
+                (Source::Replay(Rc::new(RefCell::new(replay))), None)
+            }
+            2 => {
+                let stream_position = usize::try_from(read_varint(token, &mut position)?)
+                    .map_err(|_| Error::invalid_encoding("stream position out of range"))?;
+                let length = read_varint(token, &mut position)? as usize;
+                let bytes = read_bytes(token, &mut position, length)?.to_vec();
+                // Draws consume whole words, and only so many can read past
+                // the end of the input
+                let limit = bytes
+                    .len()
+                    .saturating_add(MAX_OVERRUN_WORDS.saturating_mul(WORD_SIZE));
+                if stream_position % WORD_SIZE != 0 || stream_position > limit {
+                    return Err(Error::invalid_encoding(
+                        "stream position cannot come from a choice stream",
+                    ));
+                }
+                let stream = ChoiceStream {
+                    bytes,
+                    position: stream_position,
+                };
+                (Source::Bytes(Rc::new(RefCell::new(stream))), None)
+            }
+            tag => {
+                return Err(Error::invalid_encoding(&format!(
+                    "unknown checkpoint source {tag}"
+                )))
+            }
+        };
+
+        let recorder = match read_u8(token, &mut position)? {
+            0 => None,
+            1 => Some(Rc::new(RefCell::new(read_draws(token, &mut position)?))),
+            flag => {
+                return Err(Error::invalid_encoding(&format!(
+                    "invalid recording flag {flag}"
+                )))
+            }
+        };
+
+        if position != token.len() {
+            return Err(Error::invalid_encoding("trailing bytes after checkpoint"));
+        }
+        Ok(GenContext {
+            source,
+            root,
+            recorder,
+            verifier: None,
+            audit: None,
+            stepper: None,
+        })
+    }
+
+    /// Install a callback invoked as `callback(index, op, bound, result)` for
+    /// every `sample_every`-th draw (1 audits every draw), including draws
+    /// made by contexts split off afterwards. An exception thrown by the
+    /// callback fails the draw with a `CallbackFailed` error.
+    pub fn set_audit(&mut self, callback: Function, sample_every: u32) -> Result<(), Error> {
+        if sample_every == 0 {
+            return Err(Error::invalid_parameter("sample_every", "0"));
+        }
+        self.audit = Some(Rc::new(RefCell::new(Audit {
+            callback,
+            sample_every: sample_every as u64,
+            index: 0,
+        })));
+        Ok(())
+    }
+
+    /// Remove the audit callback
+    pub fn clear_audit(&mut self) {
+        self.audit = None;
+    }
+
+    /// Pause before every draw for interactive debugging. Draws, including
+    /// splits and draws of contexts split off afterwards, fail with a
+    /// `StepPending` error without advancing the stream until `step` allows
+    /// one more; inspect `pending_op` and `pending_bound` to see what the
+    /// generator is about to draw, then call `step` and rerun it.
+    pub fn start_stepping(&mut self) {
+        let start = match &self.source {
+            Source::Rng(seed) => Rewind::Seed(seed.clone()),
+            Source::Replay(replay) => Rewind::Position(replay.borrow().position),
+            Source::Bytes(stream) => Rewind::Position(stream.borrow().position),
+        };
+        self.stepper = Some(Rc::new(RefCell::new(Stepper {
+            start,
+            recorded: self.recorder.as_ref().map_or(0, |r| r.borrow().len()),
+            granted: 0,
+            completed: 0,
+            pending: None,
+            last: None,
+        })));
+    }
+
+    /// Allow one more draw and rewind to where stepping started, so that
+    /// rerunning the generator stops one draw later than before. Any draws
+    /// recorded since stepping started are dropped; verification and audit
+    /// state are not rewound.
+    pub fn step(&mut self) -> Result<(), Error> {
+        let stepper = self
+            .stepper
+            .clone()
+            .ok_or_else(|| Error::invalid_parameter("step", "context is not stepping"))?;
+        let mut stepper = stepper.borrow_mut();
+        match (&mut self.source, &stepper.start) {
+            (Source::Rng(seed), Rewind::Seed(start)) => *seed = start.clone(),
+            (Source::Replay(replay), Rewind::Position(start)) => {
+                replay.borrow_mut().position = *start
+            }
+            (Source::Bytes(stream), Rewind::Position(start)) => {
+                stream.borrow_mut().position = *start
+            }
+            _ => {
+                return Err(Error::invalid_parameter(
+                    "step",
+                    "source changed since stepping started",
+                ))
+            }
+        }
+        if let Some(recorder) = &self.recorder {
+            recorder.borrow_mut().truncate(stepper.recorded);
+        }
+        stepper.granted += 1;
+        stepper.completed = 0;
+        stepper.pending = None;
+        stepper.last = None;
+        Ok(())
+    }
+
+    /// Stop pausing; draws continue from the current position
+    pub fn stop_stepping(&mut self) {
+        self.stepper = None;
+    }
+
+    /// Whether the context is stepping
+    #[wasm_bindgen(getter)]
+    pub fn stepping(&self) -> bool {
+        self.stepper.is_some()
+    }
+
+    /// Number of draws allowed per run by calls to `step`
+    #[wasm_bindgen(getter)]
+    pub fn steps(&self) -> u32 {
+        self.stepper
+            .as_ref()
+            .map_or(0, |stepper| stepper.borrow().granted as u32)
+    }
+
+    /// Operation the generator paused before, or `undefined` if it has not
+    /// paused since the last `step`
+    #[wasm_bindgen(getter)]
+    pub fn pending_op(&self) -> Option<DrawOp> {
+        self.stepper
+            .as_ref()
+            .and_then(|stepper| stepper.borrow().pending.map(|(op, _)| op))
+    }
+
+    /// Bound of the pending operation (0 unless it is `Bounded`)
+    #[wasm_bindgen(getter)]
+    pub fn pending_bound(&self) -> Option<u64> {
+        self.stepper
+            .as_ref()
+            .and_then(|stepper| stepper.borrow().pending.map(|(_, bound)| bound))
+    }
+
+    /// Result of the most recent draw completed while stepping, e.g. the
+    /// one just allowed by `step` (1 for true and 0 for false bools)
+    #[wasm_bindgen(getter)]
+    pub fn last_result(&self) -> Option<u64> {
+        self.stepper
+            .as_ref()
+            .and_then(|stepper| stepper.borrow().last.map(|draw| draw.result))
+    }
+
+    /// Whether draws are currently being recorded
+    #[wasm_bindgen(getter)]
+    pub fn recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+}
+
+/// Outcome of `verify_determinism`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct DeterminismReport {
+    divergence: Option<Divergence>,
+    outputs_match: bool,
+}
+
+#[wasm_bindgen]
+impl DeterminismReport {
+    /// Whether both runs made identical draws and returned equal outputs
+    #[wasm_bindgen(getter)]
+    pub fn deterministic(&self) -> bool {
+        self.divergence.is_none() && self.outputs_match
+    }
+
+    /// First draw where the second run departed from the first
+    #[wasm_bindgen(getter)]
+    pub fn divergence(&self) -> Option<Divergence> {
+        self.divergence.clone()
+    }
+
+    /// Whether both runs returned equal values (compared via JSON, falling
+    /// back to `Object.is` for values JSON cannot represent)
+    #[wasm_bindgen(getter)]
+    pub fn outputs_match(&self) -> bool {
+        self.outputs_match
+    }
+}
+
+/// Run `generator(ctx)` twice with fresh recording contexts created from
+/// `seed` and compare the draws made and the values returned. Any difference
+/// points at nondeterminism outside the seed, such as `Math.random`, clocks
+/// or iteration over unordered state in a custom generator or callback.
+#[wasm_bindgen]
+pub fn verify_determinism(seed: &Seed, generator: &Function) -> Result<DeterminismReport, Error> {
+    let run = || -> Result<(Trace, JsValue), Error> {
+        let recorder = Recorder::default();
+        let mut ctx = GenContext::new(seed);
+        ctx.recorder = Some(recorder.clone());
+        let output = generator
+            .call1(&JsValue::NULL, &JsValue::from(ctx))
+            .map_err(|err| Error::callback_failed("generator", &format!("{err:?}")))?;
+        let draws = recorder.borrow().clone();
+        Ok((Trace::from_draws(draws), output))
+    };
+
+    let (first_trace, first_output) = run()?;
+    let (second_trace, second_output) = run()?;
+
+    let outputs_match = match (
+        JSON::stringify(&first_output),
+        JSON::stringify(&second_output),
+    ) {
+        (Ok(first), Ok(second)) => first == second,
+        _ => Object::is(&first_output, &second_output),
+    };
+
+    Ok(DeterminismReport {
+        divergence: first_trace.first_divergence(&second_trace),
+        outputs_match,
+    })
+}
@@ -0,0 +1,272 @@
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+
+use crate::encoding::{fnv1a, read_bytes, read_u8, read_varint, write_varint};
+use crate::error::Error;
+use crate::Seed;
+
+/// Magic bytes at the start of an exported corpus
+const CORPUS_MAGIC: &[u8; 4] = b"HHCP";
+
+/// Current corpus encoding version
+const CORPUS_VERSION: u8 = 1;
+
+/// Corpus encoding version written by `export_compressed`
+const CORPUS_VERSION_COMPRESSED: u8 = 2;
+
+/// An interesting input: either a seed or a choice stream
+#[derive(Debug, Clone)]
+enum Entry {
+    Seed(Seed),
+    Choices(Vec<u8>),
+}
+
+impl Entry {
+    /// Append the entry as `[1 byte kind][payload]`; seeds are stored as
+    /// state and gamma varints, choice streams as a varint length and bytes
+    fn write(&self, out: &mut Vec<u8>) {
+        match self {
+            Entry::Seed(seed) => {
+                out.push(0);
+                write_varint(out, seed.state);
+                write_varint(out, seed.gamma);
+            }
+            Entry::Choices(bytes) => {
+                out.push(1);
+                write_varint(out, bytes.len() as u64);
+                out.extend_from_slice(bytes);
+            }
+        }
+    }
+
+    fn read(bytes: &[u8], position: &mut usize) -> Result<Entry, Error> {
+        match read_u8(bytes, position)? {
+            0 => Ok(Entry::Seed(Seed::from_parts(
+                read_varint(bytes, position)?,
+                read_varint(bytes, position)?,
+            ))),
+            1 => {
+                let length = read_varint(bytes, position)? as usize;
+                Ok(Entry::Choices(
+                    read_bytes(bytes, position, length)?.to_vec(),
+                ))
+            }
+            kind => Err(Error::invalid_encoding(&format!(
+                "unknown corpus entry kind {kind}"
+            ))),
+        }
+    }
+
+    /// Append the entry like `write`, except that a choice stream is stored
+    /// as the length of its common prefix with `previous` (the last choice
+    /// stream written) and the remaining bytes
+    fn write_compressed(&self, out: &mut Vec<u8>, previous: &[u8]) {
+        match self {
+            Entry::Seed(_) => self.write(out),
+            Entry::Choices(bytes) => {
+                let shared = bytes
+                    .iter()
+                    .zip(previous)
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                out.push(1);
+                write_varint(out, shared as u64);
+                write_varint(out, (bytes.len() - shared) as u64);
+                out.extend_from_slice(&bytes[shared..]);
+            }
+        }
+    }
+
+    fn read_compressed(
+        bytes: &[u8],
+        position: &mut usize,
+        previous: &[u8],
+    ) -> Result<Entry, Error> {
+        if bytes.get(*position) != Some(&1) {
+            return Entry::read(bytes, position);
+        }
+        *position += 1;
+        let shared = read_varint(bytes, position)? as usize;
+        let prefix = previous
+            .get(..shared)
+            .ok_or_else(|| Error::invalid_encoding("shared prefix longer than previous entry"))?;
+        let length = read_varint(bytes, position)? as usize;
+        let suffix = read_bytes(bytes, position, length)?;
+        Ok(Entry::Choices([prefix, suffix].concat()))
+    }
+
+    /// Content fingerprint used for deduplication
+    fn hash(&self) -> u64 {
+        let mut encoded = Vec::new();
+        self.write(&mut encoded);
+        fnv1a(&encoded)
+    }
+}
+
+/// One corpus entry as seen from JavaScript
+#[wasm_bindgen]
+pub struct CorpusEntry {
+    entry: Entry,
+}
+
+#[wasm_bindgen]
+impl CorpusEntry {
+    /// The stored seed, or `undefined` for choice streams
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Option<Seed> {
+        match &self.entry {
+            Entry::Seed(seed) => Some(seed.clone()),
+            Entry::Choices(_) => None,
+        }
+    }
+
+    /// The stored choice stream, or `undefined` for seeds
+    #[wasm_bindgen(getter)]
+    pub fn choices(&self) -> Option<Vec<u8>> {
+        match &self.entry {
+            Entry::Seed(_) => None,
+            Entry::Choices(bytes) => Some(bytes.clone()),
+        }
+    }
+}
+
+/// Store of seeds and choice streams that triggered failures or new
+/// behavior, kept in insertion order so reruns can replay it before
+/// starting fresh random search. Entries are deduplicated by content hash.
+#[derive(Debug, Clone, Default)]
+#[wasm_bindgen]
+pub struct Corpus {
+    entries: Vec<Entry>,
+    hashes: HashSet<u64>,
+}
+
+impl Corpus {
+    /// Add an entry unless one with the same hash is already stored
+    fn add(&mut self, entry: Entry) -> bool {
+        if self.hashes.insert(entry.hash()) {
+            self.entries.push(entry);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Corpus {
+    /// Create an empty corpus
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Corpus {
+        Corpus::default()
+    }
+
+    /// Add a seed, returning false if it is already stored. Draw counters
+    /// are not part of the entry.
+    pub fn add_seed(&mut self, seed: &Seed) -> bool {
+        self.add(Entry::Seed(Seed::from_parts(seed.state, seed.gamma)))
+    }
+
+    /// Add a choice stream, returning false if it is already stored
+    pub fn add_choices(&mut self, bytes: &[u8]) -> bool {
+        self.add(Entry::Choices(bytes.to_vec()))
+    }
+
+    /// Number of stored entries
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Entry at `index` in insertion order
+    pub fn get(&self, index: usize) -> Option<CorpusEntry> {
+        self.entries.get(index).map(|entry| CorpusEntry {
+            entry: entry.clone(),
+        })
+    }
+
+    /// Export every entry as one buffer:
+    ///
+    /// ```text
+    /// [4 bytes "HHCP"][1 byte version = 1][varint entry count]
+    /// seed:    [1 byte 0][varint state][varint gamma]
+    /// choices: [1 byte 1][varint length][bytes]
+    /// ```
+    pub fn export(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(CORPUS_MAGIC);
+        out.push(CORPUS_VERSION);
+        write_varint(&mut out, self.entries.len() as u64);
+        for entry in &self.entries {
+            entry.write(&mut out);
+        }
+        out
+    }
+
+    /// Export like `export`, but with version 2, where each choice stream
+    /// stores only what differs from the previous one:
+    ///
+    /// ```text
+    /// choices: [1 byte 1][varint shared prefix length][varint length][bytes]
+    /// ```
+    ///
+    /// Corpora built by mutation, whose streams share long prefixes, shrink
+    /// accordingly. `import` reads both versions.
+    pub fn export_compressed(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(CORPUS_MAGIC);
+        out.push(CORPUS_VERSION_COMPRESSED);
+        write_varint(&mut out, self.entries.len() as u64);
+        let mut previous: &[u8] = &[];
+        for entry in &self.entries {
+            entry.write_compressed(&mut out, previous);
+            if let Entry::Choices(bytes) = entry {
+                previous = bytes;
+            }
+        }
+        out
+    }
+
+    /// Add the entries of a buffer produced by `export` or
+    /// `export_compressed`, skipping ones already stored. Returns the number
+    /// of entries added; nothing is added if the buffer is malformed.
+    pub fn import(&mut self, bytes: &[u8]) -> Result<usize, Error> {
+        if bytes.len() < CORPUS_MAGIC.len() || &bytes[..CORPUS_MAGIC.len()] != CORPUS_MAGIC {
+            return Err(Error::invalid_encoding("missing corpus header"));
+        }
+        let mut position = CORPUS_MAGIC.len();
+
+        let version = read_u8(bytes, &mut position)?;
+        if version != CORPUS_VERSION && version != CORPUS_VERSION_COMPRESSED {
+            return Err(Error::invalid_encoding(&format!(
+                "unsupported corpus version {version}"
+            )));
+        }
+
+        let count = read_varint(bytes, &mut position)?;
+        let mut entries: Vec<Entry> = Vec::new();
+        let mut previous = 0;
+        for _ in 0..count {
+            let entry = if version == CORPUS_VERSION {
+                Entry::read(bytes, &mut position)?
+            } else {
+                let previous_bytes = match entries.get(previous) {
+                    Some(Entry::Choices(bytes)) => bytes.as_slice(),
+                    _ => &[],
+                };
+                Entry::read_compressed(bytes, &mut position, previous_bytes)?
+            };
+            if let Entry::Choices(_) = entry {
+                previous = entries.len();
+            }
+            entries.push(entry);
+        }
+
+        if position != bytes.len() {
+            return Err(Error::invalid_encoding("trailing bytes after corpus"));
+        }
+        Ok(entries
+            .into_iter()
+            .filter(|entry| self.add(entry.clone()))
+            .count())
+    }
+}
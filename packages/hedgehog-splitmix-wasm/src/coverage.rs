@@ -0,0 +1,135 @@
+use wasm_bindgen::prelude::*;
+
+use crate::choice::{below, crossover_words, from_words, mutate_words, to_words, MUTATION_OPS};
+use crate::corpus::Corpus;
+use crate::error::Error;
+use crate::{to_unit_f64, Seed};
+
+/// Chance of trying a fresh random input even when the corpus has entries
+const FRESH_PROBABILITY: f64 = 0.25;
+
+/// Map a hit count to a single bucket bit (1, 2, 3, 4-7, 8-15, 16-31,
+/// 32-127, 128+) so small changes in loop counts don't count as new coverage
+fn bucket(count: u8) -> u8 {
+    match count {
+        0 => 0,
+        1 => 1,
+        2 => 2,
+        3 => 4,
+        4..=7 => 8,
+        8..=15 => 16,
+        16..=31 => 32,
+        32..=127 => 64,
+        128..=255 => 128,
+    }
+}
+
+/// Lightweight coverage-guided search over choice streams. JavaScript asks
+/// for an input, runs a case on it (e.g. through `GenContext.from_bytes`),
+/// then reports the coverage it observed; inputs that reach new coverage are
+/// kept and mutated to produce later inputs.
+#[wasm_bindgen]
+pub struct CoverageSearch {
+    seed: Seed,
+    length: usize,
+    /// Choice streams that produced new coverage, in discovery order
+    corpus: Vec<Vec<u8>>,
+    /// Union of the bucketed coverage seen so far
+    seen: Vec<u8>,
+    /// Input most recently handed out, pending a coverage report
+    current: Option<Vec<u8>>,
+}
+
+#[wasm_bindgen]
+impl CoverageSearch {
+    /// Create a search whose fresh inputs are `length` random choice bytes
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: &Seed, length: u32) -> CoverageSearch {
+        CoverageSearch {
+            seed: seed.clone(),
+            length: length as usize,
+            corpus: Vec::new(),
+            seen: Vec::new(),
+            current: None,
+        }
+    }
+
+    /// Next choice stream to run: either fresh random bytes, a mutation of an
+    /// input that previously found new coverage, or a crossover of two such
+    /// inputs
+    pub fn next_input(&mut self) -> Vec<u8> {
+        let fresh = self.corpus.is_empty() || to_unit_f64(self.seed.step()) < FRESH_PROBABILITY;
+        let input = if fresh {
+            let pair = self.seed.split();
+            self.seed = pair.left();
+            pair.right().step_bytes(self.length)
+        } else {
+            let parent = to_words(&self.corpus[below(&mut self.seed, self.corpus.len())]);
+            // One extra choice beyond the mutation operators stands for
+            // crossover with a second kept input
+            let choice = below(&mut self.seed, MUTATION_OPS.len() + 1);
+            let words = match MUTATION_OPS.get(choice) {
+                Some(&op) => {
+                    let mut words = parent;
+                    mutate_words(&mut self.seed, &mut words, op);
+                    words
+                }
+                None => {
+                    let other = to_words(&self.corpus[below(&mut self.seed, self.corpus.len())]);
+                    crossover_words(&mut self.seed, &parent, &other)
+                }
+            };
+            from_words(&words)
+        };
+        self.current = Some(input.clone());
+        input
+    }
+
+    /// Report the coverage observed while running the last input, as one
+    /// hit counter per byte (an edge bitmap works too). Returns whether it
+    /// reached anything new, in which case the input joins the corpus.
+    pub fn report(&mut self, coverage: &[u8]) -> Result<bool, Error> {
+        let input = self
+            .current
+            .take()
+            .ok_or_else(|| Error::invalid_parameter("report", "no input pending"))?;
+
+        if self.seen.len() < coverage.len() {
+            self.seen.resize(coverage.len(), 0);
+        }
+        let mut new_coverage = false;
+        for (seen, &count) in self.seen.iter_mut().zip(coverage) {
+            let bits = bucket(count);
+            if bits & !*seen != 0 {
+                *seen |= bits;
+                new_coverage = true;
+            }
+        }
+
+        if new_coverage {
+            self.corpus.push(input);
+        }
+        Ok(new_coverage)
+    }
+
+    /// Number of inputs kept for producing new coverage
+    #[wasm_bindgen(getter)]
+    pub fn corpus_length(&self) -> usize {
+        self.corpus.len()
+    }
+
+    /// Number of distinct coverage buckets reached so far
+    #[wasm_bindgen(getter)]
+    pub fn coverage(&self) -> u32 {
+        self.seen.iter().map(|bits| bits.count_ones()).sum()
+    }
+
+    /// Inputs kept so far as a `Corpus`, e.g. to export for later runs
+    pub fn to_corpus(&self) -> Corpus {
+        let mut corpus = Corpus::new();
+        for input in &self.corpus {
+            corpus.add_choices(input);
+        }
+        corpus
+    }
+}
@@ -0,0 +1,74 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::{check_probability, Seed};
+
+/// Interesting byte tokens (magic numbers, keywords, delimiters) to splice
+/// into generated bytes, in the spirit of AFL dictionaries
+#[derive(Debug, Clone, Default)]
+#[wasm_bindgen]
+pub struct Dictionary {
+    tokens: Vec<Vec<u8>>,
+}
+
+#[wasm_bindgen]
+impl Dictionary {
+    /// Create an empty dictionary
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Dictionary {
+        Dictionary::default()
+    }
+
+    /// Add a token; empty and duplicate tokens are ignored
+    pub fn add(&mut self, token: &[u8]) {
+        if !token.is_empty() && !self.tokens.iter().any(|existing| existing == token) {
+            self.tokens.push(token.to_vec());
+        }
+    }
+
+    /// Add a UTF-8 keyword
+    pub fn add_str(&mut self, token: &str) {
+        self.add(token.as_bytes());
+    }
+
+    /// Number of tokens
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.tokens.len()
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Splice dictionary tokens into `data` in place. At each position a
+    /// token is written with probability `rate` (scaled by the edge-case
+    /// scale), truncated at the end of the buffer, and scanning resumes
+    /// after it. Works on UTF-8 encoded strings as well as raw bytes.
+    #[wasm_bindgen]
+    pub fn inject_tokens(
+        &self,
+        data: &mut [u8],
+        dictionary: &Dictionary,
+        rate: f64,
+    ) -> Result<Seed, Error> {
+        let p = check_probability("rate", rate)?;
+        let indices: Vec<usize> = (0..dictionary.tokens.len()).collect();
+
+        let mut seed = self.clone();
+        let mut position = 0;
+        while position < data.len() {
+            let (next, pick) = seed.pick_special(p, &indices);
+            seed = next;
+            match pick {
+                Some(index) => {
+                    let token = &dictionary.tokens[index];
+                    let end = (position + token.len()).min(data.len());
+                    data[position..end].copy_from_slice(&token[..end - position]);
+                    position = end;
+                }
+                None => position += 1,
+            }
+        }
+        Ok(seed)
+    }
+}
@@ -0,0 +1,143 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::HEADER_SIZE;
+use crate::{DataFormat, FillParams, Seed};
+
+/// Whether element `index` of two filled buffers of `format` is the same
+fn same_element(format: DataFormat, previous: &[u8], next: &[u8], index: u64) -> bool {
+    let data_start = HEADER_SIZE as usize;
+    match format {
+        DataFormat::BoolPacked => {
+            let byte = data_start + (index / 8) as usize;
+            let mask = 1 << (index % 8);
+            previous[byte] & mask == next[byte] & mask
+        }
+        _ => {
+            let width = format.bytes_per_element() as usize;
+            let offset = data_start + index as usize * width;
+            previous[offset..offset + width] == next[offset..offset + width]
+        }
+    }
+}
+
+/// Whether element `index` is marked null in a null bitmap
+fn is_null(mask: &[u8], index: u64) -> bool {
+    mask[(index / 8) as usize] & (1 << (index % 8)) != 0
+}
+
+/// Elements that differ between a previous fill and a regenerated one,
+/// produced by `fill_buffer_diff`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct FillDiff {
+    changed: Vec<u64>,
+    unchanged: u64,
+    added: u64,
+    removed: u64,
+    final_seed: Seed,
+}
+
+#[wasm_bindgen]
+impl FillDiff {
+    /// Indices, in ascending order, of elements present in both buffers
+    /// whose value changed
+    #[wasm_bindgen(getter)]
+    pub fn changed(&self) -> Vec<u64> {
+        self.changed.clone()
+    }
+
+    /// Elements present in both buffers with the same value
+    #[wasm_bindgen(getter)]
+    pub fn unchanged(&self) -> u64 {
+        self.unchanged
+    }
+
+    /// Elements beyond the end of the previous buffer
+    #[wasm_bindgen(getter)]
+    pub fn added(&self) -> u64 {
+        self.added
+    }
+
+    /// Elements of the previous buffer beyond the end of the new one
+    #[wasm_bindgen(getter)]
+    pub fn removed(&self) -> u64 {
+        self.removed
+    }
+
+    /// Whether the regenerated buffer matches the previous one exactly
+    #[wasm_bindgen(getter)]
+    pub fn identical(&self) -> bool {
+        self.changed.is_empty() && self.added == 0 && self.removed == 0
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn final_seed(&self) -> Seed {
+        self.final_seed.clone()
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Regenerate `count` elements into `buffer` exactly as
+    /// `fill_buffer_with_params` would, and compare them against `previous`,
+    /// an earlier fill of the same format from this seed. After tweaking a
+    /// bound, range or count, the diff lists only the elements that changed,
+    /// so corpus tooling can report or re-run just those. Framed strings
+    /// and blobs are rejected, as `fill_buffer_with_params` cannot produce
+    /// them. With a null probability in `params`, `previous` must carry its
+    /// null bitmap too, and an element whose nullness flips counts as
+    /// changed.
+    #[wasm_bindgen]
+    pub fn fill_buffer_diff(
+        &self,
+        previous: &[u8],
+        buffer: &mut [u8],
+        format_u8: u8,
+        count: u64,
+        params: &FillParams,
+    ) -> Result<FillDiff, Error> {
+        let format = DataFormat::fixed_width(format_u8)?;
+        if (previous.len() as u64) < HEADER_SIZE {
+            return Err(Error::buffer_too_small(HEADER_SIZE, previous.len()));
+        }
+        if previous[0] != format_u8 {
+            return Err(Error::invalid_parameter(
+                "previous",
+                &format!("format {} where {format_u8} was expected", previous[0]),
+            ));
+        }
+        let mut previous_count = [0; 8];
+        previous_count.copy_from_slice(&previous[1..9]);
+        let previous_count = u64::from_le_bytes(previous_count);
+        let previous_data_end = HEADER_SIZE.saturating_add(format.data_size(previous_count));
+        let previous_size = previous_data_end.saturating_add(params.null_mask_size(previous_count));
+        if (previous.len() as u64) < previous_size {
+            return Err(Error::buffer_too_small(previous_size, previous.len()));
+        }
+
+        let final_seed = self.fill_buffer_with_params(buffer, format_u8, count, params)?;
+
+        // The null bitmaps follow the values in both buffers
+        let null_masks = params.null_probability().map(|_| {
+            let data_end = (HEADER_SIZE + format.data_size(count)) as usize;
+            (&previous[previous_data_end as usize..], &buffer[data_end..])
+        });
+        let shared = count.min(previous_count);
+        let changed: Vec<u64> = (0..shared)
+            .filter(|&index| {
+                !same_element(format, previous, buffer, index)
+                    || null_masks.is_some_and(|(before, after)| {
+                        is_null(before, index) != is_null(after, index)
+                    })
+            })
+            .collect();
+        Ok(FillDiff {
+            unchanged: shared - changed.len() as u64,
+            changed,
+            added: count.saturating_sub(previous_count),
+            removed: previous_count.saturating_sub(count),
+            final_seed,
+        })
+    }
+}
@@ -0,0 +1,1090 @@
+use std::collections::HashSet;
+use std::sync::OnceLock;
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::{write_header, HEADER_SIZE};
+use crate::{to_unit_f64, BatchU32Result, DataFormat, Seed, SeedAndF64, SeedAndU32};
+
+/// Running totals of `weights`, validating that every weight is finite and
+/// non-negative and that at least one is positive
+pub(crate) fn cumulative_weights(weights: &[f64]) -> Result<Vec<f64>, Error> {
+    let mut total = 0.0;
+    let mut cumulative = Vec::with_capacity(weights.len());
+    for &weight in weights {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(Error::invalid_parameter("weight", &weight.to_string()));
+        }
+        total += weight;
+        cumulative.push(total);
+    }
+    if total <= 0.0 || !total.is_finite() {
+        return Err(Error::invalid_parameter(
+            "weights",
+            "total must be positive",
+        ));
+    }
+    Ok(cumulative)
+}
+
+impl Seed {
+    /// Advance in place and pick a category with probability proportional
+    /// to its weight, given the running totals from `cumulative_weights`
+    pub(crate) fn step_category(&mut self, cumulative: &[f64]) -> usize {
+        let total = cumulative[cumulative.len() - 1];
+        let target = to_unit_f64(self.step()) * total;
+        cumulative
+            .partition_point(|&sum| sum <= target)
+            .min(cumulative.len() - 1)
+    }
+}
+
+/// Largest size in the TypeScript `Size` convention; at this size a
+/// size-scaled range covers its full bounds
+const MAX_SIZE: u32 = 100;
+
+/// Layers of the normal ziggurat
+const ZIGGURAT_LAYERS: usize = 128;
+
+/// Start of the tail of the 128-layer normal ziggurat
+const ZIGGURAT_R: f64 = 3.442619855899;
+
+/// Area of each layer of the 128-layer normal ziggurat
+const ZIGGURAT_V: f64 = 9.91256303526217e-3;
+
+/// Means below which Poisson values are drawn by inversion, which costs
+/// time proportional to the mean
+const POISSON_INVERSION_LIMIT: f64 = 10.0;
+
+/// Largest Poisson mean accepted, leaving every plausible value well
+/// inside u32
+const MAX_POISSON_LAMBDA: f64 = 1e9;
+
+/// Expected successes below which binomial values are drawn by inversion,
+/// which costs time proportional to the mean
+const BINOMIAL_INVERSION_LIMIT: f64 = 30.0;
+
+/// Stirling series correction 1/(12k) - 1/(360k^3) + ..., as used by the
+/// final acceptance test of BTPE
+fn stirling_correction(k: f64) -> f64 {
+    let k2 = k * k;
+    (13860.0 - (462.0 - (132.0 - (99.0 - 140.0 / k2) / k2) / k2) / k2) / k / 166320.0
+}
+
+/// ln(k!), exact for small k and by Stirling's series beyond
+fn ln_factorial(k: f64) -> f64 {
+    if k < 10.0 {
+        return (2..=k as u32).map(|i| (i as f64).ln()).sum();
+    }
+    let inverse = 1.0 / k;
+    let inverse_squared = inverse * inverse;
+    k * k.ln() - k
+        + 0.5 * (std::f64::consts::TAU * k).ln()
+        + inverse * (1.0 / 12.0 - inverse_squared * (1.0 / 360.0 - inverse_squared / 1260.0))
+}
+
+/// ln(1 + x) / x, continued to 1 at 0
+fn ln_1p_ratio(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.ln_1p() / x
+    } else {
+        1.0 - x * (0.5 - x / 3.0)
+    }
+}
+
+/// (e^x - 1) / x, continued to 1 at 0
+fn exp_m1_ratio(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.exp_m1() / x
+    } else {
+        1.0 + x * 0.5 * (1.0 + x / 3.0)
+    }
+}
+
+/// Integral of the Zipf hat x^-s, shifted so that it is continuous in `s`
+fn zipf_integral(x: f64, s: f64) -> f64 {
+    let log_x = x.ln();
+    exp_m1_ratio((1.0 - s) * log_x) * log_x
+}
+
+/// Inverse of `zipf_integral`
+fn zipf_integral_inverse(x: f64, s: f64) -> f64 {
+    let t = (x * (1.0 - s)).max(-1.0);
+    (ln_1p_ratio(t) * x).exp()
+}
+
+/// Unnormalized standard normal density
+fn gaussian(x: f64) -> f64 {
+    (-0.5 * x * x).exp()
+}
+
+/// Right edges of the ziggurat layers, from the base (widened to hold the
+/// tail's area) up to 0 at the peak, with the density at each edge
+fn ziggurat() -> &'static ([f64; ZIGGURAT_LAYERS + 1], [f64; ZIGGURAT_LAYERS + 1]) {
+    static TABLES: OnceLock<([f64; ZIGGURAT_LAYERS + 1], [f64; ZIGGURAT_LAYERS + 1])> =
+        OnceLock::new();
+    TABLES.get_or_init(|| {
+        let mut x = [0.0; ZIGGURAT_LAYERS + 1];
+        x[0] = ZIGGURAT_V / gaussian(ZIGGURAT_R);
+        x[1] = ZIGGURAT_R;
+        for i in 1..ZIGGURAT_LAYERS - 1 {
+            x[i + 1] = (-2.0 * (ZIGGURAT_V / x[i] + gaussian(x[i])).ln()).sqrt();
+        }
+        (x, x.map(gaussian))
+    })
+}
+
+/// Interpolate between `min` and `max` without overflowing for wide ranges
+fn lerp(min: f64, max: f64, t: f64) -> f64 {
+    (min * (1.0 - t) + max * t).clamp(min, max)
+}
+
+/// Validate a normal distribution's mean and standard deviation
+fn check_normal(mean: f64, stddev: f64) -> Result<(), Error> {
+    if !mean.is_finite() {
+        return Err(Error::invalid_parameter("mean", &mean.to_string()));
+    }
+    if !stddev.is_finite() || stddev < 0.0 {
+        return Err(Error::invalid_parameter("stddev", &stddev.to_string()));
+    }
+    Ok(())
+}
+
+fn check_poisson(lambda: f64) -> Result<(), Error> {
+    if !(0.0..=MAX_POISSON_LAMBDA).contains(&lambda) {
+        return Err(Error::invalid_parameter(
+            "lambda",
+            &format!("{lambda}, expected 0 to {MAX_POISSON_LAMBDA}"),
+        ));
+    }
+    Ok(())
+}
+
+fn check_binomial(p: f64) -> Result<(), Error> {
+    if !(0.0..=1.0).contains(&p) {
+        return Err(Error::invalid_parameter(
+            "p",
+            &format!("{p}, expected [0, 1]"),
+        ));
+    }
+    Ok(())
+}
+
+fn check_rate(name: &str, rate: f64) -> Result<(), Error> {
+    if !rate.is_finite() || rate <= 0.0 {
+        return Err(Error::invalid_parameter(name, &rate.to_string()));
+    }
+    Ok(())
+}
+
+impl Seed {
+    /// Advance in place, returning a Poisson value of mean `lambda`: by
+    /// inversion with a single draw for small means, and otherwise by
+    /// Hörmann's transformed rejection (PTRS), which takes two draws on
+    /// average whatever the mean
+    fn step_poisson(&mut self, lambda: f64) -> u32 {
+        if lambda < POISSON_INVERSION_LIMIT {
+            let u = to_unit_f64(self.step());
+            let mut probability = (-lambda).exp();
+            let mut cumulative = probability;
+            let mut k = 0;
+            // Rounding can leave the cumulative sum just short of 1, so the
+            // search also stops once the terms vanish
+            while u >= cumulative && probability > 0.0 {
+                k += 1;
+                probability *= lambda / k as f64;
+                cumulative += probability;
+            }
+            return k;
+        }
+
+        let root = lambda.sqrt();
+        let log_lambda = lambda.ln();
+        let b = 0.931 + 2.53 * root;
+        let a = -0.059 + 0.02483 * b;
+        let inverse_alpha = 1.1239 + 1.1328 / (b - 3.4);
+        let vr = 0.9277 - 3.6224 / (b - 2.0);
+        loop {
+            let u = to_unit_f64(self.step()) - 0.5;
+            let v = 1.0 - to_unit_f64(self.step());
+            let us = 0.5 - u.abs();
+            let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+            if us >= 0.07 && v <= vr {
+                return k as u32;
+            }
+            if k < 0.0 || (us < 0.013 && v > us) {
+                continue;
+            }
+            let accept = v.ln() + inverse_alpha.ln() - (a / (us * us) + b).ln();
+            if accept <= -lambda + k * log_lambda - ln_factorial(k) {
+                return k as u32;
+            }
+        }
+    }
+
+    /// Advance in place, returning the number of successes in `n` trials
+    /// that each succeed with probability `p`: by inversion for small means,
+    /// and otherwise by Kachitvichyanukul and Schmeiser's BTPE, whose cost
+    /// does not grow with `n`. Both work on the rarer outcome and flip the
+    /// count for `p > 0.5`.
+    fn step_binomial(&mut self, n: u32, p: f64) -> u32 {
+        let r = p.min(1.0 - p);
+        if n == 0 || r == 0.0 {
+            return if p > 0.5 { n } else { 0 };
+        }
+        let count = if n as f64 * r < BINOMIAL_INVERSION_LIMIT {
+            self.binomial_inversion(n, r)
+        } else {
+            self.binomial_btpe(n, r)
+        };
+        if p > 0.5 {
+            n - count
+        } else {
+            count
+        }
+    }
+
+    /// Sequential search from 0 for `p <= 0.5`, restarting with a fresh draw
+    /// in the rare case rounding carries it far past the mean
+    fn binomial_inversion(&mut self, n: u32, p: f64) -> u32 {
+        let q = 1.0 - p;
+        let first = (n as f64 * q.ln()).exp();
+        let mean = n as f64 * p;
+        let limit = (n as f64).min(mean + 10.0 * (mean * q + 1.0).sqrt()) as u32;
+        'draw: loop {
+            let mut u = to_unit_f64(self.step());
+            let mut probability = first;
+            let mut k = 0;
+            while u > probability {
+                k += 1;
+                if k > limit {
+                    continue 'draw;
+                }
+                u -= probability;
+                probability *= (n - k + 1) as f64 * p / (k as f64 * q);
+            }
+            return k;
+        }
+    }
+
+    /// BTPE for `p <= 0.5` and `n * p` at least `BINOMIAL_INVERSION_LIMIT`:
+    /// a triangle, two parallelograms and two exponential tails cover the
+    /// distribution, and most candidates are accepted without evaluating it
+    fn binomial_btpe(&mut self, n: u32, p: f64) -> u32 {
+        let n_f = n as f64;
+        let q = 1.0 - p;
+        let npq = n_f * p * q;
+        let f_mode = n_f * p + p;
+        let mode = f_mode.floor();
+        let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+        let x_mid = mode + 0.5;
+        let x_left = x_mid - p1;
+        let x_right = x_mid + p1;
+        let c = 0.134 + 20.5 / (15.3 + mode);
+        let a = (f_mode - x_left) / (f_mode - x_left * p);
+        let lambda_left = a * (1.0 + 0.5 * a);
+        let a = (x_right - f_mode) / (x_right * q);
+        let lambda_right = a * (1.0 + 0.5 * a);
+        let p2 = p1 * (1.0 + 2.0 * c);
+        let p3 = p2 + c / lambda_left;
+        let p4 = p3 + c / lambda_right;
+
+        loop {
+            let u = to_unit_f64(self.step()) * p4;
+            let mut v = 1.0 - to_unit_f64(self.step());
+
+            // Triangle: accepted outright
+            if u <= p1 {
+                return (x_mid - p1 * v + u).floor() as u32;
+            }
+            let y = if u <= p2 {
+                let x = x_left + (u - p1) / c;
+                v = v * c + 1.0 - (mode - x + 0.5).abs() / p1;
+                if v > 1.0 {
+                    continue;
+                }
+                x.floor()
+            } else if u <= p3 {
+                let y = (x_left + v.ln() / lambda_left).floor();
+                if y < 0.0 {
+                    continue;
+                }
+                v *= (u - p2) * lambda_left;
+                y
+            } else {
+                let y = (x_right - v.ln() / lambda_right).floor();
+                if y > n_f {
+                    continue;
+                }
+                v *= (u - p3) * lambda_right;
+                y
+            };
+
+            let k = (y - mode).abs();
+            if k <= 20.0 || k >= npq / 2.0 - 1.0 {
+                // Evaluate f(y) / f(mode) by its recurrence
+                let s = p / q;
+                let a = s * (n_f + 1.0);
+                let mut f = 1.0;
+                if mode < y {
+                    for i in (mode as u32 + 1)..=(y as u32) {
+                        f *= a / i as f64 - s;
+                    }
+                } else {
+                    for i in (y as u32 + 1)..=(mode as u32) {
+                        f /= a / i as f64 - s;
+                    }
+                }
+                if v <= f {
+                    return y as u32;
+                }
+                continue;
+            }
+
+            // Squeeze on ln(f(y) / f(mode)), then the Stirling bound
+            let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+            let t = -k * k / (2.0 * npq);
+            let log_v = v.ln();
+            if log_v < t - rho {
+                return y as u32;
+            }
+            if log_v > t + rho {
+                continue;
+            }
+            let x1 = y + 1.0;
+            let f1 = mode + 1.0;
+            let z = n_f + 1.0 - mode;
+            let w = n_f - y + 1.0;
+            let bound = x_mid * (f1 / x1).ln()
+                + (n_f - mode + 0.5) * (z / w).ln()
+                + (y - mode) * (w * p / (x1 * q)).ln()
+                + stirling_correction(f1)
+                + stirling_correction(z)
+                + stirling_correction(x1)
+                + stirling_correction(w);
+            if log_v <= bound {
+                return y as u32;
+            }
+        }
+    }
+
+    /// Advance in place, returning a rank in [1, n] with probability
+    /// proportional to k^-s, by Hörmann and Derflinger's rejection-inversion,
+    /// which takes about one draw whatever `n`
+    fn step_zipf(&mut self, n: u32, s: f64) -> u32 {
+        let h = |x: f64| (-s * x.ln()).exp();
+        let integral_first = zipf_integral(1.5, s) - 1.0;
+        let integral_last = zipf_integral(n as f64 + 0.5, s);
+        let squeeze = 2.0 - zipf_integral_inverse(zipf_integral(2.5, s) - h(2.0), s);
+        loop {
+            let u = integral_last + to_unit_f64(self.step()) * (integral_first - integral_last);
+            let x = zipf_integral_inverse(u, s);
+            let k = (x + 0.5).floor().clamp(1.0, n as f64);
+            if k - x <= squeeze || u >= zipf_integral(k + 0.5, s) - h(k) {
+                return k as u32;
+            }
+        }
+    }
+
+    /// Advance in place, returning an exponential value of mean 1 by
+    /// inversion; the uniform is shifted to (0, 1] so the logarithm stays
+    /// finite
+    fn step_exponential(&mut self) -> f64 {
+        -(1.0 - to_unit_f64(self.step())).ln()
+    }
+
+    /// Advance in place, returning a standard normal value. The low 7 bits
+    /// of a draw pick a layer and the top 53 bits a signed position in it;
+    /// only the rare draws outside a layer's inner rectangle draw again.
+    fn step_normal(&mut self) -> f64 {
+        let (x, density) = ziggurat();
+        loop {
+            let bits = self.step();
+            let i = bits as usize % ZIGGURAT_LAYERS;
+            let u = 2.0 * to_unit_f64(bits) - 1.0;
+            let candidate = u * x[i];
+            if candidate.abs() < x[i + 1] {
+                return candidate;
+            }
+            if i == 0 {
+                // Marsaglia's tail method, beyond R
+                loop {
+                    let tail = (1.0 - to_unit_f64(self.step())).ln() / ZIGGURAT_R;
+                    let y = (1.0 - to_unit_f64(self.step())).ln();
+                    if -2.0 * y >= tail * tail {
+                        return if u < 0.0 {
+                            tail - ZIGGURAT_R
+                        } else {
+                            ZIGGURAT_R - tail
+                        };
+                    }
+                }
+            }
+            let height = density[i + 1] + (density[i] - density[i + 1]) * to_unit_f64(self.step());
+            if height < gaussian(candidate) {
+                return candidate;
+            }
+        }
+    }
+}
+
+/// Validate a float range and its origin, matching the TypeScript `Range`
+fn check_float_range(min: f64, max: f64, origin: f64) -> Result<(), Error> {
+    if !min.is_finite() || !max.is_finite() || min > max {
+        return Err(Error::invalid_parameter(
+            "range",
+            &format!("[{min}, {max}]"),
+        ));
+    }
+    if !(min..=max).contains(&origin) {
+        return Err(Error::invalid_parameter("origin", &origin.to_string()));
+    }
+    Ok(())
+}
+
+/// Return type for operations that produce a seed and per-category counts
+#[wasm_bindgen]
+pub struct SeedAndCounts {
+    seed: Seed,
+    counts: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl SeedAndCounts {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn counts(&self) -> Vec<u32> {
+        self.counts.clone()
+    }
+}
+
+/// How `next_partition` splits a total
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[wasm_bindgen]
+pub enum PartitionMethod {
+    /// Every composition of the total into the parts is equally likely
+    #[default]
+    Uniform = 0,
+    /// Each part takes a uniform share of what the earlier parts left, so
+    /// allocations are skewed towards the first parts
+    StickBreaking = 1,
+}
+
+/// Return type for operations that produce a seed and two f64 values
+#[wasm_bindgen]
+pub struct SeedAndF64Pair {
+    seed: Seed,
+    first: f64,
+    second: f64,
+}
+
+#[wasm_bindgen]
+impl SeedAndF64Pair {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn first(&self) -> f64 {
+        self.first
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn second(&self) -> f64 {
+        self.second
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Generate two independent standard normal values with the Box-Muller
+    /// transform, using two draws for both values
+    #[wasm_bindgen]
+    pub fn next_normal_pair(&self) -> SeedAndF64Pair {
+        let mut seed = self.clone();
+        // Shift the first uniform to (0, 1] so the logarithm stays finite
+        let u1 = 1.0 - to_unit_f64(seed.step());
+        let u2 = to_unit_f64(seed.step());
+        let radius = (-2.0 * u1.ln()).sqrt();
+        let (sin, cos) = (std::f64::consts::TAU * u2).sin_cos();
+        SeedAndF64Pair {
+            seed,
+            first: radius * cos,
+            second: radius * sin,
+        }
+    }
+
+    /// Generate a normal value with the given mean and standard deviation
+    /// by the ziggurat method, which usually takes a single draw
+    #[wasm_bindgen]
+    pub fn next_normal(&self, mean: f64, stddev: f64) -> Result<SeedAndF64, Error> {
+        check_normal(mean, stddev)?;
+        let mut seed = self.clone();
+        let value = mean + stddev * seed.step_normal();
+        Ok(SeedAndF64 { seed, value })
+    }
+
+    /// Fill an F64LE buffer (see `fill_buffer`) with `count` normal values,
+    /// matching `count` chained `next_normal` calls
+    #[wasm_bindgen]
+    pub fn fill_normal(
+        &self,
+        buffer: &mut [u8],
+        count: u64,
+        mean: f64,
+        stddev: f64,
+    ) -> Result<Seed, Error> {
+        check_normal(mean, stddev)?;
+        write_header(buffer, DataFormat::F64LE, count)?;
+        let mut seed = self.clone();
+        let data = &mut buffer[HEADER_SIZE as usize..];
+        for chunk in data.chunks_exact_mut(8).take(count as usize) {
+            let value = mean + stddev * seed.step_normal();
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+        Ok(seed)
+    }
+
+    /// Generate a log-normal value whose logarithm is normal around
+    /// `ln(median)` with standard deviation `sigma`, e.g. a response size or
+    /// latency: most values sit near the median with a long right tail
+    #[wasm_bindgen]
+    pub fn next_lognormal(&self, median: f64, sigma: f64) -> Result<SeedAndF64, Error> {
+        check_rate("median", median)?;
+        if !sigma.is_finite() || sigma < 0.0 {
+            return Err(Error::invalid_parameter("sigma", &sigma.to_string()));
+        }
+        let mut seed = self.clone();
+        let value = (median.ln() + sigma * seed.step_normal()).exp();
+        Ok(SeedAndF64 { seed, value })
+    }
+
+    /// Generate a Pareto value of at least `scale` whose tail falls off as
+    /// x^-shape, e.g. file sizes or wealth; shapes at or below 2 give
+    /// infinite variance and below 1 an infinite mean. Values saturate at
+    /// f64::MAX.
+    #[wasm_bindgen]
+    pub fn next_pareto(&self, scale: f64, shape: f64) -> Result<SeedAndF64, Error> {
+        check_rate("scale", scale)?;
+        check_rate("shape", shape)?;
+        let mut seed = self.clone();
+        // Inversion, with the uniform shifted to (0, 1]
+        let u = 1.0 - to_unit_f64(seed.step());
+        let value = (scale * u.powf(-1.0 / shape)).min(f64::MAX);
+        Ok(SeedAndF64 { seed, value })
+    }
+
+    /// Generate a Zipf rank in [1, n] with probability proportional to
+    /// k^-s, e.g. which of `n` keys a request hits in a popularity-skewed
+    /// cache test; `s` of 0 is uniform and larger values concentrate on the
+    /// first ranks. Large `n` costs no more than small `n`.
+    #[wasm_bindgen]
+    pub fn next_zipf(&self, n: u32, s: f64) -> Result<SeedAndU32, Error> {
+        if n == 0 {
+            return Err(Error::invalid_parameter("n", "0"));
+        }
+        if !s.is_finite() || s < 0.0 {
+            return Err(Error::invalid_parameter("s", &s.to_string()));
+        }
+        let mut seed = self.clone();
+        let value = seed.step_zipf(n, s);
+        Ok(SeedAndU32 { seed, value })
+    }
+
+    /// Generate a Poisson count with mean `lambda`, e.g. events per interval
+    /// or a collection size with realistic skew
+    #[wasm_bindgen]
+    pub fn next_poisson(&self, lambda: f64) -> Result<SeedAndU32, Error> {
+        check_poisson(lambda)?;
+        let mut seed = self.clone();
+        let value = seed.step_poisson(lambda);
+        Ok(SeedAndU32 { seed, value })
+    }
+
+    /// Generate `count` Poisson counts with mean `lambda`, matching `count`
+    /// chained `next_poisson` calls
+    #[wasm_bindgen]
+    pub fn next_poisson_batch(&self, lambda: f64, count: u32) -> Result<BatchU32Result, Error> {
+        check_poisson(lambda)?;
+        let mut final_seed = self.clone();
+        let values = (0..count)
+            .map(|_| final_seed.step_poisson(lambda))
+            .collect();
+        Ok(BatchU32Result { values, final_seed })
+    }
+
+    /// Generate the number of successes in `n` independent trials that each
+    /// succeed with probability `p`, e.g. how many of `n` requests a flaky
+    /// model drops. Large `n` costs no more than small `n`.
+    #[wasm_bindgen]
+    pub fn next_binomial(&self, n: u32, p: f64) -> Result<SeedAndU32, Error> {
+        check_binomial(p)?;
+        let mut seed = self.clone();
+        let value = seed.step_binomial(n, p);
+        Ok(SeedAndU32 { seed, value })
+    }
+
+    /// Generate the number of failures before the first success of trials
+    /// that each succeed with probability `p`, so collection lengths favor
+    /// small sizes while still reaching large ones; the mean is
+    /// `(1 - p) / p`. Values saturate at u32::MAX.
+    #[wasm_bindgen]
+    pub fn next_geometric(&self, p: f64) -> Result<SeedAndU32, Error> {
+        if !(p > 0.0 && p <= 1.0) {
+            return Err(Error::invalid_parameter(
+                "p",
+                &format!("{p}, expected (0, 1]"),
+            ));
+        }
+        let mut seed = self.clone();
+        // Inversion: the shifted uniform keeps the logarithm finite, and
+        // ln_1p stays accurate for tiny p
+        let u = 1.0 - to_unit_f64(seed.step());
+        let value = (u.ln() / (-p).ln_1p()).floor() as u32;
+        Ok(SeedAndU32 { seed, value })
+    }
+
+    /// Generate an exponential value with rate `lambda` (mean `1 / lambda`),
+    /// e.g. an inter-arrival time
+    #[wasm_bindgen]
+    pub fn next_exponential(&self, lambda: f64) -> Result<SeedAndF64, Error> {
+        check_rate("lambda", lambda)?;
+        let mut seed = self.clone();
+        let value = seed.step_exponential() / lambda;
+        Ok(SeedAndF64 { seed, value })
+    }
+
+    /// Fill an F64LE buffer (see `fill_buffer`) with `count` exponential
+    /// values, matching `count` chained `next_exponential` calls
+    #[wasm_bindgen]
+    pub fn fill_exponential(
+        &self,
+        buffer: &mut [u8],
+        count: u64,
+        lambda: f64,
+    ) -> Result<Seed, Error> {
+        check_rate("lambda", lambda)?;
+        write_header(buffer, DataFormat::F64LE, count)?;
+        let mut seed = self.clone();
+        let data = &mut buffer[HEADER_SIZE as usize..];
+        for chunk in data.chunks_exact_mut(8).take(count as usize) {
+            let value = seed.step_exponential() / lambda;
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+        Ok(seed)
+    }
+
+    /// Generate a float in [min, max] whose shrink target is `origin`.
+    /// Sampling is uniform over the whole interval; the origin is validated
+    /// against it like `Range.withOrigin` so callers can shrink towards it.
+    #[wasm_bindgen]
+    pub fn next_f64_range(&self, min: f64, max: f64, origin: f64) -> Result<SeedAndF64, Error> {
+        check_float_range(min, max, origin)?;
+        let result = self.next_u64();
+        Ok(SeedAndF64 {
+            seed: result.seed,
+            value: lerp(min, max, to_unit_f64(result.value)),
+        })
+    }
+
+    /// Generate a float like hedgehog's `Range.linearFrac`: the bounds grow
+    /// linearly from `origin` at size 0 to [min, max] at size 100
+    #[wasm_bindgen]
+    pub fn next_f64_linear_frac(
+        &self,
+        min: f64,
+        max: f64,
+        origin: f64,
+        size: u32,
+    ) -> Result<SeedAndF64, Error> {
+        check_float_range(min, max, origin)?;
+        let scale = size.min(MAX_SIZE) as f64 / MAX_SIZE as f64;
+        let low = lerp(min, origin, 1.0 - scale);
+        let high = lerp(origin, max, scale);
+        self.next_f64_range(low, high, origin)
+    }
+
+    /// Generate `count` distinct u32s from the inclusive range [min, max] in
+    /// random order, e.g. primary keys or port numbers. Fails if the range
+    /// holds fewer than `count` values.
+    #[wasm_bindgen]
+    pub fn fill_distinct_u32(
+        &self,
+        min: u32,
+        max: u32,
+        count: u32,
+    ) -> Result<BatchU32Result, Error> {
+        if min > max {
+            return Err(Error::invalid_parameter(
+                "range",
+                &format!("[{min}, {max}]"),
+            ));
+        }
+        let span = (max - min) as u64 + 1;
+        if span < count as u64 {
+            return Err(Error::invalid_parameter(
+                "count",
+                &format!("{count} exceeds the {span} values in [{min}, {max}]"),
+            ));
+        }
+
+        let mut final_seed = self.clone();
+        let values = if count as u64 * 2 <= span {
+            // Sparse: rejection terminates quickly while at most half the
+            // range is taken
+            let mut seen = HashSet::with_capacity(count as usize);
+            let mut values = Vec::with_capacity(count as usize);
+            while values.len() < count as usize {
+                let value = min + final_seed.step_below(span) as u32;
+                if seen.insert(value) {
+                    values.push(value);
+                }
+            }
+            values
+        } else {
+            // Dense: the range is at most twice `count`, so shuffle it whole
+            let mut values: Vec<u32> = (min..=max).collect();
+            for i in 0..count as usize {
+                let j = i + final_seed.step_below((values.len() - i) as u64) as usize;
+                values.swap(i, j);
+            }
+            values.truncate(count as usize);
+            values
+        };
+        Ok(BatchU32Result { values, final_seed })
+    }
+
+    /// Distribute `n` trials over categories with the given relative
+    /// weights, returning how many landed in each category
+    #[wasm_bindgen]
+    pub fn next_multinomial(&self, n: u32, weights: &[f64]) -> Result<SeedAndCounts, Error> {
+        self.next_multinomial_batch(n, weights, 1)
+    }
+
+    /// Draw `count` independent multinomial samples at once. The counts are
+    /// returned flattened, `weights.length` entries per sample.
+    #[wasm_bindgen]
+    pub fn next_multinomial_batch(
+        &self,
+        n: u32,
+        weights: &[f64],
+        count: u32,
+    ) -> Result<SeedAndCounts, Error> {
+        let cumulative = cumulative_weights(weights)?;
+        let categories = cumulative.len();
+
+        let mut seed = self.clone();
+        let mut counts = vec![0u32; categories * count as usize];
+        for sample in counts.chunks_mut(categories) {
+            for _ in 0..n {
+                sample[seed.step_category(&cumulative)] += 1;
+            }
+        }
+        Ok(SeedAndCounts { seed, counts })
+    }
+
+    /// Split `total` into `parts` non-negative counts that sum to it, e.g.
+    /// quantity allocations, shard assignments or budget splits
+    #[wasm_bindgen]
+    pub fn next_partition(
+        &self,
+        total: u32,
+        parts: u32,
+        method: PartitionMethod,
+    ) -> Result<SeedAndCounts, Error> {
+        self.next_partition_batch(total, parts, method, 1)
+    }
+
+    /// Draw `count` independent partitions at once. The counts are returned
+    /// flattened, `parts` entries per partition.
+    #[wasm_bindgen]
+    pub fn next_partition_batch(
+        &self,
+        total: u32,
+        parts: u32,
+        method: PartitionMethod,
+        count: u32,
+    ) -> Result<SeedAndCounts, Error> {
+        if parts == 0 {
+            return Err(Error::invalid_parameter("parts", "0"));
+        }
+
+        let mut seed = self.clone();
+        let mut counts = vec![0u32; parts as usize * count as usize];
+        for sample in counts.chunks_mut(parts as usize) {
+            match method {
+                PartitionMethod::Uniform => {
+                    // Stars and bars: place `parts - 1` bars among
+                    // `total + parts - 1` slots with Floyd's algorithm, and
+                    // count the stars between consecutive bars
+                    let slots = total as u64 + parts as u64 - 1;
+                    let mut bars = HashSet::with_capacity(parts as usize - 1);
+                    for j in slots - (parts as u64 - 1)..slots {
+                        let bar = seed.step_below(j + 1);
+                        if !bars.insert(bar) {
+                            bars.insert(j);
+                        }
+                    }
+                    let mut bars: Vec<u64> = bars.into_iter().collect();
+                    bars.sort_unstable();
+                    let mut previous = 0;
+                    for (part, &bar) in sample.iter_mut().zip(&bars) {
+                        *part = (bar - previous) as u32;
+                        previous = bar + 1;
+                    }
+                    sample[parts as usize - 1] = (slots - previous) as u32;
+                }
+                PartitionMethod::StickBreaking => {
+                    let mut remaining = total;
+                    let last = parts as usize - 1;
+                    for part in &mut sample[..last] {
+                        *part = seed.step_below(remaining as u64 + 1) as u32;
+                        remaining -= *part;
+                    }
+                    sample[last] = remaining;
+                }
+            }
+        }
+        Ok(SeedAndCounts { seed, counts })
+    }
+}
+
+/// Streaming Poisson process: yields cumulative event times with
+/// exponentially distributed gaps of mean `1 / rate`, stopping at `horizon`
+#[wasm_bindgen]
+pub struct PoissonProcess {
+    seed: Seed,
+    rate: f64,
+    horizon: f64,
+    time: f64,
+}
+
+#[wasm_bindgen]
+impl PoissonProcess {
+    /// Start a process at time 0 with events occurring at `rate` per unit
+    /// time, ending at `horizon`
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: &Seed, rate: f64, horizon: f64) -> Result<PoissonProcess, Error> {
+        check_rate("rate", rate)?;
+        if horizon.is_nan() || horizon < 0.0 {
+            return Err(Error::invalid_parameter("horizon", &horizon.to_string()));
+        }
+        Ok(PoissonProcess {
+            seed: seed.clone(),
+            rate,
+            horizon,
+            time: 0.0,
+        })
+    }
+
+    /// Time of the next event, or `undefined` once the horizon is passed
+    pub fn next_event(&mut self) -> Option<f64> {
+        if self.time > self.horizon {
+            return None;
+        }
+        // 1 - u lies in (0, 1], keeping the logarithm finite
+        let gap = self.seed.step_exponential() / self.rate;
+        self.time += gap;
+        if self.time > self.horizon {
+            None
+        } else {
+            Some(self.time)
+        }
+    }
+
+    /// Up to `max` further event times, fewer if the horizon is reached
+    pub fn take(&mut self, max: u32) -> Vec<f64> {
+        let mut events = Vec::new();
+        while events.len() < max as usize {
+            match self.next_event() {
+                Some(time) => events.push(time),
+                None => break,
+            }
+        }
+        events
+    }
+
+    /// Seed after the draws made so far
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+}
+
+/// Seeded k-element sample over a stream of candidates whose length is not
+/// known up front. Uses weighted reservoir sampling (Efraimidis-Spirakis):
+/// each candidate gets the key `ln(u) / weight` and the k largest keys are
+/// kept, which reduces to a uniform sample when all weights are equal.
+#[wasm_bindgen]
+pub struct Reservoir {
+    seed: Seed,
+    capacity: usize,
+    /// Kept `(key, index)` pairs, at most `capacity` of them
+    kept: Vec<(f64, u32)>,
+    seen: u64,
+}
+
+#[wasm_bindgen]
+impl Reservoir {
+    /// Create an empty reservoir keeping up to `k` candidates
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: &Seed, k: u32) -> Reservoir {
+        Reservoir {
+            seed: seed.clone(),
+            capacity: k as usize,
+            kept: Vec::with_capacity(k as usize),
+            seen: 0,
+        }
+    }
+
+    /// Offer a candidate with weight 1
+    pub fn offer(&mut self, index: u32) {
+        self.offer_key(index, 1.0);
+    }
+
+    /// Offer a candidate that is kept with probability proportional to
+    /// `weight`; zero-weight candidates are never kept
+    pub fn offer_weighted(&mut self, index: u32, weight: f64) -> Result<(), Error> {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(Error::invalid_parameter("weight", &weight.to_string()));
+        }
+        if weight > 0.0 {
+            self.offer_key(index, weight);
+        } else {
+            self.seen += 1;
+        }
+        Ok(())
+    }
+
+    /// Indices currently sampled, in ascending order
+    pub fn sample(&self) -> Vec<u32> {
+        let mut indices: Vec<u32> = self.kept.iter().map(|&(_, index)| index).collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    /// Number of candidates offered so far
+    #[wasm_bindgen(getter)]
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// Seed after the draws made so far
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+}
+
+impl Reservoir {
+    fn offer_key(&mut self, index: u32, weight: f64) {
+        self.seen += 1;
+        if self.capacity == 0 {
+            return;
+        }
+        // 1 - u lies in (0, 1], keeping the logarithm finite
+        let key = (1.0 - to_unit_f64(self.seed.step())).ln() / weight;
+        if self.kept.len() < self.capacity {
+            self.kept.push((key, index));
+            return;
+        }
+        let (smallest, _) =
+            self.kept
+                .iter()
+                .enumerate()
+                .fold((0, f64::INFINITY), |(at, min), (i, &(key, _))| {
+                    if key < min {
+                        (i, key)
+                    } else {
+                        (at, min)
+                    }
+                });
+        if key > self.kept[smallest].0 {
+            self.kept[smallest] = (key, index);
+        }
+    }
+}
+
+/// Weighted choice over a fixed set of categories using Walker's alias
+/// method (Vose's construction). Building is O(n); each sample takes one
+/// draw and O(1) time regardless of the number of categories.
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct WeightedSampler {
+    /// Probability of keeping each column's own category
+    keep: Vec<f64>,
+    /// Category used for the rest of each column
+    alias: Vec<u32>,
+}
+
+impl WeightedSampler {
+    /// Advance in place and pick a category: the high half of the product
+    /// selects a column and the low half decides between it and its alias
+    pub(crate) fn step_sample(&self, seed: &mut Seed) -> u32 {
+        let product = seed.step() as u128 * self.keep.len() as u128;
+        let column = (product >> 64) as usize;
+        if to_unit_f64(product as u64) < self.keep[column] {
+            column as u32
+        } else {
+            self.alias[column]
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl WeightedSampler {
+    /// Build a sampler choosing index `i` with probability proportional to
+    /// `weights[i]`
+    #[wasm_bindgen(constructor)]
+    pub fn new(weights: &[f64]) -> Result<WeightedSampler, Error> {
+        let cumulative = cumulative_weights(weights)?;
+        let total = cumulative[cumulative.len() - 1];
+        let n = weights.len();
+
+        let mut keep: Vec<f64> = weights.iter().map(|w| w * n as f64 / total).collect();
+        let mut alias: Vec<u32> = (0..n as u32).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| keep[i] < 1.0);
+
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            alias[less] = more as u32;
+            keep[more] -= 1.0 - keep[less];
+            if keep[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+        // Whatever remains is 1 up to rounding error
+        for i in small.into_iter().chain(large) {
+            keep[i] = 1.0;
+        }
+        Ok(WeightedSampler { keep, alias })
+    }
+
+    /// Number of categories
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.keep.len()
+    }
+
+    /// Pick one index
+    pub fn sample(&self, seed: &Seed) -> SeedAndU32 {
+        let mut seed = seed.clone();
+        let value = self.step_sample(&mut seed);
+        SeedAndU32 { seed, value }
+    }
+
+    /// Pick `count` indices independently
+    pub fn sample_batch(&self, seed: &Seed, count: u32) -> BatchU32Result {
+        let mut final_seed = seed.clone();
+        let values = (0..count)
+            .map(|_| self.step_sample(&mut final_seed))
+            .collect();
+        BatchU32Result { values, final_seed }
+    }
+}
@@ -0,0 +1,338 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::{
+    check_probability, to_range_i64, to_unit_f32, to_unit_f64, Seed, SeedAndF32, SeedAndF64,
+    SeedAndI64, SeedAndValue,
+};
+
+/// Module-wide multiplier applied to every edge-case probability, stored as
+/// f64 bits. Defaults to 1.0.
+static EDGE_CASE_SCALE: AtomicU64 = AtomicU64::new(0x3ff0_0000_0000_0000);
+
+/// Scale all edge-case and special-value injection rates at once.
+/// A scale of 0 disables injection, 1 leaves rates unchanged, and larger
+/// values increase them (each effective rate is capped at 1).
+#[wasm_bindgen]
+pub fn set_edge_case_scale(scale: f64) -> Result<(), Error> {
+    if !scale.is_finite() || scale < 0.0 {
+        return Err(Error::invalid_parameter(
+            "edge case scale",
+            &scale.to_string(),
+        ));
+    }
+    EDGE_CASE_SCALE.store(scale.to_bits(), Ordering::Relaxed);
+    Ok(())
+}
+
+/// Current module-wide edge-case scale
+#[wasm_bindgen]
+pub fn edge_case_scale() -> f64 {
+    f64::from_bits(EDGE_CASE_SCALE.load(Ordering::Relaxed))
+}
+
+/// Apply the module-wide scale to a per-call probability
+fn scaled_probability(p: f64) -> f64 {
+    (p * edge_case_scale()).min(1.0)
+}
+
+/// Special f64 values: non-finite, signed zeros, subnormals, extremes and
+/// exact integers at the limit of the 53-bit mantissa
+pub(crate) const SPECIAL_F64: [f64; 15] = [
+    f64::NAN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    0.0,
+    -0.0,
+    f64::MIN_POSITIVE,
+    f64::from_bits(1),                     // smallest subnormal
+    f64::from_bits(0x000f_ffff_ffff_ffff), // largest subnormal
+    f64::EPSILON,
+    1.0,
+    -1.0,
+    9007199254740991.0, // Number.MAX_SAFE_INTEGER
+    9007199254740992.0, // 2^53
+    f64::MAX,
+    f64::MIN,
+];
+
+/// Special f32 values, mirroring `SPECIAL_F64` for single precision
+pub(crate) const SPECIAL_F32: [f32; 15] = [
+    f32::NAN,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    0.0,
+    -0.0,
+    f32::MIN_POSITIVE,
+    f32::from_bits(1),           // smallest subnormal
+    f32::from_bits(0x007f_ffff), // largest subnormal
+    f32::EPSILON,
+    1.0,
+    -1.0,
+    16777215.0, // 2^24 - 1
+    16777216.0, // 2^24
+    f32::MAX,
+    f32::MIN,
+];
+
+/// Boundary values for the inclusive range [min, max], in priority order.
+/// Candidates outside the range are dropped and duplicates removed.
+pub(crate) fn integer_edges(min: i64, max: i64) -> Vec<i64> {
+    let candidates = [
+        Some(0),
+        Some(1),
+        Some(-1),
+        Some(min),
+        Some(max),
+        min.checked_add(1),
+        max.checked_sub(1),
+    ];
+
+    let mut edges = Vec::with_capacity(candidates.len());
+    for value in candidates.into_iter().flatten() {
+        if value >= min && value <= max && !edges.contains(&value) {
+            edges.push(value);
+        }
+    }
+    edges
+}
+
+/// Bytes that tend to break parsers: NUL, 0xFF, UTF-8 continuation bytes,
+/// lead bytes that are never valid (0xC0, 0xC1, 0xF5..) and high-bit patterns
+pub(crate) const BOUNDARY_BYTES: [u8; 12] = [
+    0x00, 0xff, 0x80, 0xbf, 0xc0, 0xc1, 0xe0, 0xf0, 0xf5, 0xfe, 0x7f, 0xaa,
+];
+
+/// Common sentinel values that tend to expose truncation bugs
+const SENTINELS: [i64; 6] = [0xff, 0x7fff, 0xffff, 0x7fff_ffff, 0xffff_ffff, i64::MAX];
+
+/// Powers of two (and their negations) with their ±1 neighbours, plus common
+/// sentinels, restricted to [min, max]. This covers the 2^31, 2^32 and 2^53
+/// neighbourhoods where overflow and truncation bugs cluster.
+pub(crate) fn magic_values(min: i64, max: i64) -> Vec<i64> {
+    let mut values = Vec::new();
+    let mut push = |value: i64| {
+        if value >= min && value <= max && !values.contains(&value) {
+            values.push(value);
+        }
+    };
+
+    for shift in 0..63 {
+        let power = 1i64 << shift;
+        for value in [power - 1, power, power + 1] {
+            push(value);
+            push(-value);
+        }
+    }
+    push(i64::MIN);
+    for sentinel in SENTINELS {
+        push(sentinel);
+    }
+    values
+}
+
+impl Seed {
+    /// With probability `p` (after applying the module-wide edge-case scale),
+    /// pick one of `specials`. Returns the seed to continue from and the
+    /// picked value, or `None` when the caller should sample normally. When
+    /// the effective probability is zero no decision draw is made, so the
+    /// stream matches the unbiased generator exactly.
+    pub(crate) fn pick_special<T: Copy>(&self, p: f64, specials: &[T]) -> (Seed, Option<T>) {
+        if specials.is_empty() {
+            return (self.clone(), None);
+        }
+        self.pick_special_with(p, |seed| {
+            let pick = seed.next_bounded(specials.len() as u64);
+            (pick.seed, specials[pick.value as usize])
+        })
+    }
+
+    /// As `pick_special`, but `pick` draws the special itself and only runs
+    /// once the decision draw has chosen one, so callers can skip building
+    /// candidate lists that are rarely used. There must be at least one
+    /// special to pick.
+    pub(crate) fn pick_special_with<T>(
+        &self,
+        p: f64,
+        pick: impl FnOnce(Seed) -> (Seed, T),
+    ) -> (Seed, Option<T>) {
+        let p = scaled_probability(p);
+        if p <= 0.0 {
+            return (self.clone(), None);
+        }
+
+        let decision = self.next_u64();
+        if to_unit_f64(decision.value) < p {
+            let (seed, special) = pick(decision.seed);
+            (seed, Some(special))
+        } else {
+            (decision.seed, None)
+        }
+    }
+
+    /// Advance in place, returning one of `specials` with probability `p`
+    /// as `pick_special` does
+    pub(crate) fn step_special<T: Copy>(&mut self, p: f64, specials: &[T]) -> Option<T> {
+        let (next, pick) = self.pick_special(p, specials);
+        *self = next;
+        pick
+    }
+
+    /// Try each `(probability, candidates)` bias in order, returning the first
+    /// value picked, or `None` when the caller should sample normally
+    pub(crate) fn pick_biased(&self, biases: &[(f64, &[i64])]) -> (Seed, Option<i64>) {
+        let mut seed = self.clone();
+        for &(p, candidates) in biases {
+            let (next, pick) = seed.pick_special(p, candidates);
+            if pick.is_some() {
+                return (next, pick);
+            }
+            seed = next;
+        }
+        (seed, None)
+    }
+
+    /// Draw a value in [min, max], picking from `edges` with probability `p`
+    pub(crate) fn next_range_with_edges(
+        &self,
+        min: i64,
+        max: i64,
+        p: f64,
+        edges: &[i64],
+    ) -> (Seed, i64) {
+        match self.pick_special(p, edges) {
+            (seed, Some(edge)) => (seed, edge),
+            (seed, None) => {
+                let result = seed.next_u64();
+                (result.seed, to_range_i64(result.value, min, max))
+            }
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Generate a value in [0, bound), injecting boundary values
+    /// (0, 1, bound-2, bound-1) with probability `edge_probability`
+    #[wasm_bindgen]
+    pub fn next_bounded_biased(
+        &self,
+        bound: u64,
+        edge_probability: f64,
+    ) -> Result<SeedAndValue, Error> {
+        if bound == 0 {
+            return Err(Error::invalid_parameter("bound", "0"));
+        }
+        let p = check_probability("edge_probability", edge_probability)?;
+
+        // Boundaries only fit in i64 when the bound does; the top half of
+        // the u64 range picks from the same edges as u64 values instead
+        if bound > i64::MAX as u64 {
+            return Ok(match self.pick_special(p, &[0, 1, bound - 1, bound - 2]) {
+                (seed, Some(value)) => SeedAndValue { seed, value },
+                (seed, None) => seed.next_bounded(bound),
+            });
+        }
+
+        let max = bound as i64 - 1;
+        let (seed, value) = self.next_range_with_edges(0, max, p, &integer_edges(0, max));
+        Ok(SeedAndValue {
+            seed,
+            value: value as u64,
+        })
+    }
+
+    /// Generate a value in the inclusive range [min, max], injecting boundary
+    /// values (0, ±1, min, max, min+1, max-1) with probability `edge_probability`
+    #[wasm_bindgen]
+    pub fn next_range_biased(
+        &self,
+        min: i64,
+        max: i64,
+        edge_probability: f64,
+    ) -> Result<SeedAndI64, Error> {
+        if min > max {
+            return Err(Error::invalid_parameter(
+                "range",
+                &format!("[{min}, {max}]"),
+            ));
+        }
+        let p = check_probability("edge_probability", edge_probability)?;
+
+        let (seed, value) = self.next_range_with_edges(min, max, p, &integer_edges(min, max));
+        Ok(SeedAndI64 { seed, value })
+    }
+
+    /// Generate a value in the inclusive range [min, max], injecting powers of
+    /// two, their neighbours and sentinels such as 0xFFFF and 0x7FFFFFFF with
+    /// probability `magic_probability`
+    #[wasm_bindgen]
+    pub fn next_range_magic(
+        &self,
+        min: i64,
+        max: i64,
+        magic_probability: f64,
+    ) -> Result<SeedAndI64, Error> {
+        if min > max {
+            return Err(Error::invalid_parameter(
+                "range",
+                &format!("[{min}, {max}]"),
+            ));
+        }
+        let p = check_probability("magic_probability", magic_probability)?;
+
+        let (seed, value) = self.next_range_with_edges(min, max, p, &magic_values(min, max));
+        Ok(SeedAndI64 { seed, value })
+    }
+
+    /// Overwrite bytes of `data` in place with structure-breaking bytes (0x00,
+    /// 0xFF, UTF-8 continuation bytes, invalid lead bytes, high-bit patterns),
+    /// each position being replaced with probability `rate`
+    #[wasm_bindgen]
+    pub fn inject_boundary_bytes(&self, data: &mut [u8], rate: f64) -> Result<Seed, Error> {
+        let p = check_probability("rate", rate)?;
+
+        let mut seed = self.clone();
+        for byte in data.iter_mut() {
+            let (next, pick) = seed.pick_special(p, &BOUNDARY_BYTES);
+            if let Some(boundary) = pick {
+                *byte = boundary;
+            }
+            seed = next;
+        }
+        Ok(seed)
+    }
+
+    /// Generate a float in [0, 1), replacing it with a special value (NaN,
+    /// ±Infinity, -0, subnormals, exact integers) with probability
+    /// `special_probability`
+    #[wasm_bindgen]
+    pub fn next_f64_biased(&self, special_probability: f64) -> Result<SeedAndF64, Error> {
+        let p = check_probability("special_probability", special_probability)?;
+
+        let (seed, value) = match self.pick_special(p, &SPECIAL_F64) {
+            (seed, Some(special)) => (seed, special),
+            (seed, None) => {
+                let result = seed.next_u64();
+                (result.seed, to_unit_f64(result.value))
+            }
+        };
+        Ok(SeedAndF64 { seed, value })
+    }
+
+    /// Single-precision variant of `next_f64_biased`
+    #[wasm_bindgen]
+    pub fn next_f32_biased(&self, special_probability: f64) -> Result<SeedAndF32, Error> {
+        let p = check_probability("special_probability", special_probability)?;
+
+        let (seed, value) = match self.pick_special(p, &SPECIAL_F32) {
+            (seed, Some(special)) => (seed, special),
+            (seed, None) => {
+                let result = seed.next_u64();
+                (result.seed, to_unit_f32(result.value))
+            }
+        };
+        Ok(SeedAndF32 { seed, value })
+    }
+}
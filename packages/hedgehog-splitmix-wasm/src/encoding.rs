@@ -0,0 +1,119 @@
+use crate::error::Error;
+
+/// Append `value` as an unsigned LEB128 varint
+pub(crate) fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Read an unsigned LEB128 varint starting at `*position`, advancing it
+pub(crate) fn read_varint(bytes: &[u8], position: &mut usize) -> Result<u64, Error> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes
+            .get(*position)
+            .ok_or_else(|| Error::invalid_encoding("unexpected end of input in varint"))?;
+        *position += 1;
+
+        let bits = (byte & 0x7f) as u64;
+        if shift == 63 && bits > 1 {
+            return Err(Error::invalid_encoding("varint overflows u64"));
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(Error::invalid_encoding("varint longer than 10 bytes"))
+}
+
+/// Append the difference `value - previous` as a zigzag varint, so small
+/// steps in either direction take a single byte
+pub(crate) fn write_delta(out: &mut Vec<u8>, previous: u64, value: u64) {
+    let delta = value.wrapping_sub(previous) as i64;
+    write_varint(out, ((delta << 1) ^ (delta >> 63)) as u64);
+}
+
+/// Read a difference written by `write_delta` and apply it to `previous`
+pub(crate) fn read_delta(bytes: &[u8], position: &mut usize, previous: u64) -> Result<u64, Error> {
+    let zigzag = read_varint(bytes, position)?;
+    let delta = (zigzag >> 1) ^ (zigzag & 1).wrapping_neg();
+    Ok(previous.wrapping_add(delta))
+}
+
+/// Read a single byte at `*position`, advancing it
+pub(crate) fn read_u8(bytes: &[u8], position: &mut usize) -> Result<u8, Error> {
+    let byte = *bytes
+        .get(*position)
+        .ok_or_else(|| Error::invalid_encoding("unexpected end of input"))?;
+    *position += 1;
+    Ok(byte)
+}
+
+/// Read `length` raw bytes starting at `*position`, advancing it
+pub(crate) fn read_bytes<'a>(
+    bytes: &'a [u8],
+    position: &mut usize,
+    length: usize,
+) -> Result<&'a [u8], Error> {
+    let slice = bytes
+        .get(*position..position.saturating_add(length))
+        .ok_or_else(|| Error::invalid_encoding("unexpected end of input"))?;
+    *position += length;
+    Ok(slice)
+}
+
+/// 64-bit FNV-1a hash, used for checksums and content fingerprints
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode bytes as unpadded base64url, safe for environment variables and
+/// command lines
+pub(crate) fn to_base64url(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buf = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (buf[0] as u32) << 16 | (buf[1] as u32) << 8 | buf[2] as u32;
+        for i in 0..chunk.len() + 1 {
+            out.push(BASE64URL[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+/// Decode unpadded base64url produced by `to_base64url`
+pub(crate) fn from_base64url(text: &str) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    let mut n = 0u32;
+    let mut bits = 0;
+    for c in text.bytes() {
+        let value = BASE64URL.iter().position(|&b| b == c).ok_or_else(|| {
+            Error::invalid_encoding(&format!("invalid character {:?}", c as char))
+        })?;
+        n = n << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((n >> bits) as u8);
+        }
+    }
+    // `to_base64url` never leaves a whole character or set padding bits over
+    if bits >= 6 || n & ((1 << bits) - 1) != 0 {
+        return Err(Error::invalid_encoding("truncated base64url"));
+    }
+    Ok(out)
+}
@@ -13,6 +13,14 @@ pub enum ErrorKind {
     InvalidFormat,
     /// Invalid parameter values
     InvalidParameter,
+    /// A replayed draw did not match the recorded trace
+    TraceDivergence,
+    /// Serialized data is malformed or uses an unsupported version
+    InvalidEncoding,
+    /// A JavaScript callback threw an exception
+    CallbackFailed,
+    /// A stepping context paused before a draw until the next `step`
+    StepPending,
 }
 
 /// Detailed error information
@@ -72,6 +80,10 @@ impl fmt::Display for ErrorKind {
             ErrorKind::BufferTooSmall => write!(f, "BufferTooSmall"),
             ErrorKind::InvalidFormat => write!(f, "InvalidFormat"),
             ErrorKind::InvalidParameter => write!(f, "InvalidParameter"),
+            ErrorKind::TraceDivergence => write!(f, "TraceDivergence"),
+            ErrorKind::InvalidEncoding => write!(f, "InvalidEncoding"),
+            ErrorKind::CallbackFailed => write!(f, "CallbackFailed"),
+            ErrorKind::StepPending => write!(f, "StepPending"),
         }
     }
 }
@@ -104,4 +116,32 @@ impl Error {
             format!("Invalid {param}: {value}"),
         )
     }
+
+    pub fn trace_divergence(index: usize, expected: &str, actual: &str) -> Error {
+        Error::new(
+            ErrorKind::TraceDivergence,
+            format!("Trace divergence at draw {index}: expected {expected}, got {actual}"),
+        )
+    }
+
+    pub fn invalid_encoding(reason: &str) -> Error {
+        Error::new(
+            ErrorKind::InvalidEncoding,
+            format!("Invalid encoding: {reason}"),
+        )
+    }
+
+    pub fn callback_failed(callback: &str, reason: &str) -> Error {
+        Error::new(
+            ErrorKind::CallbackFailed,
+            format!("{callback} callback threw: {reason}"),
+        )
+    }
+
+    pub fn step_pending(index: u64, pending: &str) -> Error {
+        Error::new(
+            ErrorKind::StepPending,
+            format!("Paused before draw {index} ({pending}); call step() to continue"),
+        )
+    }
 }
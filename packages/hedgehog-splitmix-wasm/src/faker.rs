@@ -0,0 +1,310 @@
+use wasm_bindgen::prelude::*;
+
+use crate::Seed;
+
+const FIRST_NAMES: [&str; 32] = [
+    "Ada", "Alan", "Amara", "Ana", "Ben", "Chen", "Chloe", "Dmitri", "Elena", "Emeka", "Farah",
+    "Grace", "Hana", "Ivan", "Jamal", "Kai", "Lena", "Lucas", "Maya", "Mateo", "Noah", "Olga",
+    "Priya", "Quinn", "Ravi", "Sofia", "Tariq", "Uma", "Victor", "Wei", "Yara", "Zoe",
+];
+
+const LAST_NAMES: [&str; 32] = [
+    "Adams", "Baker", "Costa", "Dubois", "Evans", "Fischer", "Garcia", "Hughes", "Ibrahim",
+    "Jensen", "Kim", "Lopez", "Mbeki", "Nakamura", "Okafor", "Patel", "Quispe", "Rossi", "Schmidt",
+    "Tanaka", "Usman", "Varga", "Wang", "Xu", "Yilmaz", "Zhang", "Novak", "Silva", "Murphy",
+    "Kowalski", "Haddad", "Larsen",
+];
+
+const STREET_NAMES: [&str; 16] = [
+    "Maple", "Oak", "Cedar", "Elm", "Pine", "Willow", "Birch", "Lake", "Hill", "River", "Park",
+    "Mill", "Church", "Station", "Meadow", "Harbor",
+];
+
+const STREET_SUFFIXES: [&str; 8] = [
+    "Street", "Avenue", "Road", "Lane", "Drive", "Court", "Way", "Place",
+];
+
+const CITIES: [&str; 16] = [
+    "Springfield",
+    "Riverton",
+    "Fairview",
+    "Lakeside",
+    "Greenville",
+    "Brookfield",
+    "Ashford",
+    "Clearwater",
+    "Oakridge",
+    "Westbury",
+    "Northgate",
+    "Kingsport",
+    "Millbrook",
+    "Stonehaven",
+    "Bayview",
+    "Elmstead",
+];
+
+/// Domains reserved for documentation by RFC 2606, so generated addresses
+/// can never reach a real mailbox
+const EMAIL_DOMAINS: [&str; 3] = ["example.com", "example.org", "example.net"];
+
+const LOREM_WORDS: [&str; 48] = [
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "ex",
+    "ea",
+    "commodo",
+    "consequat",
+    "duis",
+    "aute",
+    "irure",
+    "in",
+    "reprehenderit",
+    "voluptate",
+    "velit",
+    "esse",
+    "cillum",
+    "fugiat",
+    "nulla",
+    "pariatur",
+    "excepteur",
+    "sint",
+];
+
+/// Kinds of fixture data produced by `fake` and `fake_batch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum FakeKind {
+    FirstName = 0,
+    LastName = 1,
+    /// First and last name separated by a space
+    FullName = 2,
+    /// `first.last<nn>@example.{com,org,net}`
+    Email = 3,
+    /// North American number in the fictional 555-01xx range
+    Phone = 4,
+    /// House number, street name and suffix
+    StreetAddress = 5,
+    City = 6,
+    /// Five-digit postal code
+    PostalCode = 7,
+    LoremWord = 8,
+    /// 4 to 12 lorem words, capitalized and ending in a period
+    LoremSentence = 9,
+    /// 3 to 6 lorem sentences
+    LoremParagraph = 10,
+}
+
+impl Seed {
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.step_below(items.len() as u64) as usize]
+    }
+
+    /// Inclusive range [min, max] for small counts
+    fn step_between(&mut self, min: u64, max: u64) -> u64 {
+        min + self.step_below(max - min + 1)
+    }
+
+    fn push_sentence(&mut self, out: &mut String) {
+        let words = self.step_between(4, 12);
+        for i in 0..words {
+            let word = self.pick(&LOREM_WORDS);
+            if i == 0 {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    out.extend(first.to_uppercase());
+                    out.push_str(chars.as_str());
+                }
+            } else {
+                out.push(' ');
+                out.push_str(word);
+            }
+        }
+        out.push('.');
+    }
+
+    /// Advance in place, appending one value of `kind` to `out`
+    fn push_fake(&mut self, kind: FakeKind, out: &mut String) {
+        match kind {
+            FakeKind::FirstName => out.push_str(self.pick(&FIRST_NAMES)),
+            FakeKind::LastName => out.push_str(self.pick(&LAST_NAMES)),
+            FakeKind::FullName => {
+                out.push_str(self.pick(&FIRST_NAMES));
+                out.push(' ');
+                out.push_str(self.pick(&LAST_NAMES));
+            }
+            FakeKind::Email => {
+                let first = self.pick(&FIRST_NAMES).to_lowercase();
+                let last = self.pick(&LAST_NAMES).to_lowercase();
+                let number = self.step_below(100);
+                let domain = self.pick(&EMAIL_DOMAINS);
+                out.push_str(&format!("{first}.{last}{number}@{domain}"));
+            }
+            FakeKind::Phone => {
+                let area = self.step_between(201, 989);
+                let line = self.step_below(100);
+                out.push_str(&format!("+1-{area}-555-01{line:02}"));
+            }
+            FakeKind::StreetAddress => {
+                let number = self.step_between(1, 9999);
+                let street = self.pick(&STREET_NAMES);
+                let suffix = self.pick(&STREET_SUFFIXES);
+                out.push_str(&format!("{number} {street} {suffix}"));
+            }
+            FakeKind::City => out.push_str(self.pick(&CITIES)),
+            FakeKind::PostalCode => out.push_str(&format!("{:05}", self.step_below(100_000))),
+            FakeKind::LoremWord => out.push_str(self.pick(&LOREM_WORDS)),
+            FakeKind::LoremSentence => self.push_sentence(out),
+            FakeKind::LoremParagraph => {
+                let sentences = self.step_between(3, 6);
+                for i in 0..sentences {
+                    if i > 0 {
+                        out.push(' ');
+                    }
+                    self.push_sentence(out);
+                }
+            }
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Generate one fixture value of `kind`
+    #[wasm_bindgen]
+    pub fn fake(&self, kind: FakeKind) -> SeedAndString {
+        let mut seed = self.clone();
+        let mut value = String::new();
+        seed.push_fake(kind, &mut value);
+        SeedAndString { seed, value }
+    }
+
+    /// Generate `count` fixture values of `kind` into one framed buffer,
+    /// each value written as `[4 bytes length LE][UTF-8 bytes]`. The stream
+    /// matches `count` chained `fake` calls.
+    #[wasm_bindgen]
+    pub fn fake_batch(&self, kind: FakeKind, count: u32) -> FakeBatch {
+        self.string_batch(count, |seed, value| seed.push_fake(kind, value))
+    }
+}
+
+impl Seed {
+    /// Generate `count` strings with `push`, which advances the seed and
+    /// appends one value, into a framed batch
+    pub(crate) fn string_batch(
+        &self,
+        count: u32,
+        mut push: impl FnMut(&mut Seed, &mut String),
+    ) -> FakeBatch {
+        let mut seed = self.clone();
+        let mut framed = Vec::new();
+        let mut value = String::new();
+        for _ in 0..count {
+            value.clear();
+            push(&mut seed, &mut value);
+            framed.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            framed.extend_from_slice(value.as_bytes());
+        }
+        FakeBatch {
+            framed,
+            count,
+            final_seed: seed,
+        }
+    }
+}
+
+/// Return type for operations that produce a seed and a string
+#[wasm_bindgen]
+pub struct SeedAndString {
+    pub(crate) seed: Seed,
+    pub(crate) value: String,
+}
+
+#[wasm_bindgen]
+impl SeedAndString {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+}
+
+/// Length-prefixed batch of UTF-8 strings produced by `fake_batch` and
+/// `locale_batch`
+#[wasm_bindgen]
+pub struct FakeBatch {
+    framed: Vec<u8>,
+    count: u32,
+    final_seed: Seed,
+}
+
+#[wasm_bindgen]
+impl FakeBatch {
+    /// The framed bytes: `[4 bytes length LE][UTF-8 bytes]` per value
+    #[wasm_bindgen(getter)]
+    pub fn framed(&self) -> Vec<u8> {
+        self.framed.clone()
+    }
+
+    /// Number of values in the batch
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// The values decoded into strings, for callers that do not need the
+    /// framed form
+    #[wasm_bindgen(getter)]
+    pub fn values(&self) -> Vec<String> {
+        let mut values = Vec::with_capacity(self.count as usize);
+        let mut position = 0;
+        while position + 4 <= self.framed.len() {
+            let mut length = [0; 4];
+            length.copy_from_slice(&self.framed[position..position + 4]);
+            let start = position + 4;
+            let end = start + u32::from_le_bytes(length) as usize;
+            let Some(bytes) = self.framed.get(start..end) else {
+                break;
+            };
+            values.push(String::from_utf8_lossy(bytes).into_owned());
+            position = end;
+        }
+        values
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn final_seed(&self) -> Seed {
+        self.final_seed.clone()
+    }
+}
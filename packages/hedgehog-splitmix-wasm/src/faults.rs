@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::{check_probability, to_unit_f64, Seed};
+
+/// Calls of one fault-injection channel that should fail, e.g. "fail the
+/// 3rd and 7th I/O call", decided from a test case's seed
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct FaultPlan {
+    /// Zero-based indices of failing calls, ascending
+    indices: Vec<u32>,
+    calls: u32,
+    /// Calls made so far through `next_call`
+    position: u32,
+}
+
+#[wasm_bindgen]
+impl FaultPlan {
+    /// Zero-based indices of the calls that should fail, ascending
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u32> {
+        self.indices.clone()
+    }
+
+    /// Number of calls the plan covers; calls beyond it never fail
+    #[wasm_bindgen(getter)]
+    pub fn calls(&self) -> u32 {
+        self.calls
+    }
+
+    /// Number of failing calls
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether call `index` (zero-based) should fail
+    pub fn should_fail(&self, index: u32) -> bool {
+        self.indices.binary_search(&index).is_ok()
+    }
+
+    /// Count one more call and return whether it should fail, so an I/O
+    /// wrapper can consult the plan without tracking indices itself
+    pub fn next_call(&mut self) -> bool {
+        let fail = self.should_fail(self.position);
+        self.position = self.position.saturating_add(1);
+        fail
+    }
+
+    /// Calls counted by `next_call` so far
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// Restart `next_call` counting from the first call
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Plan faults for `channel` (e.g. `"io"` or `"network"`) over `calls`
+    /// calls, each failing independently with `probability`. The plan is
+    /// derived from the seed's state and gamma without advancing it, so
+    /// adding fault injection leaves the generated data, and any repro token
+    /// for it, unchanged; distinct channels get independent plans.
+    #[wasm_bindgen]
+    pub fn fault_plan(
+        &self,
+        channel: &str,
+        calls: u32,
+        probability: f64,
+    ) -> Result<FaultPlan, Error> {
+        let probability = check_probability("probability", probability)?;
+        let mut seed = self.named(channel);
+        let mut indices = Vec::new();
+        if probability >= 1.0 {
+            indices.extend(0..calls);
+        } else if probability > 0.0 {
+            // Skip geometric gaps between faults, so sparse plans over many
+            // calls take one draw per fault
+            let log_miss = (-probability).ln_1p();
+            let mut next = 0u64;
+            loop {
+                let u = 1.0 - to_unit_f64(seed.step());
+                next = next.saturating_add((u.ln() / log_miss).floor() as u64);
+                if next >= calls as u64 {
+                    break;
+                }
+                indices.push(next as u32);
+                next += 1;
+            }
+        }
+        Ok(FaultPlan {
+            indices,
+            calls,
+            position: 0,
+        })
+    }
+
+    /// Plan exactly `faults` failing calls for `channel` among `calls`
+    /// calls, each subset equally likely; derived like `fault_plan`
+    #[wasm_bindgen]
+    pub fn fault_plan_exact(
+        &self,
+        channel: &str,
+        calls: u32,
+        faults: u32,
+    ) -> Result<FaultPlan, Error> {
+        if faults > calls {
+            return Err(Error::invalid_parameter(
+                "faults",
+                &format!("{faults}, expected at most calls ({calls})"),
+            ));
+        }
+        let mut seed = self.named(channel);
+        // Floyd's algorithm: one draw per fault whatever the number of calls
+        let mut chosen = HashSet::with_capacity(faults as usize);
+        for j in (calls - faults)..calls {
+            let candidate = seed.step_below(j as u64 + 1) as u32;
+            if !chosen.insert(candidate) {
+                chosen.insert(j);
+            }
+        }
+        let mut indices: Vec<u32> = chosen.into_iter().collect();
+        indices.sort_unstable();
+        Ok(FaultPlan {
+            indices,
+            calls,
+            position: 0,
+        })
+    }
+}
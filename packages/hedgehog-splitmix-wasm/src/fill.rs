@@ -0,0 +1,1129 @@
+use wasm_bindgen::prelude::*;
+
+use crate::edge::{integer_edges, magic_values, BOUNDARY_BYTES, SPECIAL_F32, SPECIAL_F64};
+use crate::error::Error;
+use crate::metrics;
+use crate::quasi::{QuasiSampler, Sequence};
+use crate::{check_probability, to_range_i64, to_unit_f32, to_unit_f64, DataFormat, Seed};
+
+/// Size of the buffer header: 1 byte format + 8 bytes count
+pub(crate) const HEADER_SIZE: u64 = 9;
+
+/// Check that `buffer` holds `count` elements of `format` and write the
+/// buffer protocol header
+pub(crate) fn write_header(buffer: &mut [u8], format: DataFormat, count: u64) -> Result<(), Error> {
+    let required = HEADER_SIZE.saturating_add(count.saturating_mul(format.bytes_per_element()));
+    if (buffer.len() as u64) < required {
+        return Err(Error::buffer_too_small(required, buffer.len()));
+    }
+    buffer[0] = format as u8;
+    buffer[1..9].copy_from_slice(&count.to_le_bytes());
+    Ok(())
+}
+
+/// Practical upper limit on buffer size (1GB)
+const PRACTICAL_MAX_BUFFER: u64 = 1024 * 1024 * 1024;
+
+/// Corner cases for F64LE: zero, the smallest step above zero, the midpoint
+/// and the largest value below one
+const F64_CORNERS: [f64; 4] = [
+    0.0,
+    1.0 / (1u64 << 53) as f64,
+    0.5,
+    1.0 - 1.0 / (1u64 << 53) as f64,
+];
+
+/// Corner cases for F32LE, mirroring `F64_CORNERS` at 24-bit precision
+const F32_CORNERS: [f32; 4] = [
+    0.0,
+    1.0 / (1u32 << 24) as f32,
+    0.5,
+    1.0 - 1.0 / (1u32 << 24) as f32,
+];
+
+/// Integers around ±Number.MAX_SAFE_INTEGER not in `SPECIAL_F64`, where
+/// JavaScript integer arithmetic silently starts rounding
+const SAFE_INTEGER_F64: [f64; 5] = [
+    9007199254740990.0,
+    9007199254740994.0,
+    -9007199254740991.0,
+    -9007199254740992.0,
+    -9007199254740994.0,
+];
+
+/// Corner cases for U128LE: the ends of the range and the 2^64 boundary
+/// where a carry moves into the high half
+const U128_CORNERS: [u128; 6] = [0, 1, u128::MAX, u128::MAX - 1, u64::MAX as u128, 1 << 64];
+
+/// Start and length of the surrogate block, which holds no scalar values
+pub(crate) const SURROGATE_START: u32 = 0xd800;
+pub(crate) const SURROGATE_COUNT: u32 = 0x800;
+
+/// Corner cases for CharU32: the ends of the ASCII, BMP and full ranges and
+/// the scalar values either side of the surrogate block
+const CHAR_CORNERS: [u32; 8] = [0, 0x7f, 0x80, 0xd7ff, 0xe000, 0xffff, 0x10000, 0x10ffff];
+
+/// Boundary values for the unsigned range [0, max], in priority order
+fn unsigned_edges(max: u64) -> Vec<u64> {
+    let mut edges = Vec::with_capacity(4);
+    for value in [0, 1, max, max.saturating_sub(1)] {
+        if value <= max && !edges.contains(&value) {
+            edges.push(value);
+        }
+    }
+    edges
+}
+
+/// Magic values for the unsigned range [0, max]: the signed magic values
+/// that are non-negative, plus the 2^63 and 2^64 neighbourhoods
+fn unsigned_magic(max: u64) -> Vec<u64> {
+    let mut values: Vec<u64> = magic_values(0, max.min(i64::MAX as u64) as i64)
+        .into_iter()
+        .map(|value| value as u64)
+        .collect();
+    for value in [1 << 63, (1 << 63) + 1, u64::MAX - 1, u64::MAX] {
+        if value <= max && !values.contains(&value) {
+            values.push(value);
+        }
+    }
+    values
+}
+
+/// Most decimal places DecimalI64 supports; 10^19 overflows an i64
+pub(crate) const MAX_DECIMAL_SCALE: u32 = 18;
+
+/// Largest f64 below 1, keeping stratified samples inside [0, 1)
+const LARGEST_BELOW_ONE: f64 = 1.0 - f64::EPSILON / 2.0;
+
+/// Largest f32 below 1; rounding an f64 just under 1 to f32 can give 1.0
+const LARGEST_BELOW_ONE_F32: f32 = 1.0 - f32::EPSILON / 2.0;
+
+/// Map `unit` in [0, 1) onto [min, max). Interpolating between the ends
+/// rather than scaling the span keeps spans wider than f64::MAX finite, and
+/// results that round up to `max` are pulled back below it.
+fn unit_to_range_f64(unit: f64, min: f64, max: f64) -> f64 {
+    let value = unit * max + (1.0 - unit) * min;
+    if value >= max {
+        max.next_down().max(min)
+    } else {
+        value.max(min)
+    }
+}
+
+/// Map `unit` in [0, 1) onto [min, max) in single precision, nudging
+/// results that round to just outside the range back inside it
+fn unit_to_range_f32(unit: f32, min: f64, max: f64) -> f32 {
+    let mut value = unit_to_range_f64(unit as f64, min, max) as f32;
+    if (value as f64) < min {
+        value = value.next_up();
+    }
+    if value as f64 >= max {
+        value = value.next_down();
+    }
+    value
+}
+
+/// Optional parameters for `fill_buffer_with_params`
+#[derive(Debug, Clone, Default)]
+#[wasm_bindgen]
+pub struct FillParams {
+    bound: Option<u32>,
+    bound_u64: Option<u64>,
+    range: Option<(i64, i64)>,
+    float_range: Option<(f64, f64)>,
+    gaps: Option<(u64, u64)>,
+    float_gaps: Option<(f64, f64)>,
+    null_probability: Option<f64>,
+    scale: u32,
+    corner_cases: u32,
+    boundary_fraction: f64,
+    magic_fraction: f64,
+    special_fraction: f64,
+    true_probability: Option<f64>,
+    true_probabilities: Option<Vec<f64>>,
+    bounds: Option<Vec<u32>>,
+    sequence: Sequence,
+    dimensions: u32,
+    sequence_start: u64,
+    bmp_only: bool,
+}
+
+#[wasm_bindgen]
+impl FillParams {
+    /// Parameters matching plain `fill_buffer` with no bound
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> FillParams {
+        FillParams {
+            dimensions: 1,
+            ..FillParams::default()
+        }
+    }
+
+    /// Exclusive upper bound for integer formats
+    pub fn with_bound(mut self, bound: Option<u32>) -> FillParams {
+        self.bound = bound;
+        self
+    }
+
+    /// Per-element exclusive upper bounds for U32LE and U32BE, one per
+    /// element, so each value is bounded individually; takes precedence
+    /// over `with_bound`, and other formats reject it
+    pub fn with_bounds(mut self, bounds: Vec<u32>) -> FillParams {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Exclusive upper bound for U64LE, taking precedence over `with_bound`
+    pub fn with_bound_u64(mut self, bound: Option<u64>) -> FillParams {
+        self.bound_u64 = bound;
+        self
+    }
+
+    /// Inclusive range [min, max] for the signed formats I32LE and I64LE,
+    /// and in whole units for DecimalI64
+    pub fn with_range(mut self, min: i64, max: i64) -> FillParams {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Decimal places for DecimalI64: values are integers in units of
+    /// 10^-scale, and `with_range` is given in whole units (default 0)
+    pub fn with_scale(mut self, scale: u32) -> FillParams {
+        self.scale = scale;
+        self
+    }
+
+    /// Half-open range [min, max) for F64LE and F32LE values in place of
+    /// [0, 1); corner cases and low-discrepancy sequences are mapped onto it
+    /// too. Other formats reject it
+    pub fn with_float_range(mut self, min: f64, max: f64) -> FillParams {
+        self.float_range = Some((min, max));
+        self
+    }
+
+    /// Emit integer formats as a monotone sequence: each element is the
+    /// previous one (or the bottom of the range, for the first) plus a gap
+    /// drawn uniformly from [min_gap, max_gap]. A minimum gap of 0 gives a
+    /// sorted sequence and 1 or more a strictly increasing one.
+    pub fn with_gaps(mut self, min_gap: u64, max_gap: u64) -> FillParams {
+        self.gaps = Some((min_gap, max_gap));
+        self
+    }
+
+    /// Emit F64LE and F32LE as a sorted sequence: each element is the
+    /// previous one (or the bottom of the float range, 0 without one) plus
+    /// a gap drawn uniformly from [min_gap, max_gap), e.g. timestamps
+    pub fn with_float_gaps(mut self, min_gap: f64, max_gap: f64) -> FillParams {
+        self.float_gaps = Some((min_gap, max_gap));
+        self
+    }
+
+    /// Make elements nullable: each is null with probability `p`, recorded
+    /// in a null bitmap of `ceil(count / 8)` bytes after the values (bit
+    /// `i % 8` of byte `i / 8` set for a null element `i`), and null
+    /// elements' values are zeroed. Use `buffer_size` for the larger layout.
+    pub fn with_null_probability(mut self, p: f64) -> FillParams {
+        self.null_probability = Some(p);
+        self
+    }
+
+    /// Emit up to `corner_cases` deterministic corner values for the format
+    /// and bound before switching to random sampling
+    pub fn with_corner_cases(mut self, corner_cases: u32) -> FillParams {
+        self.corner_cases = corner_cases;
+        self
+    }
+
+    /// Fraction of integer samples placed exactly at or adjacent to the
+    /// bounds (0, 1, min, max and their neighbours), using the type's own
+    /// limits when no bound or range is set
+    pub fn with_boundary_fraction(mut self, boundary_fraction: f64) -> FillParams {
+        self.boundary_fraction = boundary_fraction;
+        self
+    }
+
+    /// Fraction of integer samples replaced by powers of two, their
+    /// neighbours and common sentinels (0xFFFF, 0x7FFFFFFF) within range
+    pub fn with_magic_fraction(mut self, magic_fraction: f64) -> FillParams {
+        self.magic_fraction = magic_fraction;
+        self
+    }
+
+    /// Fraction of float samples replaced by special values: NaN,
+    /// ±Infinity, ±0, subnormals, the extremes and the integers around
+    /// ±Number.MAX_SAFE_INTEGER (±2^24 for f32), never replacing corner samples
+    pub fn with_special_fraction(mut self, special_fraction: f64) -> FillParams {
+        self.special_fraction = special_fraction;
+        self
+    }
+
+    /// Probability of each BoolU8 or BoolPacked element being 1 (default 0.5)
+    pub fn with_true_probability(mut self, probability: f64) -> FillParams {
+        self.true_probability = Some(probability);
+        self
+    }
+
+    /// Per-element probabilities of BoolU8 or BoolPacked elements being 1,
+    /// one per element; takes precedence over `with_true_probability`
+    pub fn with_true_probabilities(mut self, probabilities: Vec<f64>) -> FillParams {
+        self.true_probabilities = Some(probabilities);
+        self
+    }
+
+    /// Sequence used for F64LE values: pseudo-random draws (default) or a
+    /// randomized low-discrepancy sequence for even coverage of the domain
+    pub fn with_sequence(mut self, sequence: Sequence) -> FillParams {
+        self.sequence = sequence;
+        self
+    }
+
+    /// Number of coordinates per low-discrepancy point; consecutive
+    /// elements fill one point before moving to the next (default 1)
+    pub fn with_dimensions(mut self, dimensions: u32) -> FillParams {
+        self.dimensions = dimensions;
+        self
+    }
+
+    /// Index of the first low-discrepancy point, to continue a sequence
+    /// across calls (default 0)
+    pub fn with_sequence_start(mut self, start: u64) -> FillParams {
+        self.sequence_start = start;
+        self
+    }
+
+    /// Restrict CharU32 values to the Basic Multilingual Plane, so each
+    /// fits in a single UTF-16 code unit
+    pub fn with_bmp_only(mut self, bmp_only: bool) -> FillParams {
+        self.bmp_only = bmp_only;
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bound(&self) -> Option<u32> {
+        self.bound
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bound_u64(&self) -> Option<u64> {
+        self.bound_u64
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn range_min(&self) -> Option<i64> {
+        self.range.map(|(min, _)| min)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn range_max(&self) -> Option<i64> {
+        self.range.map(|(_, max)| max)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn float_min(&self) -> Option<f64> {
+        self.float_range.map(|(min, _)| min)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn float_max(&self) -> Option<f64> {
+        self.float_range.map(|(_, max)| max)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min_gap(&self) -> Option<u64> {
+        self.gaps.map(|(min, _)| min)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_gap(&self) -> Option<u64> {
+        self.gaps.map(|(_, max)| max)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min_float_gap(&self) -> Option<f64> {
+        self.float_gaps.map(|(min, _)| min)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_float_gap(&self) -> Option<f64> {
+        self.float_gaps.map(|(_, max)| max)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn null_probability(&self) -> Option<f64> {
+        self.null_probability
+    }
+
+    /// Buffer size needed for `count` elements of a format under these
+    /// parameters, header and null bitmap included
+    pub fn buffer_size(&self, format_u8: u8, count: u64) -> Result<u64, Error> {
+        let format = DataFormat::fixed_width(format_u8)?;
+        Ok(HEADER_SIZE + format.data_size(count) + self.null_mask_size(count))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn corner_cases(&self) -> u32 {
+        self.corner_cases
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn boundary_fraction(&self) -> f64 {
+        self.boundary_fraction
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn magic_fraction(&self) -> f64 {
+        self.magic_fraction
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn special_fraction(&self) -> f64 {
+        self.special_fraction
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn true_probability(&self) -> Option<f64> {
+        self.true_probability
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn true_probabilities(&self) -> Option<Vec<f64>> {
+        self.true_probabilities.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bounds(&self) -> Option<Vec<u32>> {
+        self.bounds.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn sequence(&self) -> Sequence {
+        self.sequence
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn dimensions(&self) -> u32 {
+        self.dimensions
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn sequence_start(&self) -> u64 {
+        self.sequence_start
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bmp_only(&self) -> bool {
+        self.bmp_only
+    }
+}
+
+impl FillParams {
+    /// DecimalI64 range in units of 10^-scale: `range` scaled up from whole
+    /// units, or every i64 when no range is set. `None` if scaling overflows.
+    fn decimal_range(&self) -> Option<(i64, i64)> {
+        let Some((min, max)) = self.range else {
+            return Some((i64::MIN, i64::MAX));
+        };
+        let factor = 10i64.checked_pow(self.scale)?;
+        Some((min.checked_mul(factor)?, max.checked_mul(factor)?))
+    }
+
+    /// Bytes of null bitmap following the values
+    pub(crate) fn null_mask_size(&self, count: u64) -> u64 {
+        match self.null_probability {
+            Some(_) => count.div_ceil(8),
+            None => 0,
+        }
+    }
+
+    /// Inclusive value range of an integer format under these parameters,
+    /// or `None` for formats without one
+    fn integer_range(&self, format: DataFormat) -> Option<(i128, i128)> {
+        let below = |bound: u64| bound.saturating_sub(1) as i128;
+        match format {
+            DataFormat::U16LE => {
+                Some((0, self.bound.map_or(u16::MAX as i128, |b| below(b as u64))))
+            }
+            DataFormat::U32LE => {
+                Some((0, self.bound.map_or(u32::MAX as i128, |b| below(b as u64))))
+            }
+            DataFormat::U64LE => {
+                let bound = self.bound_u64.or(self.bound.map(u64::from));
+                Some((0, bound.map_or(u64::MAX as i128, below)))
+            }
+            DataFormat::I32LE => {
+                let (min, max) = self.range.unwrap_or((i32::MIN as i64, i32::MAX as i64));
+                Some((min as i128, max as i128))
+            }
+            DataFormat::I64LE => {
+                let (min, max) = self.range.unwrap_or((i64::MIN, i64::MAX));
+                Some((min as i128, max as i128))
+            }
+            DataFormat::DecimalI64 => {
+                let (min, max) = self.decimal_range()?;
+                Some((min as i128, max as i128))
+            }
+            _ => None,
+        }
+    }
+
+    /// Corner values to emit first, truncated to the requested number
+    fn corners<T>(&self, mut all: Vec<T>) -> Vec<T> {
+        all.truncate(self.corner_cases as usize);
+        all
+    }
+}
+
+/// Buffer size needed by `fill_buffer` for `count` elements of a format,
+/// header included
+#[wasm_bindgen]
+pub fn fill_buffer_size(format_u8: u8, count: u64) -> Result<u64, Error> {
+    let format = DataFormat::fixed_width(format_u8)?;
+    Ok(HEADER_SIZE + format.data_size(count))
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Fill a byte buffer using the structured protocol of `fill_buffer`,
+    /// with additional generation parameters
+    /// Buffer layout: [1 byte format][8 bytes count][data bytes...]
+    #[wasm_bindgen]
+    pub fn fill_buffer_with_params(
+        &self,
+        buffer: &mut [u8],
+        format_u8: u8,
+        count: u64,
+        params: &FillParams,
+    ) -> Result<Seed, Error> {
+        let started = metrics::start();
+        let result = self.fill_unrecorded(buffer, format_u8, count, params);
+        if let (Ok(_), Ok(format)) = (&result, DataFormat::fixed_width(format_u8)) {
+            let bytes = format.data_size(count);
+            metrics::record_fill("fill_buffer", format, count, bytes, started);
+        }
+        result
+    }
+}
+
+impl Seed {
+    fn fill_unrecorded(
+        &self,
+        buffer: &mut [u8],
+        format_u8: u8,
+        count: u64,
+        params: &FillParams,
+    ) -> Result<Seed, Error> {
+        if buffer.len() as u64 > PRACTICAL_MAX_BUFFER {
+            return Err(Error::buffer_too_large(
+                buffer.len() as u64 >> 20,
+                PRACTICAL_MAX_BUFFER >> 20,
+            ));
+        }
+
+        let format = DataFormat::fixed_width(format_u8)?;
+        // Big-endian formats are validated and generated as their
+        // little-endian layout, then byte-swapped, so both byte orders carry
+        // exactly the same values
+        let (layout, big_endian) = format.byte_order();
+        let boundary_fraction = check_probability("boundary_fraction", params.boundary_fraction)?;
+        let magic_fraction = check_probability("magic_fraction", params.magic_fraction)?;
+        let special_fraction = check_probability("special_fraction", params.special_fraction)?;
+        if let Some(p) = params.true_probability {
+            check_probability("true_probability", p)?;
+        }
+        if let Some(probabilities) = &params.true_probabilities {
+            if probabilities.len() as u64 != count {
+                return Err(Error::invalid_parameter(
+                    "true_probabilities",
+                    &format!("{} entries for {count} elements", probabilities.len()),
+                ));
+            }
+            for &p in probabilities {
+                check_probability("true_probability", p)?;
+            }
+        }
+        if let Some(bounds) = &params.bounds {
+            if layout != DataFormat::U32LE {
+                return Err(Error::invalid_parameter(
+                    "bounds",
+                    &format!("not supported for format {format_u8}"),
+                ));
+            }
+            if bounds.len() as u64 != count {
+                return Err(Error::invalid_parameter(
+                    "bounds",
+                    &format!("{} entries for {count} elements", bounds.len()),
+                ));
+            }
+            if bounds.contains(&0) {
+                return Err(Error::invalid_parameter("bounds", "0"));
+            }
+        }
+        if let (DataFormat::U16LE, Some(bound)) = (layout, params.bound) {
+            if bound > 1 << 16 {
+                return Err(Error::invalid_parameter(
+                    "bound",
+                    &format!("{bound} exceeds the 16-bit range"),
+                ));
+            }
+        }
+        if params.bound_u64 == Some(0) {
+            return Err(Error::invalid_parameter("bound_u64", "0"));
+        }
+        if params.scale > MAX_DECIMAL_SCALE {
+            return Err(Error::invalid_parameter(
+                "scale",
+                &format!("{} exceeds {MAX_DECIMAL_SCALE}", params.scale),
+            ));
+        }
+        let (min, max) = match layout {
+            DataFormat::I32LE => params.range.unwrap_or((i32::MIN as i64, i32::MAX as i64)),
+            DataFormat::DecimalI64 => match params.decimal_range() {
+                Some(range) => range,
+                None => {
+                    let (min, max) = params.range.unwrap_or_default();
+                    return Err(Error::invalid_parameter(
+                        "range",
+                        &format!("[{min}, {max}] overflows i64 at scale {}", params.scale),
+                    ));
+                }
+            },
+            _ => params.range.unwrap_or((i64::MIN, i64::MAX)),
+        };
+        let fits = match layout {
+            DataFormat::I32LE => min >= i32::MIN as i64 && max <= i32::MAX as i64,
+            _ => true,
+        };
+        if min > max || !fits {
+            return Err(Error::invalid_parameter(
+                "range",
+                &format!("[{min}, {max}]"),
+            ));
+        }
+        if let Some((min, max)) = params.float_range {
+            if !matches!(layout, DataFormat::F64LE | DataFormat::F32LE) {
+                return Err(Error::invalid_parameter(
+                    "float_range",
+                    &format!("not supported for format {format_u8}"),
+                ));
+            }
+            // F32 ranges must hold at least one f32
+            let lowest = unit_to_range_f32(0.0, min, max) as f64;
+            let holds_f32 = layout == DataFormat::F64LE
+                || (-f32::MAX as f64 <= min
+                    && max <= f32::MAX as f64
+                    && (min..max).contains(&lowest));
+            if !(min.is_finite() && max.is_finite() && min < max && holds_f32) {
+                return Err(Error::invalid_parameter(
+                    "float_range",
+                    &format!("[{min}, {max})"),
+                ));
+            }
+        }
+        let monotone = match params.gaps {
+            Some((min_gap, max_gap)) => {
+                let Some((lowest, highest)) = params.integer_range(layout) else {
+                    return Err(Error::invalid_parameter(
+                        "gaps",
+                        &format!("not supported for format {format_u8}"),
+                    ));
+                };
+                // Every sequence must fit, even one drawing the largest gap
+                // every time
+                let reach = (count as u128).checked_mul(max_gap as u128);
+                let fits = reach.is_some_and(|reach| reach <= (highest - lowest) as u128);
+                if min_gap > max_gap || !fits {
+                    return Err(Error::invalid_parameter(
+                        "gaps",
+                        &format!(
+                            "[{min_gap}, {max_gap}] for {count} elements in [{lowest}, {highest}]"
+                        ),
+                    ));
+                }
+                Some((lowest, min_gap, max_gap))
+            }
+            None => None,
+        };
+        let float_monotone = match params.float_gaps {
+            Some((min_gap, max_gap)) => {
+                if !matches!(layout, DataFormat::F64LE | DataFormat::F32LE) {
+                    return Err(Error::invalid_parameter(
+                        "float_gaps",
+                        &format!("not supported for format {format_u8}"),
+                    ));
+                }
+                let (lowest, highest) = params.float_range.unwrap_or((0.0, f64::INFINITY));
+                // As with integer gaps, the largest gaps must still fit,
+                // below the top of the half-open range
+                let reach = lowest + count as f64 * max_gap;
+                if !(0.0 <= min_gap && min_gap <= max_gap && reach.is_finite() && reach < highest) {
+                    return Err(Error::invalid_parameter(
+                        "float_gaps",
+                        &format!(
+                            "[{min_gap}, {max_gap}) for {count} elements from {lowest} to {highest}"
+                        ),
+                    ));
+                }
+                Some((lowest, highest, min_gap, max_gap))
+            }
+            None => None,
+        };
+        let null_probability = match params.null_probability {
+            Some(p) => Some(check_probability("null_probability", p)?),
+            None => None,
+        };
+        let bytes_per_element = format.bytes_per_element();
+        let data_size = format.data_size(count) as usize;
+        let required_size = HEADER_SIZE + data_size as u64 + params.null_mask_size(count);
+
+        if buffer.len() < required_size as usize {
+            return Err(Error::buffer_too_small(required_size, buffer.len()));
+        }
+
+        // Write header
+        buffer[0] = format_u8;
+        buffer[1..9].copy_from_slice(&count.to_le_bytes());
+
+        // Generate data
+        let mut seed = self.clone();
+        let data_start = HEADER_SIZE as usize;
+
+        match format {
+            _ if monotone.is_some() => {
+                let (mut value, min_gap, max_gap) = monotone.unwrap_or_default();
+                let width = bytes_per_element as usize;
+                for i in 0..count as usize {
+                    let gap = match (max_gap - min_gap).checked_add(1) {
+                        Some(span) => min_gap + seed.step_below(span),
+                        None => seed.step(),
+                    };
+                    value += gap as i128;
+                    // Two's complement truncation writes signed and unsigned
+                    // values alike
+                    let offset = data_start + i * width;
+                    buffer[offset..offset + width].copy_from_slice(&value.to_le_bytes()[..width]);
+                }
+            }
+            _ if float_monotone.is_some() => {
+                let (mut value, highest, min_gap, max_gap) = float_monotone.unwrap_or_default();
+                for i in 0..count as usize {
+                    value += min_gap + to_unit_f64(seed.step()) * (max_gap - min_gap);
+                    if layout == DataFormat::F32LE {
+                        // Rounding to f32 is monotone, so order survives, but
+                        // it may reach the top of the range
+                        let mut narrow = value as f32;
+                        while narrow as f64 >= highest {
+                            narrow = narrow.next_down();
+                        }
+                        let offset = data_start + i * 4;
+                        buffer[offset..offset + 4].copy_from_slice(&narrow.to_le_bytes());
+                    } else {
+                        // Summing the gaps may round up onto the top
+                        let value = value.min(highest.next_down());
+                        let offset = data_start + i * 8;
+                        buffer[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+                    }
+                }
+            }
+            DataFormat::U32LE | DataFormat::U32BE => {
+                let max_below = |bound: u32| {
+                    if bound == u32::MAX {
+                        u32::MAX as i64
+                    } else {
+                        bound as i64 - 1
+                    }
+                };
+                let to_bounded = |output: u64, bound: u32| {
+                    if bound == u32::MAX {
+                        output as u32
+                    } else {
+                        ((output as u128 * bound as u128) >> 64) as u32
+                    }
+                };
+                let shared_bound = params.bound.unwrap_or(u32::MAX);
+                let shared_edges = integer_edges(0, max_below(shared_bound));
+                let shared_magic = magic_values(0, max_below(shared_bound));
+                let biased = boundary_fraction > 0.0 || magic_fraction > 0.0;
+                for i in 0..count as usize {
+                    let bound = params
+                        .bounds
+                        .as_ref()
+                        .map_or(shared_bound, |bounds| bounds[i]);
+                    // Per-element bounds need their own edges, computed only
+                    // for corner cases; biased draws look candidates up below
+                    let own;
+                    let edges = match params.bounds {
+                        Some(_) if i < params.corner_cases as usize => {
+                            own = integer_edges(0, max_below(bound));
+                            &own
+                        }
+                        _ => &shared_edges,
+                    };
+                    let bounded = if let Some(&corner) =
+                        edges.get(i).filter(|_| i < params.corner_cases as usize)
+                    {
+                        corner as u32
+                    } else if biased && params.bounds.is_some() {
+                        // The magic values below a bound are those of the
+                        // shared list, in the same order, so they are counted
+                        // and indexed in place rather than collected
+                        let max = max_below(bound);
+                        let (next, pick) = seed.pick_special_with(boundary_fraction, |seed| {
+                            let edges = integer_edges(0, max);
+                            let pick = seed.next_bounded(edges.len() as u64);
+                            (pick.seed, edges[pick.value as usize])
+                        });
+                        let (next, pick) = match pick {
+                            Some(_) => (next, pick),
+                            None => next.pick_special_with(magic_fraction, |seed| {
+                                let mut magic = shared_magic.iter().filter(|&&value| value <= max);
+                                let pick = seed.next_bounded(magic.clone().count() as u64);
+                                (
+                                    pick.seed,
+                                    magic.nth(pick.value as usize).copied().unwrap_or(0),
+                                )
+                            }),
+                        };
+                        seed = next;
+                        match pick {
+                            Some(value) => value as u32,
+                            None => to_bounded(seed.step(), bound),
+                        }
+                    } else if biased {
+                        let biases = [
+                            (boundary_fraction, edges.as_slice()),
+                            (magic_fraction, shared_magic.as_slice()),
+                        ];
+                        let (next, pick) = seed.pick_biased(&biases);
+                        seed = next;
+                        match pick {
+                            Some(value) => value as u32,
+                            None => to_bounded(seed.step(), bound),
+                        }
+                    } else {
+                        to_bounded(seed.step(), bound)
+                    };
+                    let offset = data_start + i * 4;
+                    buffer[offset..offset + 4].copy_from_slice(&bounded.to_le_bytes());
+                }
+            }
+            DataFormat::U16LE | DataFormat::U16BE => {
+                let max = params
+                    .bound
+                    .map_or(u16::MAX as i64, |bound| bound as i64 - 1);
+                let to_bounded = |output: u64| match params.bound {
+                    Some(bound) => ((output as u128 * bound as u128) >> 64) as u16,
+                    None => output as u16,
+                };
+                let edges = integer_edges(0, max);
+                let magic = magic_values(0, max);
+                let biases = [
+                    (boundary_fraction, edges.as_slice()),
+                    (magic_fraction, magic.as_slice()),
+                ];
+                let corners = params.corners(edges.clone());
+                for i in 0..count as usize {
+                    let value = if let Some(&corner) = corners.get(i) {
+                        corner as u16
+                    } else {
+                        let (next, pick) = seed.pick_biased(&biases);
+                        seed = next;
+                        match pick {
+                            Some(value) => value as u16,
+                            None => to_bounded(seed.step()),
+                        }
+                    };
+                    let offset = data_start + i * 2;
+                    buffer[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+                }
+            }
+            DataFormat::U64LE | DataFormat::U64BE => {
+                let bound = params.bound_u64.or(params.bound.map(u64::from));
+                let max = bound.map_or(u64::MAX, |bound| bound.saturating_sub(1));
+                let to_bounded = |output: u64| match bound {
+                    Some(bound) => ((output as u128 * bound as u128) >> 64) as u64,
+                    None => output,
+                };
+                let edges = unsigned_edges(max);
+                let magic = unsigned_magic(max);
+                let corners = params.corners(edges.clone());
+                for i in 0..count as usize {
+                    let value = if let Some(&corner) = corners.get(i) {
+                        corner
+                    } else {
+                        let (next, edge) = seed.pick_special(boundary_fraction, &edges);
+                        let (next, pick) = match edge {
+                            Some(edge) => (next, Some(edge)),
+                            None => next.pick_special(magic_fraction, &magic),
+                        };
+                        seed = next;
+                        match pick {
+                            Some(value) => value,
+                            None => to_bounded(seed.step()),
+                        }
+                    };
+                    let offset = data_start + i * 8;
+                    buffer[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+                }
+            }
+            DataFormat::I32LE
+            | DataFormat::I64LE
+            | DataFormat::I32BE
+            | DataFormat::I64BE
+            | DataFormat::DecimalI64 => {
+                let width = bytes_per_element as usize;
+                let edges = integer_edges(min, max);
+                let magic = magic_values(min, max);
+                let biases = [
+                    (boundary_fraction, edges.as_slice()),
+                    (magic_fraction, magic.as_slice()),
+                ];
+                let corners = params.corners(edges.clone());
+                for i in 0..count as usize {
+                    let value = if let Some(&corner) = corners.get(i) {
+                        corner
+                    } else {
+                        let (next, pick) = seed.pick_biased(&biases);
+                        seed = next;
+                        match pick {
+                            Some(value) => value,
+                            None => to_range_i64(seed.step(), min, max),
+                        }
+                    };
+                    let offset = data_start + i * width;
+                    buffer[offset..offset + width].copy_from_slice(&value.to_le_bytes()[..width]);
+                }
+            }
+            DataFormat::U128LE | DataFormat::U128BE => {
+                let corners = params.corners(U128_CORNERS.to_vec());
+                // The ends of the range, leading U128_CORNERS
+                let edges = &U128_CORNERS[..4];
+                for i in 0..count as usize {
+                    let value = if let Some(&corner) = corners.get(i) {
+                        corner
+                    } else {
+                        let (next, edge) = seed.pick_special(boundary_fraction, edges);
+                        seed = next;
+                        match edge {
+                            Some(edge) => edge,
+                            None => {
+                                let lo = seed.step();
+                                let hi = seed.step();
+                                (hi as u128) << 64 | lo as u128
+                            }
+                        }
+                    };
+                    let offset = data_start + i * 16;
+                    buffer[offset..offset + 16].copy_from_slice(&value.to_le_bytes());
+                }
+            }
+            DataFormat::CharU32 => {
+                let end: u32 = if params.bmp_only { 0x10000 } else { 0x110000 };
+                let scalars = (end - SURROGATE_COUNT) as u128;
+                let corners: Vec<u32> = CHAR_CORNERS.into_iter().filter(|&c| c < end).collect();
+                let corners = params.corners(corners);
+                for i in 0..count as usize {
+                    let value = if let Some(&corner) = corners.get(i) {
+                        corner
+                    } else {
+                        // Draw an index over the scalar values and step over
+                        // the surrogate block, so no draw is rejected
+                        let index = ((seed.step() as u128 * scalars) >> 64) as u32;
+                        if index < SURROGATE_START {
+                            index
+                        } else {
+                            index + SURROGATE_COUNT
+                        }
+                    };
+                    let offset = data_start + i * 4;
+                    buffer[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+                }
+            }
+            DataFormat::F64LE | DataFormat::F64BE => {
+                let corners = params.corners(F64_CORNERS.to_vec());
+                let sampler = QuasiSampler::new(
+                    &mut seed,
+                    params.sequence,
+                    params.dimensions,
+                    params.sequence_start,
+                )?;
+                let specials: Vec<f64> = SPECIAL_F64
+                    .iter()
+                    .chain(&SAFE_INTEGER_F64)
+                    .copied()
+                    .collect();
+                for i in 0..count as usize {
+                    let offset = data_start + i * 8;
+                    if i >= corners.len() {
+                        if let Some(special) = seed.step_special(special_fraction, &specials) {
+                            buffer[offset..offset + 8].copy_from_slice(&special.to_le_bytes());
+                            continue;
+                        }
+                    }
+                    let float_val = if let Some(&corner) = corners.get(i) {
+                        corner
+                    } else if let Some(sampler) = &sampler {
+                        sampler.sample(i)
+                    } else if params.sequence == Sequence::Stratified {
+                        let jitter = to_unit_f64(seed.step());
+                        ((i as f64 + jitter) / count as f64).min(LARGEST_BELOW_ONE)
+                    } else {
+                        let output = seed.step();
+                        // Convert to [0, 1) range with high precision
+                        to_unit_f64(output)
+                    };
+                    let float_val = match params.float_range {
+                        Some((min, max)) => unit_to_range_f64(float_val, min, max),
+                        None => float_val,
+                    };
+                    buffer[offset..offset + 8].copy_from_slice(&float_val.to_le_bytes());
+                }
+            }
+            DataFormat::F32LE | DataFormat::F32BE => {
+                let corners = params.corners(F32_CORNERS.to_vec());
+                let sampler = QuasiSampler::new(
+                    &mut seed,
+                    params.sequence,
+                    params.dimensions,
+                    params.sequence_start,
+                )?;
+                for i in 0..count as usize {
+                    let offset = data_start + i * 4;
+                    if i >= corners.len() {
+                        if let Some(special) = seed.step_special(special_fraction, &SPECIAL_F32) {
+                            buffer[offset..offset + 4].copy_from_slice(&special.to_le_bytes());
+                            continue;
+                        }
+                    }
+                    let float_val = if let Some(&corner) = corners.get(i) {
+                        corner
+                    } else if let Some(sampler) = &sampler {
+                        (sampler.sample(i) as f32).min(LARGEST_BELOW_ONE_F32)
+                    } else if params.sequence == Sequence::Stratified {
+                        let jitter = to_unit_f64(seed.step());
+                        (((i as f64 + jitter) / count as f64) as f32).min(LARGEST_BELOW_ONE_F32)
+                    } else {
+                        to_unit_f32(seed.step())
+                    };
+                    let float_val = match params.float_range {
+                        Some((min, max)) => unit_to_range_f32(float_val, min, max),
+                        None => float_val,
+                    };
+                    buffer[offset..offset + 4].copy_from_slice(&float_val.to_le_bytes());
+                }
+            }
+            DataFormat::U8 => {
+                let data = &mut buffer[data_start..data_start + count as usize];
+                let corners = params.corners(BOUNDARY_BYTES.to_vec());
+                let head = corners.len().min(data.len());
+                data[..head].copy_from_slice(&corners[..head]);
+                if boundary_fraction > 0.0 || magic_fraction > 0.0 {
+                    // Biased bytes take a draw each rather than eight per draw
+                    let edges = integer_edges(0, u8::MAX as i64);
+                    let magic: Vec<i64> = BOUNDARY_BYTES.iter().map(|&b| b as i64).collect();
+                    let biases = [
+                        (boundary_fraction, edges.as_slice()),
+                        (magic_fraction, magic.as_slice()),
+                    ];
+                    for byte in &mut data[head..] {
+                        let (next, pick) = seed.pick_biased(&biases);
+                        seed = next;
+                        *byte = match pick {
+                            Some(value) => value as u8,
+                            None => seed.step() as u8,
+                        };
+                    }
+                } else {
+                    seed.step_fill(&mut data[head..]);
+                }
+            }
+            DataFormat::BoolPacked => {
+                let data = &mut buffer[data_start..data_start + data_size];
+                let corners = params.corners(vec![0, 1]);
+                let weighted =
+                    params.true_probability.is_some() || params.true_probabilities.is_some();
+                if weighted {
+                    data.fill(0);
+                    for i in 0..count as usize {
+                        let probability = match &params.true_probabilities {
+                            Some(probabilities) => probabilities[i],
+                            None => params.true_probability.unwrap_or(0.5),
+                        };
+                        let bit = match corners.get(i) {
+                            Some(&corner) => corner,
+                            None => u8::from(to_unit_f64(seed.step()) < probability),
+                        };
+                        data[i / 8] |= bit << (i % 8);
+                    }
+                } else {
+                    // Unweighted bits are raw output, eight bytes per step
+                    seed.step_fill(data);
+                    for (i, &corner) in corners.iter().enumerate().take(count as usize) {
+                        data[i / 8] = data[i / 8] & !(1 << (i % 8)) | corner << (i % 8);
+                    }
+                }
+                // Zero the padding bits after the last element
+                if count % 8 != 0 {
+                    data[data_size - 1] &= (1u8 << (count % 8)) - 1;
+                }
+            }
+            DataFormat::BoolU8 => {
+                let corners = params.corners(vec![0, 1]);
+                for i in 0..count as usize {
+                    let probability = match &params.true_probabilities {
+                        Some(probabilities) => Some(probabilities[i]),
+                        None => params.true_probability,
+                    };
+                    buffer[data_start + i] = if let Some(&corner) = corners.get(i) {
+                        corner
+                    } else {
+                        let output = seed.step();
+                        let flip = match probability {
+                            Some(p) => to_unit_f64(output) < p,
+                            None => output & 1 == 1,
+                        };
+                        if flip {
+                            1
+                        } else {
+                            0
+                        }
+                    };
+                }
+            }
+            // Rejected by `fixed_width` above
+            DataFormat::FramedStrings | DataFormat::FramedBlobs => {}
+        }
+
+        if big_endian {
+            let data = &mut buffer[data_start..data_start + data_size];
+            for element in data.chunks_exact_mut(bytes_per_element as usize) {
+                element.reverse();
+            }
+        }
+
+        // The null bitmap is drawn after the values, so the values of a
+        // nullable fill match a plain fill from the same seed
+        if let Some(p) = null_probability {
+            let (data, mask) = buffer[data_start..].split_at_mut(data_size);
+            let mask = &mut mask[..count.div_ceil(8) as usize];
+            mask.fill(0);
+            let width = bytes_per_element as usize;
+            for i in 0..count as usize {
+                if to_unit_f64(seed.step()) >= p {
+                    continue;
+                }
+                mask[i / 8] |= 1 << (i % 8);
+                match format {
+                    DataFormat::BoolPacked => data[i / 8] &= !(1 << (i % 8)),
+                    _ => data[i * width..(i + 1) * width].fill(0),
+                }
+            }
+        }
+
+        Ok(seed)
+    }
+}
@@ -0,0 +1,196 @@
+use std::f64::consts::TAU;
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::{write_header, HEADER_SIZE};
+use crate::{to_unit_f32, to_unit_f64, DataFormat, Seed};
+
+/// Records produced by `fill_geometry`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[wasm_bindgen]
+pub enum GeometryKind {
+    /// Colors as (r, g, b, a), each channel in [0, 1)
+    #[default]
+    Rgba = 0,
+    /// Directions uniform over the unit circle or sphere
+    UnitVector = 1,
+    /// Points uniform in an axis-aligned box
+    Point = 2,
+    /// Rotations as normalized (x, y, z, w) quaternions, uniform over all
+    /// rotations
+    Quaternion = 3,
+}
+
+/// Parameters for `fill_geometry`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct GeometryParams {
+    kind: GeometryKind,
+    dimensions: u32,
+    box_min: Option<Vec<f32>>,
+    box_max: Option<Vec<f32>>,
+}
+
+impl Default for GeometryParams {
+    fn default() -> GeometryParams {
+        GeometryParams {
+            kind: GeometryKind::Rgba,
+            dimensions: 3,
+            box_min: None,
+            box_max: None,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl GeometryParams {
+    /// RGBA colors, with 3D vectors and points in the unit cube for the
+    /// other kinds
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> GeometryParams {
+        GeometryParams::default()
+    }
+
+    pub fn with_kind(mut self, kind: GeometryKind) -> GeometryParams {
+        self.kind = kind;
+        self
+    }
+
+    /// Components of unit vectors and points: 2 or 3
+    pub fn with_dimensions(mut self, dimensions: u32) -> GeometryParams {
+        self.dimensions = dimensions;
+        self
+    }
+
+    /// Per-axis corners of the box points are drawn from, [min, max) on
+    /// each axis, with one entry per dimension (default the unit cube)
+    pub fn with_box(mut self, min: Vec<f32>, max: Vec<f32>) -> GeometryParams {
+        self.box_min = Some(min);
+        self.box_max = Some(max);
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> GeometryKind {
+        self.kind
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn dimensions(&self) -> u32 {
+        self.dimensions
+    }
+
+    /// F32 components in each record
+    #[wasm_bindgen(getter)]
+    pub fn components(&self) -> u32 {
+        match self.kind {
+            GeometryKind::Rgba | GeometryKind::Quaternion => 4,
+            GeometryKind::UnitVector | GeometryKind::Point => self.dimensions,
+        }
+    }
+
+    /// Buffer size `fill_geometry` needs for `count` records
+    pub fn buffer_size(&self, count: u64) -> u64 {
+        HEADER_SIZE.saturating_add(count.saturating_mul(self.components() as u64 * 4))
+    }
+}
+
+impl GeometryParams {
+    fn validate(&self) -> Result<(), Error> {
+        if !(2..=3).contains(&self.dimensions) {
+            return Err(Error::invalid_parameter(
+                "dimensions",
+                &format!("{}, expected 2 or 3", self.dimensions),
+            ));
+        }
+        if let (Some(min), Some(max)) = (&self.box_min, &self.box_max) {
+            if min.len() != self.dimensions as usize || max.len() != self.dimensions as usize {
+                return Err(Error::invalid_parameter(
+                    "box",
+                    &format!(
+                        "{} and {} corners for {} dimensions",
+                        min.len(),
+                        max.len(),
+                        self.dimensions
+                    ),
+                ));
+            }
+            for (lo, hi) in min.iter().zip(max) {
+                if !(lo.is_finite() && hi.is_finite() && lo < hi) {
+                    return Err(Error::invalid_parameter("box", &format!("[{lo}, {hi})")));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Fill an `F32LE` buffer with `count` graphics records of
+    /// `params.components()` floats each, back to back, for property tests
+    /// of rendering and geometry code. The header count is the number of
+    /// floats. Unit vectors and quaternions are exactly uniform, drawn
+    /// without rejection, and normalized to f32 precision.
+    #[wasm_bindgen]
+    pub fn fill_geometry(
+        &self,
+        buffer: &mut [u8],
+        count: u64,
+        params: &GeometryParams,
+    ) -> Result<Seed, Error> {
+        params.validate()?;
+        let components = params.components() as usize;
+        let floats = count.saturating_mul(components as u64);
+        write_header(buffer, DataFormat::F32LE, floats)?;
+
+        let mut seed = self.clone();
+        let mut record = [0f64; 4];
+        let data = &mut buffer[HEADER_SIZE as usize..];
+        for chunk in data.chunks_exact_mut(components * 4).take(count as usize) {
+            match params.kind {
+                GeometryKind::Rgba => {
+                    for channel in record.iter_mut() {
+                        *channel = to_unit_f32(seed.step()) as f64;
+                    }
+                }
+                GeometryKind::UnitVector if params.dimensions == 2 => {
+                    let (sin, cos) = (TAU * to_unit_f64(seed.step())).sin_cos();
+                    record[..2].copy_from_slice(&[cos, sin]);
+                }
+                GeometryKind::UnitVector => {
+                    // Archimedes: z is uniform on [-1, 1] over the sphere
+                    let z = 2.0 * to_unit_f64(seed.step()) - 1.0;
+                    let (sin, cos) = (TAU * to_unit_f64(seed.step())).sin_cos();
+                    let r = (1.0 - z * z).sqrt();
+                    record[..3].copy_from_slice(&[r * cos, r * sin, z]);
+                }
+                GeometryKind::Point => {
+                    for (axis, value) in record[..components].iter_mut().enumerate() {
+                        *value = match (&params.box_min, &params.box_max) {
+                            (Some(min), Some(max)) => {
+                                let (lo, hi) = (min[axis] as f64, max[axis] as f64);
+                                let point = (lo + to_unit_f64(seed.step()) * (hi - lo)) as f32;
+                                // Rounding to f32 may reach the upper corner
+                                point.min(max[axis].next_down()) as f64
+                            }
+                            _ => to_unit_f32(seed.step()) as f64,
+                        };
+                    }
+                }
+                GeometryKind::Quaternion => {
+                    // Shoemake's subgroup algorithm
+                    let u = to_unit_f64(seed.step());
+                    let (sin1, cos1) = (TAU * to_unit_f64(seed.step())).sin_cos();
+                    let (sin2, cos2) = (TAU * to_unit_f64(seed.step())).sin_cos();
+                    let (a, b) = ((1.0 - u).sqrt(), u.sqrt());
+                    record.copy_from_slice(&[a * sin1, a * cos1, b * sin2, b * cos2]);
+                }
+            }
+            for (bytes, value) in chunk.chunks_exact_mut(4).zip(&record) {
+                bytes.copy_from_slice(&(*value as f32).to_le_bytes());
+            }
+        }
+        Ok(seed)
+    }
+}
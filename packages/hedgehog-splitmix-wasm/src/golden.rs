@@ -0,0 +1,160 @@
+use wasm_bindgen::prelude::*;
+
+use crate::corpus::Corpus;
+use crate::encoding::{fnv1a, read_bytes, read_u8, read_varint, write_varint};
+use crate::error::Error;
+use crate::Seed;
+
+/// Magic bytes at the start of a golden corpus file
+const GOLDEN_MAGIC: &[u8; 4] = b"HHGC";
+
+/// Current golden corpus encoding version
+const GOLDEN_VERSION: u8 = 1;
+
+/// Bytes of the trailing checksum
+const CHECKSUM_SIZE: usize = 8;
+
+/// A "blessed" corpus pinned for regression properties, together with the
+/// seed, size and configuration it was generated from so it can be
+/// regenerated on demand and compared against the pinned copy
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct GoldenCorpus {
+    seed: Seed,
+    size: u32,
+    config_hash: u64,
+    label: String,
+    corpus: Corpus,
+}
+
+#[wasm_bindgen]
+impl GoldenCorpus {
+    /// Pin `corpus`, generated under `label` (e.g. the property name) from
+    /// `seed` at `size` with the configuration hashed as `config_hash`
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        corpus: &Corpus,
+        seed: &Seed,
+        size: u32,
+        config_hash: u64,
+        label: &str,
+    ) -> GoldenCorpus {
+        GoldenCorpus {
+            seed: Seed::from_parts(seed.state, seed.gamma),
+            size,
+            config_hash,
+            label: label.to_string(),
+            corpus: corpus.clone(),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn config_hash(&self) -> u64 {
+        self.config_hash
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    /// The pinned corpus
+    #[wasm_bindgen(getter)]
+    pub fn corpus(&self) -> Corpus {
+        self.corpus.clone()
+    }
+
+    /// Whether `corpus` has exactly the pinned entries in the same order,
+    /// e.g. after regenerating it from `seed`, `size` and the configuration
+    pub fn matches(&self, corpus: &Corpus) -> bool {
+        corpus.export() == self.corpus.export()
+    }
+
+    /// Encode the corpus and its metadata as one blob for checking in:
+    ///
+    /// ```text
+    /// [4 bytes "HHGC"][1 byte version = 1]
+    /// [varint state][varint gamma][varint size][varint config_hash]
+    /// [varint label length][UTF-8 label]
+    /// [varint corpus length][corpus, as written by `Corpus.export_compressed`]
+    /// [8 bytes FNV-1a of everything before, little-endian]
+    /// ```
+    pub fn serialize(&self) -> Vec<u8> {
+        let corpus = self.corpus.export_compressed();
+        let mut out = Vec::with_capacity(32 + self.label.len() + corpus.len());
+        out.extend_from_slice(GOLDEN_MAGIC);
+        out.push(GOLDEN_VERSION);
+        write_varint(&mut out, self.seed.state);
+        write_varint(&mut out, self.seed.gamma);
+        write_varint(&mut out, self.size as u64);
+        write_varint(&mut out, self.config_hash);
+        write_varint(&mut out, self.label.len() as u64);
+        out.extend_from_slice(self.label.as_bytes());
+        write_varint(&mut out, corpus.len() as u64);
+        out.extend_from_slice(&corpus);
+        let checksum = fnv1a(&out);
+        out.extend_from_slice(&checksum.to_le_bytes());
+        out
+    }
+
+    /// Load a blob produced by `serialize`, failing if it is truncated,
+    /// corrupted or edited by hand
+    pub fn parse(bytes: &[u8]) -> Result<GoldenCorpus, Error> {
+        if bytes.len() < GOLDEN_MAGIC.len() || &bytes[..GOLDEN_MAGIC.len()] != GOLDEN_MAGIC {
+            return Err(Error::invalid_encoding("missing golden corpus header"));
+        }
+        let mut position = GOLDEN_MAGIC.len();
+
+        let version = read_u8(bytes, &mut position)?;
+        if version != GOLDEN_VERSION {
+            return Err(Error::invalid_encoding(&format!(
+                "unsupported golden corpus version {version}"
+            )));
+        }
+
+        let body_end = bytes
+            .len()
+            .checked_sub(CHECKSUM_SIZE)
+            .filter(|&end| end >= position)
+            .ok_or_else(|| Error::invalid_encoding("golden corpus too short"))?;
+        let mut checksum = [0u8; CHECKSUM_SIZE];
+        checksum.copy_from_slice(&bytes[body_end..]);
+        if u64::from_le_bytes(checksum) != fnv1a(&bytes[..body_end]) {
+            return Err(Error::invalid_encoding("golden corpus checksum mismatch"));
+        }
+        let body = &bytes[..body_end];
+
+        let state = read_varint(body, &mut position)?;
+        let gamma = read_varint(body, &mut position)?;
+        let size = u32::try_from(read_varint(body, &mut position)?)
+            .map_err(|_| Error::invalid_encoding("golden corpus size out of range"))?;
+        let config_hash = read_varint(body, &mut position)?;
+        let length = read_varint(body, &mut position)? as usize;
+        let label = String::from_utf8(read_bytes(body, &mut position, length)?.to_vec())
+            .map_err(|_| Error::invalid_encoding("golden corpus label is not UTF-8"))?;
+        let length = read_varint(body, &mut position)? as usize;
+        let mut corpus = Corpus::new();
+        corpus.import(read_bytes(body, &mut position, length)?)?;
+
+        if position != body.len() {
+            return Err(Error::invalid_encoding("trailing bytes in golden corpus"));
+        }
+        Ok(GoldenCorpus {
+            seed: Seed::from_parts(state, gamma),
+            size,
+            config_hash,
+            label,
+            corpus,
+        })
+    }
+}
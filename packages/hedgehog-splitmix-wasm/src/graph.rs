@@ -0,0 +1,242 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::{check_probability, to_unit_f64, Seed};
+
+/// Graph header: [1 byte kind][4 bytes node count LE][4 bytes edge count LE]
+const GRAPH_HEADER_SIZE: u64 = 9;
+
+/// Bytes per edge: two u32 node indices
+const EDGE_SIZE: u64 = 8;
+
+/// Shapes produced by `fill_graph`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[wasm_bindgen]
+pub enum GraphKind {
+    /// Rooted tree at node 0; every other node has exactly one parent
+    #[default]
+    Tree = 0,
+    /// Directed acyclic graph; every edge goes from a lower to a higher node
+    Dag = 1,
+}
+
+/// Parameters for `fill_graph`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct GraphParams {
+    kind: GraphKind,
+    min_nodes: u32,
+    max_nodes: u32,
+    max_children: u32,
+    edge_probability: f64,
+    connected: bool,
+}
+
+impl Default for GraphParams {
+    fn default() -> GraphParams {
+        GraphParams {
+            kind: GraphKind::Tree,
+            min_nodes: 1,
+            max_nodes: 16,
+            max_children: 0,
+            edge_probability: 0.25,
+            connected: true,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl GraphParams {
+    /// Trees of 1 to 16 nodes with unlimited branching
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> GraphParams {
+        GraphParams::default()
+    }
+
+    pub fn with_kind(mut self, kind: GraphKind) -> GraphParams {
+        self.kind = kind;
+        self
+    }
+
+    /// Inclusive range for the number of nodes, drawn uniformly
+    pub fn with_nodes(mut self, min: u32, max: u32) -> GraphParams {
+        self.min_nodes = min;
+        self.max_nodes = max;
+        self
+    }
+
+    /// Branching factor: the most children (out-edges for a DAG) any node
+    /// may have, or 0 for no limit
+    pub fn with_max_children(mut self, max_children: u32) -> GraphParams {
+        self.max_children = max_children;
+        self
+    }
+
+    /// Connectivity of a DAG: the probability of each additional forward
+    /// edge between two nodes
+    pub fn with_edge_probability(mut self, p: f64) -> GraphParams {
+        self.edge_probability = p;
+        self
+    }
+
+    /// Whether a DAG is weakly connected, built on a random spanning tree
+    /// before extra edges are added. Trees are always connected.
+    pub fn with_connected(mut self, connected: bool) -> GraphParams {
+        self.connected = connected;
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> GraphKind {
+        self.kind
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min_nodes(&self) -> u32 {
+        self.min_nodes
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_nodes(&self) -> u32 {
+        self.max_nodes
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_children(&self) -> u32 {
+        self.max_children
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn edge_probability(&self) -> f64 {
+        self.edge_probability
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn connected(&self) -> bool {
+        self.connected
+    }
+
+    /// Buffer size that holds any graph these parameters can produce
+    #[wasm_bindgen(getter)]
+    pub fn max_buffer_size(&self) -> u64 {
+        self.max_edges()
+            .saturating_mul(EDGE_SIZE)
+            .saturating_add(GRAPH_HEADER_SIZE)
+    }
+}
+
+impl GraphParams {
+    fn max_edges(&self) -> u64 {
+        let nodes = self.max_nodes as u128;
+        match self.kind {
+            GraphKind::Tree => nodes.saturating_sub(1) as u64,
+            GraphKind::Dag => {
+                // Node k (counting from the last) can have at most
+                // min(k, max_children) forward edges
+                let last = nodes.saturating_sub(1);
+                let children = match self.max_children {
+                    0 => last,
+                    max => (max as u128).min(last),
+                };
+                let total = children * (children + 1) / 2 + (last - children) * children;
+                total.min(u64::MAX as u128) as u64
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if self.min_nodes == 0 || self.min_nodes > self.max_nodes {
+            return Err(Error::invalid_parameter(
+                "nodes",
+                &format!("[{}, {}]", self.min_nodes, self.max_nodes),
+            ));
+        }
+        check_probability("edge_probability", self.edge_probability)?;
+        Ok(())
+    }
+}
+
+impl Seed {
+    /// Attach each node after the first to a uniformly chosen earlier node
+    /// with spare capacity, appending `(parent, child)` edges
+    fn spanning_edges(
+        &mut self,
+        nodes: u32,
+        max_children: u32,
+        edges: &mut Vec<(u32, u32)>,
+        children: &mut [u32],
+    ) {
+        let mut open: Vec<u32> = vec![0];
+        for child in 1..nodes {
+            let slot = self.step_below(open.len() as u64) as usize;
+            let parent = open[slot];
+            edges.push((parent, child));
+            children[parent as usize] += 1;
+            if max_children != 0 && children[parent as usize] >= max_children {
+                open.swap_remove(slot);
+            }
+            open.push(child);
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Fill a buffer with a random tree or DAG as an edge list:
+    ///
+    /// ```text
+    /// [1 byte kind][4 bytes node count LE][4 bytes edge count LE]
+    /// per edge: [4 bytes from LE][4 bytes to LE]
+    /// ```
+    ///
+    /// Nodes are numbered from 0 and every edge points from a lower to a
+    /// higher node, so node order is a topological order. The buffer must
+    /// hold at least `params.max_buffer_size` bytes.
+    #[wasm_bindgen]
+    pub fn fill_graph(&self, buffer: &mut [u8], params: &GraphParams) -> Result<Seed, Error> {
+        params.validate()?;
+        let required = params.max_buffer_size();
+        if (buffer.len() as u64) < required {
+            return Err(Error::buffer_too_small(required, buffer.len()));
+        }
+
+        let mut seed = self.clone();
+        let span = (params.max_nodes - params.min_nodes) as u64 + 1;
+        let nodes = params.min_nodes + seed.step_below(span) as u32;
+
+        let mut edges = Vec::new();
+        let mut children = vec![0u32; nodes as usize];
+        if params.kind == GraphKind::Tree || params.connected {
+            seed.spanning_edges(nodes, params.max_children, &mut edges, &mut children);
+        }
+        if params.kind == GraphKind::Dag && params.edge_probability > 0.0 {
+            let mut present: Vec<(u32, u32)> = edges.clone();
+            present.sort_unstable();
+            for from in 0..nodes {
+                for to in from + 1..nodes {
+                    if params.max_children != 0 && children[from as usize] >= params.max_children {
+                        break;
+                    }
+                    if present.binary_search(&(from, to)).is_ok() {
+                        continue;
+                    }
+                    if to_unit_f64(seed.step()) < params.edge_probability {
+                        edges.push((from, to));
+                        children[from as usize] += 1;
+                    }
+                }
+            }
+            edges.sort_unstable();
+        }
+
+        buffer[0] = params.kind as u8;
+        buffer[1..5].copy_from_slice(&nodes.to_le_bytes());
+        buffer[5..9].copy_from_slice(&(edges.len() as u32).to_le_bytes());
+        for (i, (from, to)) in edges.iter().enumerate() {
+            let offset = (GRAPH_HEADER_SIZE + i as u64 * EDGE_SIZE) as usize;
+            buffer[offset..offset + 4].copy_from_slice(&from.to_le_bytes());
+            buffer[offset + 4..offset + 8].copy_from_slice(&to.to_le_bytes());
+        }
+        Ok(seed)
+    }
+}
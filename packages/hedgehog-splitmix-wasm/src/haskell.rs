@@ -0,0 +1,186 @@
+use wasm_bindgen::prelude::*;
+
+use crate::GOLDEN_GAMMA;
+
+/// Stafford-style mixer used by Haskell's splitmix for outputs (MurmurHash3
+/// finalizer constants)
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 33)).wrapping_mul(0xff51afd7ed558ccd);
+    z = (z ^ (z >> 33)).wrapping_mul(0xc4ceb9fe1a85ec53);
+    z ^ (z >> 33)
+}
+
+/// Stafford variant 13 mixer, used by Haskell's splitmix for gammas
+fn mix64_variant13(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Odd gamma with enough bit transitions, as in Haskell's splitmix
+fn haskell_mix_gamma(z: u64) -> u64 {
+    let gamma = mix64_variant13(z) | 1;
+    if (gamma ^ (gamma >> 1)).count_ones() >= 24 {
+        gamma
+    } else {
+        gamma ^ 0xaaaa_aaaa_aaaa_aaaa
+    }
+}
+
+/// Seed whose construction, `next_word64` and `split` match Haskell
+/// hedgehog's `Seed` (the `SMGen` of the splitmix package) exactly, so a
+/// failure can be replayed across the Haskell and TypeScript
+/// implementations. Unlike `Seed`, the state is advanced before mixing with
+/// different constants, and the gamma has its bit transitions checked.
+#[derive(Debug, Clone, PartialEq)]
+#[wasm_bindgen]
+pub struct HaskellSeed {
+    value: u64,
+    gamma: u64,
+}
+
+#[wasm_bindgen]
+impl HaskellSeed {
+    /// Equivalent of hedgehog's `Seed.from` / splitmix's `mkSMGen`
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: u64) -> HaskellSeed {
+        HaskellSeed {
+            value: mix64(value),
+            gamma: haskell_mix_gamma(value.wrapping_add(GOLDEN_GAMMA)),
+        }
+    }
+
+    /// Create from the value and gamma shown by Haskell's `Show` instance.
+    /// The gamma must be odd for the stream to have full period.
+    pub fn from_parts(value: u64, gamma: u64) -> HaskellSeed {
+        HaskellSeed { value, gamma }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn gamma(&self) -> u64 {
+        self.gamma
+    }
+
+    /// Equivalent of hedgehog's `nextWord64`
+    pub fn next_word64(&self) -> HaskellSeedAndValue {
+        let value = self.value.wrapping_add(self.gamma);
+        HaskellSeedAndValue {
+            seed: HaskellSeed {
+                value,
+                gamma: self.gamma,
+            },
+            value: mix64(value),
+        }
+    }
+
+    /// Equivalent of hedgehog's `split`
+    pub fn split(&self) -> HaskellSeedPair {
+        let first = self.value.wrapping_add(self.gamma);
+        let second = first.wrapping_add(self.gamma);
+        HaskellSeedPair {
+            left: HaskellSeed {
+                value: second,
+                gamma: self.gamma,
+            },
+            right: HaskellSeed {
+                value: mix64(first),
+                gamma: haskell_mix_gamma(second),
+            },
+        }
+    }
+}
+
+/// Return type for `HaskellSeed::next_word64`
+#[wasm_bindgen]
+pub struct HaskellSeedAndValue {
+    seed: HaskellSeed,
+    value: u64,
+}
+
+#[wasm_bindgen]
+impl HaskellSeedAndValue {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> HaskellSeed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+/// Return type for `HaskellSeed::split`
+#[wasm_bindgen]
+pub struct HaskellSeedPair {
+    left: HaskellSeed,
+    right: HaskellSeed,
+}
+
+#[wasm_bindgen]
+impl HaskellSeedPair {
+    #[wasm_bindgen(getter)]
+    pub fn left(&self) -> HaskellSeed {
+        self.left.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn right(&self) -> HaskellSeed {
+        self.right.clone()
+    }
+}
+
+/// Golden vectors: `(x, Seed.from x, first three nextWord64 outputs, split)`
+/// with seeds as `(value, gamma)`. `Seed.from 42` and its first output
+/// match the examples in the splitmix documentation
+/// (`SMGen 9297814886316923340 13679457532755275413`, `1275548033995301424`).
+type Golden = (u64, (u64, u64), [u64; 3], (u64, u64), (u64, u64));
+
+const HASKELL_GOLDEN: [Golden; 3] = [
+    (
+        0,
+        (0x0000000000000000, 0xe220a8397b1dcdaf),
+        [0x9474f0eb06d79fd8, 0xf89e0ce996962508, 0xccccc18cf3dc6f98],
+        (0xc4415072f63b9b5e, 0xe220a8397b1dcdaf),
+        (0x9474f0eb06d79fd8, 0xd49ffe2f0ef90131),
+    ),
+    (
+        1,
+        (0xb456bcfc34c2cb2c, 0x910a2dec89025cc1),
+        [0xe0e39dee72a8b9f5, 0x7283ec7a13ca219b, 0x7b237e9a12dbbbe2],
+        (0xd66b18d546c784ae, 0x910a2dec89025cc1),
+        (0xe0e39dee72a8b9f5, 0x4ddba5b6fb3a5783),
+    ),
+    (
+        42,
+        (0x810879608e4259cc, 0xbdd732262feb6e95),
+        [0x11b3a82dc43ce230, 0x9091b75f0deac297, 0x1d51e2d69c70605f],
+        (0xfcb6ddacee1936f6, 0xbdd732262feb6e95),
+        (0x11b3a82dc43ce230, 0x91eaf22bbaf0ca29),
+    ),
+];
+
+/// Check `HaskellSeed` against the embedded golden vectors
+#[wasm_bindgen]
+pub fn verify_haskell_compat() -> bool {
+    let parts = |seed: &HaskellSeed| (seed.value, seed.gamma);
+    HASKELL_GOLDEN.iter().all(|&(x, from, words, left, right)| {
+        let seed = HaskellSeed::new(x);
+        let mut next = seed.clone();
+        let words_match = words.iter().all(|&word| {
+            let result = next.next_word64();
+            next = result.seed;
+            result.value == word
+        });
+        let pair = seed.split();
+        parts(&seed) == from
+            && words_match
+            && parts(&pair.left) == left
+            && parts(&pair.right) == right
+    })
+}
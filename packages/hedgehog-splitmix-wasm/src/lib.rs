@@ -1,7 +1,77 @@
 use wasm_bindgen::prelude::*;
 
+mod arbitrary;
+mod audit;
+mod chars;
+mod choice;
+mod cluster;
+mod context;
+mod corpus;
+mod coverage;
+mod dictionary;
+mod diff;
+mod distributions;
+mod edge;
+mod encoding;
 mod error;
-use error::Error;
+mod faker;
+mod faults;
+mod fill;
+mod geometry;
+mod golden;
+mod graph;
+mod haskell;
+mod locale;
+mod markov;
+mod metrics;
+mod money;
+mod neighbor;
+mod quasi;
+mod records;
+mod repro;
+mod retry;
+mod shard;
+mod sparse;
+mod strings;
+mod tensor;
+mod time;
+mod trace;
+pub use arbitrary::{build_from_bytes, FromChoices};
+pub use audit::{audit_splits, SeedIsolationAudit, SplitAudit};
+pub use choice::{MutatedChoices, MutationOp};
+pub use cluster::{fingerprint_choices, FailureClusters};
+pub use context::{verify_determinism, DeterminismReport, GenContext};
+pub use corpus::{Corpus, CorpusEntry};
+pub use coverage::CoverageSearch;
+pub use dictionary::Dictionary;
+pub use diff::FillDiff;
+pub use distributions::{
+    PartitionMethod, PoissonProcess, Reservoir, SeedAndCounts, SeedAndF64Pair, WeightedSampler,
+};
+pub use error::{Error, ErrorKind};
+pub use faker::{FakeBatch, FakeKind, SeedAndString};
+pub use faults::FaultPlan;
+pub use fill::{fill_buffer_size, FillParams};
+pub use geometry::{GeometryKind, GeometryParams};
+pub use golden::GoldenCorpus;
+pub use graph::{GraphKind, GraphParams};
+pub use haskell::{verify_haskell_compat, HaskellSeed, HaskellSeedAndValue, HaskellSeedPair};
+pub use locale::{Locale, LocaleKind, LocaleParams};
+pub use markov::MarkovChain;
+pub use metrics::{
+    metrics_enabled, metrics_report, reset_metrics, set_metrics_enabled, MetricsReport,
+};
+pub use money::MoneyParams;
+pub use quasi::Sequence;
+pub use records::records_buffer_size;
+pub use repro::{make_repro, parse_repro, Repro};
+pub use retry::{Constraint, RetryResult};
+pub use shard::{shard, Shard};
+pub use sparse::SparseFill;
+pub use strings::{blobs_buffer_size, StringAlphabet, StringParams};
+pub use tensor::{tensor_buffer_size, TensorParams};
+pub use time::{ClockParams, LatencyParams};
+pub use trace::{DrawOp, Trace};
 
 /// Data formats supported by the buffer API
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -13,23 +83,149 @@ pub enum DataFormat {
     F64LE = 1,
     /// Boolean values as u8 (0 or 1)
     BoolU8 = 2,
+    /// 64-bit unsigned integers, little-endian
+    U64LE = 3,
+    /// 32-bit signed integers, little-endian
+    I32LE = 4,
+    /// 64-bit signed integers, little-endian
+    I64LE = 5,
+    /// 32-bit floating point, little-endian
+    F32LE = 6,
+    /// Raw bytes, eight per output
+    U8 = 7,
+    /// 16-bit unsigned integers, little-endian
+    U16LE = 8,
+    /// 128-bit unsigned integers, little-endian (low u64 then high u64)
+    U128LE = 9,
+    /// Unicode scalar values as u32, little-endian (never a surrogate)
+    CharU32 = 10,
+    /// Booleans packed eight per byte, least significant bit first
+    BoolPacked = 11,
+    /// 16-bit unsigned integers, big-endian
+    U16BE = 12,
+    /// 32-bit unsigned integers, big-endian
+    U32BE = 13,
+    /// 64-bit unsigned integers, big-endian
+    U64BE = 14,
+    /// 128-bit unsigned integers, big-endian
+    U128BE = 15,
+    /// 32-bit signed integers, big-endian
+    I32BE = 16,
+    /// 64-bit signed integers, big-endian
+    I64BE = 17,
+    /// 32-bit floating point, big-endian
+    F32BE = 18,
+    /// 64-bit floating point, big-endian
+    F64BE = 19,
+    /// Fixed-point decimals as 64-bit signed integers in units of
+    /// 10^-scale (e.g. cents at scale 2), little-endian
+    DecimalI64 = 20,
+    /// Length-prefixed UTF-8 strings written by `fill_strings`, with no
+    /// fixed element width
+    FramedStrings = 21,
+    /// Length-prefixed byte blobs written by `fill_blobs`, with no fixed
+    /// element width
+    FramedBlobs = 22,
 }
 
 impl DataFormat {
+    /// Number of formats; codes run from 0 to the last variant with no gaps
+    const COUNT: usize = DataFormat::FramedBlobs as usize + 1;
+
     fn from_u8(value: u8) -> Result<Self, Error> {
         match value {
             0 => Ok(DataFormat::U32LE),
             1 => Ok(DataFormat::F64LE),
             2 => Ok(DataFormat::BoolU8),
+            3 => Ok(DataFormat::U64LE),
+            4 => Ok(DataFormat::I32LE),
+            5 => Ok(DataFormat::I64LE),
+            6 => Ok(DataFormat::F32LE),
+            7 => Ok(DataFormat::U8),
+            8 => Ok(DataFormat::U16LE),
+            9 => Ok(DataFormat::U128LE),
+            10 => Ok(DataFormat::CharU32),
+            11 => Ok(DataFormat::BoolPacked),
+            12 => Ok(DataFormat::U16BE),
+            13 => Ok(DataFormat::U32BE),
+            14 => Ok(DataFormat::U64BE),
+            15 => Ok(DataFormat::U128BE),
+            16 => Ok(DataFormat::I32BE),
+            17 => Ok(DataFormat::I64BE),
+            18 => Ok(DataFormat::F32BE),
+            19 => Ok(DataFormat::F64BE),
+            20 => Ok(DataFormat::DecimalI64),
+            21 => Ok(DataFormat::FramedStrings),
+            22 => Ok(DataFormat::FramedBlobs),
             _ => Err(Error::invalid_format(value)),
         }
     }
 
+    /// Parse a format whose elements all take `bytes_per_element`, as the
+    /// fill, neighbor, diff, tensor and record paths need; framed layouts
+    /// come only from their own fill functions
+    fn fixed_width(value: u8) -> Result<Self, Error> {
+        let format = DataFormat::from_u8(value)?;
+        if format.is_framed() {
+            return Err(Error::invalid_parameter(
+                "format",
+                &format!("{format:?} has no fixed element width"),
+            ));
+        }
+        Ok(format)
+    }
+
+    /// Whether elements are length-prefixed frames rather than fixed-width
+    fn is_framed(self) -> bool {
+        matches!(self, DataFormat::FramedStrings | DataFormat::FramedBlobs)
+    }
+
+    /// The little-endian format producing the same values, and whether this
+    /// format stores them byte-swapped
+    fn byte_order(self) -> (DataFormat, bool) {
+        match self {
+            DataFormat::U16BE => (DataFormat::U16LE, true),
+            DataFormat::U32BE => (DataFormat::U32LE, true),
+            DataFormat::U64BE => (DataFormat::U64LE, true),
+            DataFormat::U128BE => (DataFormat::U128LE, true),
+            DataFormat::I32BE => (DataFormat::I32LE, true),
+            DataFormat::I64BE => (DataFormat::I64LE, true),
+            DataFormat::F32BE => (DataFormat::F32LE, true),
+            DataFormat::F64BE => (DataFormat::F64LE, true),
+            _ => (self, false),
+        }
+    }
+
+    /// Bytes of data for `count` elements
+    fn data_size(self, count: u64) -> u64 {
+        match self {
+            DataFormat::BoolPacked => count.div_ceil(8),
+            _ => count * self.bytes_per_element(),
+        }
+    }
+
+    /// Width of one element; BoolPacked elements share bytes, see `data_size`
     fn bytes_per_element(self) -> u64 {
         match self {
             DataFormat::U32LE => 4,
             DataFormat::F64LE => 8,
             DataFormat::BoolU8 => 1,
+            DataFormat::U64LE => 8,
+            DataFormat::I32LE => 4,
+            DataFormat::I64LE => 8,
+            DataFormat::F32LE => 4,
+            DataFormat::U8 => 1,
+            DataFormat::U16LE => 2,
+            DataFormat::U128LE => 16,
+            DataFormat::CharU32 => 4,
+            DataFormat::BoolPacked => 1,
+            DataFormat::U16BE => 2,
+            DataFormat::U32BE | DataFormat::I32BE | DataFormat::F32BE => 4,
+            DataFormat::U64BE | DataFormat::I64BE | DataFormat::F64BE => 8,
+            DataFormat::DecimalI64 => 8,
+            DataFormat::U128BE => 16,
+            // Frames vary in length; `fixed_width` keeps these out
+            DataFormat::FramedStrings | DataFormat::FramedBlobs => 0,
         }
     }
 }
@@ -57,11 +253,149 @@ fn mix_gamma(mut z: u64) -> u64 {
     (z | 1).wrapping_mul(GOLDEN_GAMMA)
 }
 
+/// Validate that a probability parameter lies in [0, 1]
+fn check_probability(param: &str, p: f64) -> Result<f64, Error> {
+    if p.is_finite() && (0.0..=1.0).contains(&p) {
+        Ok(p)
+    } else {
+        Err(Error::invalid_parameter(param, &p.to_string()))
+    }
+}
+
+/// Convert a raw output to a float in [0, 1) using the top 53 bits
+fn to_unit_f64(output: u64) -> f64 {
+    (output >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+/// Convert a raw output to a single-precision float in [0, 1) using the top 24 bits
+fn to_unit_f32(output: u64) -> f32 {
+    (output >> 40) as f32 * (1.0 / (1u32 << 24) as f32)
+}
+
+/// Map a raw output uniformly onto the inclusive range [min, max]
+fn to_range_i64(output: u64, min: i64, max: i64) -> i64 {
+    let span = (max as i128 - min as i128 + 1) as u128;
+    if span > u64::MAX as u128 {
+        return output as i64;
+    }
+    let offset = ((output as u128 * span) >> 64) as i128;
+    (min as i128 + offset) as i64
+}
+
 /// SplitMix64 seed with state and gamma
+#[derive(Debug, Clone)]
 #[wasm_bindgen]
 pub struct Seed {
     state: u64,
     gamma: u64,
+    draws: u64,
+    draws_since_split: u64,
+}
+
+impl Seed {
+    /// Seed with fresh draw counters
+    fn with_parts(state: u64, gamma: u64) -> Seed {
+        Seed {
+            state,
+            gamma,
+            draws: 0,
+            draws_since_split: 0,
+        }
+    }
+
+    /// Advance this seed in place and return the next raw output
+    fn step(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(self.gamma);
+        self.draws += 1;
+        self.draws_since_split += 1;
+        splitmix64_mix(self.state)
+    }
+
+    /// Advance in place and return `length` random bytes, taking eight
+    /// little-endian bytes from each draw
+    fn step_bytes(&mut self, length: usize) -> Vec<u8> {
+        let mut bytes = vec![0; length];
+        self.step_fill(&mut bytes);
+        bytes
+    }
+
+    /// Advance in place, overwriting `out` with random bytes in the same
+    /// layout as `step_bytes`
+    fn step_fill(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(8) {
+            let bytes = self.step().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Advance in place and return an unbiased value in [0, bound) for
+    /// bound > 0, rejecting the outputs that would favour low values
+    fn step_below(&mut self, bound: u64) -> u64 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = self.step() as u128 * bound as u128;
+            if product as u64 >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+
+    /// Advance in place and return true with probability exactly `p`.
+    /// Draws are compared, 64 bits at a time, against the binary expansion
+    /// of `p` as if drawing a uniform real in [0, 1) digit by digit, so
+    /// every digit of `p` counts, down to subnormals. Almost always one
+    /// draw; more only while the draws match `p`.
+    fn step_bernoulli(&mut self, p: f64) -> bool {
+        let mut output = self.step();
+        if p >= 1.0 {
+            return true;
+        }
+        if p <= 0.0 {
+            return false;
+        }
+        // p = mantissa * 2^-exponent, with subnormals using a fixed exponent
+        let bits = p.to_bits();
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = match (bits >> 52) as i32 {
+            0 => (fraction, 1074),
+            biased => (fraction | 1 << 52, 1075 - biased),
+        };
+
+        // Digits 64w + 1 through 64w + 64 of p, as one word
+        let digits = |word: i32| -> u64 {
+            let shift = 64 * (word + 1) - exponent;
+            if (0..128).contains(&shift) {
+                ((mantissa as u128) << shift) as u64
+            } else if (-127..0).contains(&shift) {
+                ((mantissa as u128) >> -shift) as u64
+            } else {
+                0
+            }
+        };
+        let last = (exponent - 1) / 64;
+        let mut word = 0;
+        loop {
+            let expected = digits(word);
+            if output != expected {
+                return output < expected;
+            }
+            // Equal to every digit of p means the real drawn is at least p
+            if word == last {
+                return false;
+            }
+            word += 1;
+            output = self.step();
+        }
+    }
+
+    /// Independent seed for the stream tagged `name`, derived from the state
+    /// and gamma alone so that draws made on it never shift this stream
+    fn named(&self, name: &str) -> Seed {
+        let tag = encoding::fnv1a(name.as_bytes());
+        let state = splitmix64_mix(self.state ^ tag);
+        let gamma = mix_gamma(self.gamma ^ splitmix64_mix(tag));
+        Seed::from_parts(state, gamma)
+    }
 }
 
 #[wasm_bindgen]
@@ -71,13 +405,13 @@ impl Seed {
     pub fn new(value: u64) -> Seed {
         let state = splitmix64_mix(value);
         let gamma = mix_gamma(state);
-        Seed { state, gamma }
+        Seed::with_parts(state, gamma)
     }
 
     /// Create seed from state and gamma components
     #[wasm_bindgen]
     pub fn from_parts(state: u64, gamma: u64) -> Seed {
-        Seed { state, gamma }
+        Seed::with_parts(state, gamma)
     }
 
     /// Get the state component
@@ -92,21 +426,90 @@ impl Seed {
         self.gamma
     }
 
+    /// Number of draws consumed since this seed's stream was constructed,
+    /// including draws made before any split along the way
+    #[wasm_bindgen(getter)]
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+
+    /// Number of draws consumed since the last split
+    #[wasm_bindgen(getter)]
+    pub fn draws_since_split(&self) -> u64 {
+        self.draws_since_split
+    }
+
     /// Generate next random u64 and new seed
     #[wasm_bindgen]
     pub fn next_u64(&self) -> SeedAndValue {
-        let new_state = self.state.wrapping_add(self.gamma);
-        let output = splitmix64_mix(new_state);
-        let new_seed = Seed {
-            state: new_state,
-            gamma: self.gamma,
-        };
+        let mut new_seed = self.clone();
+        let output = new_seed.step();
         SeedAndValue {
             seed: new_seed,
             value: output,
         }
     }
 
+    /// Split one 64-bit output into two u32s: `first` is the low half and
+    /// `second` the high half. This mapping is part of the stable stream.
+    #[wasm_bindgen]
+    pub fn next_u32_pair(&self) -> SeedAndU32Pair {
+        let result = self.next_u64();
+        SeedAndU32Pair {
+            seed: result.seed,
+            first: result.value as u32,
+            second: (result.value >> 32) as u32,
+        }
+    }
+
+    /// Generate a 128-bit value from two outputs, the first supplying the
+    /// low 64 bits and the second the high 64 bits
+    #[wasm_bindgen]
+    pub fn next_u128(&self) -> SeedAndU128 {
+        let mut seed = self.clone();
+        let lo = seed.step();
+        let hi = seed.step();
+        SeedAndU128 { seed, hi, lo }
+    }
+
+    /// Generate `n` consecutive outputs (at most 16) in one call, for
+    /// composite values such as 128-bit ids. The stream matches `n`
+    /// chained `next_u64` calls.
+    #[wasm_bindgen]
+    pub fn next_u64_n(&self, n: u32) -> Result<SeedAndU64s, Error> {
+        if n as usize > MAX_U64_N {
+            return Err(Error::invalid_parameter("n", &n.to_string()));
+        }
+        let mut seed = self.clone();
+        let mut values = [0; MAX_U64_N];
+        for value in values.iter_mut().take(n as usize) {
+            *value = seed.step();
+        }
+        Ok(SeedAndU64s {
+            seed,
+            values,
+            length: n as usize,
+        })
+    }
+
+    /// Generate `count` u32s taking both halves of each output, low half
+    /// first, so the stream matches repeated `next_u32_pair` calls. An odd
+    /// count discards the high half of the last output.
+    #[wasm_bindgen]
+    pub fn next_u32_batch(&self, count: u32) -> BatchU32Result {
+        let started = metrics::start();
+        let mut final_seed = self.clone();
+        let mut values = Vec::with_capacity(count as usize + 1);
+        while values.len() < count as usize {
+            let output = final_seed.step();
+            values.push(output as u32);
+            values.push((output >> 32) as u32);
+        }
+        values.truncate(count as usize);
+        metrics::record_call("next_u32_batch", started);
+        BatchU32Result { values, final_seed }
+    }
+
     /// Generate bounded random value
     #[wasm_bindgen]
     pub fn next_bounded(&self, bound: u64) -> SeedAndValue {
@@ -118,6 +521,50 @@ impl Seed {
         }
     }
 
+    /// Generate an unbiased value in [0, bound) for any 64-bit bound, using
+    /// Lemire's widening multiply with rejection
+    #[wasm_bindgen]
+    pub fn next_bounded_u64(&self, bound: u64) -> Result<SeedAndValue, Error> {
+        if bound == 0 {
+            return Err(Error::invalid_parameter("bound", "0"));
+        }
+        let mut seed = self.clone();
+        let value = seed.step_below(bound);
+        Ok(SeedAndValue { seed, value })
+    }
+
+    /// Generate an unbiased value in the inclusive range [min, max]
+    #[wasm_bindgen]
+    pub fn next_bounded_i64(&self, min: i64, max: i64) -> Result<SeedAndI64, Error> {
+        if min > max {
+            return Err(Error::invalid_parameter(
+                "range",
+                &format!("[{min}, {max}]"),
+            ));
+        }
+        let mut seed = self.clone();
+        let span = max.wrapping_sub(min) as u64;
+        let offset = if span == u64::MAX {
+            seed.step()
+        } else {
+            seed.step_below(span + 1)
+        };
+        Ok(SeedAndI64 {
+            seed,
+            value: min.wrapping_add(offset as i64),
+        })
+    }
+
+    /// Generate `n` random bytes
+    #[wasm_bindgen]
+    pub fn next_bytes(&self, n: u32) -> SeedAndBytes {
+        let started = metrics::start();
+        let mut seed = self.clone();
+        let bytes = seed.step_bytes(n as usize);
+        metrics::record_call("next_bytes", started);
+        SeedAndBytes { seed, bytes }
+    }
+
     /// Generate random boolean
     #[wasm_bindgen]
     pub fn next_bool(&self) -> SeedAndBool {
@@ -128,20 +575,32 @@ impl Seed {
         }
     }
 
+    /// Generate true with probability `p` in [0, 1], exactly: unlike
+    /// comparing a 53-bit float against `p`, which rounds every probability
+    /// to a multiple of 2^-53, probabilities like 1e-20 are honored, so
+    /// rare-event generators fire at the right rate. Always takes at least
+    /// one draw.
+    #[wasm_bindgen]
+    pub fn next_bool_p(&self, p: f64) -> Result<SeedAndBool, Error> {
+        let p = check_probability("p", p)?;
+        let mut seed = self.clone();
+        let value = seed.step_bernoulli(p);
+        Ok(SeedAndBool { seed, value })
+    }
+
     /// Split seed into two independent seeds
     #[wasm_bindgen]
     pub fn split(&self) -> SeedPair {
-        let new_state = self.state.wrapping_add(self.gamma);
-        let output = splitmix64_mix(new_state);
+        let mut left_seed = self.clone();
+        let output = left_seed.step();
         let new_gamma = mix_gamma(output);
+        left_seed.draws_since_split = 0;
 
-        let left_seed = Seed {
-            state: new_state,
-            gamma: self.gamma,
-        };
         let right_seed = Seed {
             state: output,
             gamma: new_gamma,
+            draws: left_seed.draws,
+            draws_since_split: 0,
         };
 
         SeedPair {
@@ -150,25 +609,37 @@ impl Seed {
         }
     }
 
+    /// Seed for lane `index`, derived in O(1) from this seed. It equals the
+    /// right seed of `split()` after `index` draws, so lanes are independent
+    /// streams and shards can each compute their own from a shared root.
+    #[wasm_bindgen]
+    pub fn split_at(&self, index: u64) -> Seed {
+        let steps = index.wrapping_add(1);
+        let output = splitmix64_mix(self.state.wrapping_add(self.gamma.wrapping_mul(steps)));
+        Seed {
+            state: output,
+            gamma: mix_gamma(output),
+            draws: self.draws.wrapping_add(steps),
+            draws_since_split: 0,
+        }
+    }
+
     /// Generate multiple booleans in a single call (batched for performance)
     #[wasm_bindgen]
     pub fn next_bools_batch(&self, count: u32) -> BatchBoolResult {
+        let started = metrics::start();
         let mut results = Vec::with_capacity(count as usize);
-        let mut current_state = self.state;
-        let gamma = self.gamma;
+        let mut final_seed = self.clone();
 
         for _ in 0..count {
-            current_state = current_state.wrapping_add(gamma);
-            let output = splitmix64_mix(current_state);
+            let output = final_seed.step();
             results.push(if output & 1 == 1 { 1 } else { 0 });
         }
 
+        metrics::record_call("next_bools_batch", started);
         BatchBoolResult {
             values: results,
-            final_seed: Seed {
-                state: current_state,
-                gamma,
-            },
+            final_seed,
         }
     }
 
@@ -182,73 +653,8 @@ impl Seed {
         count: u64,
         bound: Option<u32>,
     ) -> Result<Seed, Error> {
-        // Validation limits
-        const PRACTICAL_MAX_BUFFER: u64 = 1024 * 1024 * 1024; // 1GB conservative limit
-
-        if buffer.len() as u64 > PRACTICAL_MAX_BUFFER {
-            return Err(Error::buffer_too_large(
-                buffer.len() as u64 >> 20,
-                PRACTICAL_MAX_BUFFER >> 20,
-            ));
-        }
-
-        let format = DataFormat::from_u8(format_u8)?;
-        let bytes_per_element = format.bytes_per_element();
-        let header_size = 9; // 1 byte format + 8 bytes count
-        let data_size = count * bytes_per_element;
-        let required_size = header_size + data_size;
-
-        if buffer.len() < required_size as usize {
-            return Err(Error::buffer_too_small(required_size, buffer.len()));
-        }
-
-        // Write header
-        buffer[0] = format_u8;
-        buffer[1..9].copy_from_slice(&count.to_le_bytes());
-
-        // Generate data
-        let mut current_state = self.state;
-        let gamma = self.gamma;
-        let data_start = header_size as usize;
-
-        match format {
-            DataFormat::U32LE => {
-                let bound_u64 = bound.unwrap_or(u32::MAX) as u64;
-                for i in 0..count as usize {
-                    current_state = current_state.wrapping_add(gamma);
-                    let output = splitmix64_mix(current_state);
-                    let bounded = if bound_u64 == u32::MAX as u64 {
-                        output as u32
-                    } else {
-                        ((output as u128 * bound_u64 as u128) >> 64) as u32
-                    };
-                    let offset = data_start + i * 4;
-                    buffer[offset..offset + 4].copy_from_slice(&bounded.to_le_bytes());
-                }
-            }
-            DataFormat::F64LE => {
-                for i in 0..count as usize {
-                    current_state = current_state.wrapping_add(gamma);
-                    let output = splitmix64_mix(current_state);
-                    // Convert to [0, 1) range with high precision
-                    let float_val = (output >> 11) as f64 * (1.0 / (1u64 << 53) as f64);
-                    let offset = data_start + i * 8;
-                    buffer[offset..offset + 8].copy_from_slice(&float_val.to_le_bytes());
-                }
-            }
-            DataFormat::BoolU8 => {
-                for i in 0..count as usize {
-                    current_state = current_state.wrapping_add(gamma);
-                    let output = splitmix64_mix(current_state);
-                    buffer[data_start + i] = if output & 1 == 1 { 1 } else { 0 };
-                }
-            }
-        }
-
-        Ok(Seed {
-            state: current_state,
-            gamma,
-        })
+        let params = FillParams::new().with_bound(bound);
+        self.fill_buffer_with_params(buffer, format_u8, count, &params)
     }
 }
 
@@ -263,10 +669,7 @@ pub struct SeedAndValue {
 impl SeedAndValue {
     #[wasm_bindgen(getter)]
     pub fn seed(&self) -> Seed {
-        Seed {
-            state: self.seed.state,
-            gamma: self.seed.gamma,
-        }
+        self.seed.clone()
     }
 
     #[wasm_bindgen(getter)]
@@ -286,10 +689,7 @@ pub struct SeedAndBool {
 impl SeedAndBool {
     #[wasm_bindgen(getter)]
     pub fn seed(&self) -> Seed {
-        Seed {
-            state: self.seed.state,
-            gamma: self.seed.gamma,
-        }
+        self.seed.clone()
     }
 
     #[wasm_bindgen(getter)]
@@ -298,6 +698,190 @@ impl SeedAndBool {
     }
 }
 
+/// Return type for operations that produce a seed and u32 value
+#[wasm_bindgen]
+pub struct SeedAndU32 {
+    seed: Seed,
+    value: u32,
+}
+
+#[wasm_bindgen]
+impl SeedAndU32 {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+/// Return type for operations that produce a seed and a byte array
+#[wasm_bindgen]
+pub struct SeedAndBytes {
+    seed: Seed,
+    bytes: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl SeedAndBytes {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+}
+
+/// Return type for `next_u128`, with the value split into two u64 halves
+#[wasm_bindgen]
+pub struct SeedAndU128 {
+    seed: Seed,
+    hi: u64,
+    lo: u64,
+}
+
+#[wasm_bindgen]
+impl SeedAndU128 {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    /// High 64 bits
+    #[wasm_bindgen(getter)]
+    pub fn hi(&self) -> u64 {
+        self.hi
+    }
+
+    /// Low 64 bits
+    #[wasm_bindgen(getter)]
+    pub fn lo(&self) -> u64 {
+        self.lo
+    }
+}
+
+/// Most outputs `next_u64_n` returns in one call
+const MAX_U64_N: usize = 16;
+
+/// Return type for `next_u64_n`: a seed and up to 16 u64 values held
+/// inline rather than on the heap
+#[wasm_bindgen]
+pub struct SeedAndU64s {
+    seed: Seed,
+    values: [u64; MAX_U64_N],
+    length: usize,
+}
+
+#[wasm_bindgen]
+impl SeedAndU64s {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn values(&self) -> Vec<u64> {
+        self.values[..self.length].to_vec()
+    }
+
+    /// Value at `index`, or `undefined` past the end
+    pub fn get(&self, index: usize) -> Option<u64> {
+        self.values[..self.length].get(index).copied()
+    }
+}
+
+/// Return type for operations that produce a seed and two u32 values
+#[wasm_bindgen]
+pub struct SeedAndU32Pair {
+    seed: Seed,
+    first: u32,
+    second: u32,
+}
+
+#[wasm_bindgen]
+impl SeedAndU32Pair {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn first(&self) -> u32 {
+        self.first
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+}
+
+/// Return type for operations that produce a seed and signed i64 value
+#[wasm_bindgen]
+pub struct SeedAndI64 {
+    seed: Seed,
+    value: i64,
+}
+
+#[wasm_bindgen]
+impl SeedAndI64 {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+/// Return type for operations that produce a seed and f64 value
+#[wasm_bindgen]
+pub struct SeedAndF64 {
+    seed: Seed,
+    value: f64,
+}
+
+#[wasm_bindgen]
+impl SeedAndF64 {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// Return type for operations that produce a seed and f32 value
+#[wasm_bindgen]
+pub struct SeedAndF32 {
+    seed: Seed,
+    value: f32,
+}
+
+#[wasm_bindgen]
+impl SeedAndF32 {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
 /// Return type for seed splitting
 #[wasm_bindgen]
 pub struct SeedPair {
@@ -312,22 +896,23 @@ pub struct BatchBoolResult {
     final_seed: Seed,
 }
 
+/// Return type for batch u32 operations
+#[wasm_bindgen]
+pub struct BatchU32Result {
+    values: Vec<u32>,
+    final_seed: Seed,
+}
+
 #[wasm_bindgen]
 impl SeedPair {
     #[wasm_bindgen(getter)]
     pub fn left(&self) -> Seed {
-        Seed {
-            state: self.left.state,
-            gamma: self.left.gamma,
-        }
+        self.left.clone()
     }
 
     #[wasm_bindgen(getter)]
     pub fn right(&self) -> Seed {
-        Seed {
-            state: self.right.state,
-            gamma: self.right.gamma,
-        }
+        self.right.clone()
     }
 }
 
@@ -340,9 +925,19 @@ impl BatchBoolResult {
 
     #[wasm_bindgen(getter)]
     pub fn final_seed(&self) -> Seed {
-        Seed {
-            state: self.final_seed.state,
-            gamma: self.final_seed.gamma,
-        }
+        self.final_seed.clone()
+    }
+}
+
+#[wasm_bindgen]
+impl BatchU32Result {
+    #[wasm_bindgen(getter)]
+    pub fn values(&self) -> Vec<u32> {
+        self.values.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn final_seed(&self) -> Seed {
+        self.final_seed.clone()
     }
 }
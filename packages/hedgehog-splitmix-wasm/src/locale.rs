@@ -0,0 +1,306 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::faker::{FakeBatch, SeedAndString};
+use crate::{check_probability, to_unit_f64, Seed};
+
+/// Most integer digits of a formatted number, keeping it exact in an f64
+const MAX_INTEGER_DIGITS: u32 = 15;
+
+/// Most fraction digits of a formatted number
+const MAX_FRACTION_DIGITS: u32 = 9;
+
+/// Conventions for formatting numbers and dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[wasm_bindgen]
+pub enum Locale {
+    /// `1,234,567.89` and `03/25/2024`
+    #[default]
+    EnUs = 0,
+    /// `1,234,567.89` and `25/03/2024`
+    EnGb = 1,
+    /// `1.234.567,89` and `25.03.2024`
+    DeDe = 2,
+    /// `1 234 567,89` (narrow no-break space) and `25/03/2024`
+    FrFr = 3,
+    /// `1’234’567.89` and `25.03.2024`
+    DeCh = 4,
+    /// `12,34,567.89` (lakh grouping) and `25/03/2024`
+    EnIn = 5,
+    /// `1,234,567.89` and `2024/03/25`
+    JaJp = 6,
+}
+
+/// Date component orders
+enum DateOrder {
+    MonthDayYear,
+    DayMonthYear,
+    YearMonthDay,
+}
+
+impl Locale {
+    /// Grouping separator and decimal separator
+    fn separators(self) -> (&'static str, char) {
+        match self {
+            Locale::EnUs | Locale::EnGb | Locale::EnIn | Locale::JaJp => (",", '.'),
+            Locale::DeDe => (".", ','),
+            Locale::FrFr => ("\u{202f}", ','),
+            Locale::DeCh => ("\u{2019}", '.'),
+        }
+    }
+
+    fn date_order(self) -> (DateOrder, char) {
+        match self {
+            Locale::EnUs => (DateOrder::MonthDayYear, '/'),
+            Locale::EnGb | Locale::FrFr | Locale::EnIn => (DateOrder::DayMonthYear, '/'),
+            Locale::DeDe | Locale::DeCh => (DateOrder::DayMonthYear, '.'),
+            Locale::JaJp => (DateOrder::YearMonthDay, '/'),
+        }
+    }
+}
+
+/// Values produced by `locale_string` and `locale_batch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum LocaleKind {
+    /// Decimal number with grouped integer digits
+    Number = 0,
+    /// Calendar date with zero-padded day and month
+    Date = 1,
+}
+
+/// Parameters for `locale_string` and `locale_batch`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct LocaleParams {
+    locale: Locale,
+    max_integer_digits: u32,
+    fraction_digits: u32,
+    negative_probability: f64,
+    min_year: u32,
+    max_year: u32,
+}
+
+impl Default for LocaleParams {
+    fn default() -> LocaleParams {
+        LocaleParams {
+            locale: Locale::EnUs,
+            max_integer_digits: 7,
+            fraction_digits: 2,
+            negative_probability: 0.0,
+            min_year: 1900,
+            max_year: 2100,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl LocaleParams {
+    /// US English numbers of up to 7 integer digits and 2 fraction digits,
+    /// and dates from 1900 to 2100
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> LocaleParams {
+        LocaleParams::default()
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> LocaleParams {
+        self.locale = locale;
+        self
+    }
+
+    /// Most integer digits of a number (1 to 15); the digit count is drawn
+    /// uniformly, so small and large magnitudes are equally common
+    pub fn with_max_integer_digits(mut self, digits: u32) -> LocaleParams {
+        self.max_integer_digits = digits;
+        self
+    }
+
+    /// Fraction digits of a number (0 to 9), with no decimal separator at 0
+    pub fn with_fraction_digits(mut self, digits: u32) -> LocaleParams {
+        self.fraction_digits = digits;
+        self
+    }
+
+    /// Probability of a number being negative
+    pub fn with_negative_probability(mut self, p: f64) -> LocaleParams {
+        self.negative_probability = p;
+        self
+    }
+
+    /// Inclusive range of years for dates
+    pub fn with_years(mut self, min_year: u32, max_year: u32) -> LocaleParams {
+        self.min_year = min_year;
+        self.max_year = max_year;
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_integer_digits(&self) -> u32 {
+        self.max_integer_digits
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn fraction_digits(&self) -> u32 {
+        self.fraction_digits
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn negative_probability(&self) -> f64 {
+        self.negative_probability
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min_year(&self) -> u32 {
+        self.min_year
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_year(&self) -> u32 {
+        self.max_year
+    }
+}
+
+impl LocaleParams {
+    fn validate(&self) -> Result<(), Error> {
+        if !(1..=MAX_INTEGER_DIGITS).contains(&self.max_integer_digits) {
+            return Err(Error::invalid_parameter(
+                "max_integer_digits",
+                &format!(
+                    "{}, expected 1 to {MAX_INTEGER_DIGITS}",
+                    self.max_integer_digits
+                ),
+            ));
+        }
+        if self.fraction_digits > MAX_FRACTION_DIGITS {
+            return Err(Error::invalid_parameter(
+                "fraction_digits",
+                &format!("{} exceeds {MAX_FRACTION_DIGITS}", self.fraction_digits),
+            ));
+        }
+        check_probability("negative_probability", self.negative_probability)?;
+        if self.min_year == 0 || self.min_year > self.max_year || self.max_year > 9999 {
+            return Err(Error::invalid_parameter(
+                "years",
+                &format!(
+                    "[{}, {}], expected within [1, 9999]",
+                    self.min_year, self.max_year
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Seed {
+    /// Advance in place, appending `count` random decimal digits, the first
+    /// nonzero when `leading` is set
+    fn push_digits(&mut self, count: u32, leading: bool, out: &mut String) {
+        for i in 0..count {
+            let digit = if leading && i == 0 {
+                1 + self.step_below(9)
+            } else {
+                self.step_below(10)
+            };
+            out.push(char::from(b'0' + digit as u8));
+        }
+    }
+
+    /// Advance in place, appending one value of `kind` to `out`
+    fn push_locale(&mut self, kind: LocaleKind, params: &LocaleParams, out: &mut String) {
+        match kind {
+            LocaleKind::Number => {
+                let (group, decimal) = params.locale.separators();
+                if params.negative_probability > 0.0
+                    && to_unit_f64(self.step()) < params.negative_probability
+                {
+                    out.push('-');
+                }
+                let length = 1 + self.step_below(params.max_integer_digits as u64) as u32;
+                let mut digits = String::new();
+                self.push_digits(length, length > 1, &mut digits);
+                for (i, digit) in digits.chars().enumerate() {
+                    let remaining = length as usize - i;
+                    // Indian grouping puts the last three digits together
+                    // and pairs the rest
+                    let separate = match params.locale {
+                        Locale::EnIn => remaining == 3 || remaining > 3 && (remaining - 3) % 2 == 0,
+                        _ => remaining % 3 == 0,
+                    };
+                    if i > 0 && separate {
+                        out.push_str(group);
+                    }
+                    out.push(digit);
+                }
+                if params.fraction_digits > 0 {
+                    out.push(decimal);
+                    self.push_digits(params.fraction_digits, false, out);
+                }
+            }
+            LocaleKind::Date => {
+                let years = (params.max_year - params.min_year) as u64 + 1;
+                let year = params.min_year + self.step_below(years) as u32;
+                let month = 1 + self.step_below(12) as u32;
+                let day = 1 + self.step_below(days_in_month(year, month) as u64) as u32;
+                let (order, separator) = params.locale.date_order();
+                let date = match order {
+                    DateOrder::MonthDayYear => {
+                        format!("{month:02}{separator}{day:02}{separator}{year:04}")
+                    }
+                    DateOrder::DayMonthYear => {
+                        format!("{day:02}{separator}{month:02}{separator}{year:04}")
+                    }
+                    DateOrder::YearMonthDay => {
+                        format!("{year:04}{separator}{month:02}{separator}{day:02}")
+                    }
+                };
+                out.push_str(&date);
+            }
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Generate one number or date formatted by the conventions of
+    /// `params.locale`, for testing parsers of international input
+    #[wasm_bindgen]
+    pub fn locale_string(
+        &self,
+        kind: LocaleKind,
+        params: &LocaleParams,
+    ) -> Result<SeedAndString, Error> {
+        params.validate()?;
+        let mut seed = self.clone();
+        let mut value = String::new();
+        seed.push_locale(kind, params, &mut value);
+        Ok(SeedAndString { seed, value })
+    }
+
+    /// Generate `count` locale-formatted values into one framed buffer, as
+    /// `fake_batch` does. The stream matches `count` chained
+    /// `locale_string` calls.
+    #[wasm_bindgen]
+    pub fn locale_batch(
+        &self,
+        kind: LocaleKind,
+        params: &LocaleParams,
+        count: u32,
+    ) -> Result<FakeBatch, Error> {
+        params.validate()?;
+        Ok(self.string_batch(count, |seed, value| seed.push_locale(kind, params, value)))
+    }
+}
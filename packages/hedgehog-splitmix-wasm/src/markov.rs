@@ -0,0 +1,128 @@
+use wasm_bindgen::prelude::*;
+
+use crate::distributions::WeightedSampler;
+use crate::error::Error;
+use crate::fill::HEADER_SIZE;
+use crate::{BatchU32Result, DataFormat, Seed};
+
+/// Token sequences sampled from a Markov chain over caller-supplied token
+/// ids, e.g. protocol message types or log line templates. Each state has
+/// an alias-method sampler for its row of the transition matrix, so every
+/// step takes one draw regardless of the number of tokens.
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct MarkovChain {
+    tokens: Vec<u32>,
+    start: WeightedSampler,
+    /// Sampler for each state's successors, or `None` for a state whose row
+    /// is all zeros and so ends the sequence
+    transitions: Vec<Option<WeightedSampler>>,
+}
+
+impl MarkovChain {
+    /// Advance in place, appending up to `max_length` token ids to `out`
+    fn step_sequence(&self, seed: &mut Seed, max_length: u32, out: &mut Vec<u32>) {
+        if max_length == 0 {
+            return;
+        }
+        let mut state = self.start.step_sample(seed) as usize;
+        out.push(self.tokens[state]);
+        for _ in 1..max_length {
+            let Some(row) = &self.transitions[state] else {
+                break;
+            };
+            state = row.step_sample(seed) as usize;
+            out.push(self.tokens[state]);
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl MarkovChain {
+    /// Build a chain over `tokens`: sequences begin at state `i` with
+    /// probability proportional to `start[i]`, and move from state `i` to
+    /// state `j` with probability proportional to `transitions[i * n + j]`
+    /// (a row-major n×n matrix of weights). A row of zeros marks a state
+    /// that ends the sequence.
+    #[wasm_bindgen(constructor)]
+    pub fn new(tokens: &[u32], start: &[f64], transitions: &[f64]) -> Result<MarkovChain, Error> {
+        let n = tokens.len();
+        if n == 0 {
+            return Err(Error::invalid_parameter("tokens", "empty"));
+        }
+        if start.len() != n {
+            return Err(Error::invalid_parameter(
+                "start",
+                &format!("{} weights for {n} tokens", start.len()),
+            ));
+        }
+        if transitions.len() != n * n {
+            return Err(Error::invalid_parameter(
+                "transitions",
+                &format!(
+                    "{} weights for {n} tokens, expected {}",
+                    transitions.len(),
+                    n * n
+                ),
+            ));
+        }
+
+        let rows = transitions
+            .chunks(n)
+            .map(|row| {
+                if row.iter().all(|&weight| weight == 0.0) {
+                    Ok(None)
+                } else {
+                    WeightedSampler::new(row).map(Some)
+                }
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(MarkovChain {
+            tokens: tokens.to_vec(),
+            start: WeightedSampler::new(start)?,
+            transitions: rows,
+        })
+    }
+
+    /// Number of tokens (states)
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Sample one sequence of at most `max_length` token ids, shorter if it
+    /// reaches a terminal state
+    pub fn sample(&self, seed: &Seed, max_length: u32) -> BatchU32Result {
+        let mut final_seed = seed.clone();
+        let mut values = Vec::new();
+        self.step_sequence(&mut final_seed, max_length, &mut values);
+        BatchU32Result { values, final_seed }
+    }
+
+    /// Sample one sequence into a U32LE buffer (see `fill_buffer`), whose
+    /// header count is the number of tokens produced. The buffer must hold
+    /// `max_length` tokens.
+    pub fn fill_buffer(
+        &self,
+        seed: &Seed,
+        buffer: &mut [u8],
+        max_length: u32,
+    ) -> Result<Seed, Error> {
+        let required = HEADER_SIZE + max_length as u64 * 4;
+        if (buffer.len() as u64) < required {
+            return Err(Error::buffer_too_small(required, buffer.len()));
+        }
+
+        let mut final_seed = seed.clone();
+        let mut tokens = Vec::with_capacity(max_length as usize);
+        self.step_sequence(&mut final_seed, max_length, &mut tokens);
+
+        buffer[0] = DataFormat::U32LE as u8;
+        buffer[1..9].copy_from_slice(&(tokens.len() as u64).to_le_bytes());
+        for (i, token) in tokens.iter().enumerate() {
+            let offset = HEADER_SIZE as usize + i * 4;
+            buffer[offset..offset + 4].copy_from_slice(&token.to_le_bytes());
+        }
+        Ok(final_seed)
+    }
+}
@@ -0,0 +1,429 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use wasm_bindgen::prelude::*;
+
+use crate::DataFormat;
+
+/// Whether instrumented entry points record anything. Disabled by default so
+/// the only cost outside metrics mode is one relaxed load per call.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Accumulated metrics since the last reset
+static METRICS: Mutex<Metrics> = Mutex::new(Metrics::new());
+
+/// Number of buffer formats tracked, indexed by the format tag
+const FORMAT_COUNT: usize = DataFormat::COUNT;
+
+/// Latency histogram buckets: bucket 0 counts calls under 1µs, bucket `i`
+/// counts calls in [2^(i-1), 2^i) µs, and the last bucket everything slower
+const LATENCY_BUCKETS: usize = 32;
+
+#[derive(Debug, Clone, Copy)]
+struct FormatStats {
+    calls: u64,
+    elements: u64,
+    bytes: u64,
+    micros: f64,
+    histogram: [u64; LATENCY_BUCKETS],
+}
+
+impl FormatStats {
+    const fn new() -> FormatStats {
+        FormatStats {
+            calls: 0,
+            elements: 0,
+            bytes: 0,
+            micros: 0.0,
+            histogram: [0; LATENCY_BUCKETS],
+        }
+    }
+}
+
+/// Filter outcomes of one `next_until` call site
+#[derive(Debug, Clone)]
+struct SiteStats {
+    site: String,
+    calls: u64,
+    attempts: u64,
+    discards: u64,
+    gave_up: u64,
+}
+
+impl SiteStats {
+    fn discard_ratio(&self) -> f64 {
+        if self.attempts > 0 {
+            self.discards as f64 / self.attempts as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Metrics {
+    formats: [FormatStats; FORMAT_COUNT],
+    /// Calls into the module per entry point name, with total time in µs
+    crossings: Vec<(&'static str, u64, f64)>,
+    /// Filter outcomes per call site, in first-seen order
+    sites: Vec<SiteStats>,
+}
+
+impl Metrics {
+    const fn new() -> Metrics {
+        Metrics {
+            formats: [FormatStats::new(); FORMAT_COUNT],
+            crossings: Vec::new(),
+            sites: Vec::new(),
+        }
+    }
+
+    fn record_site(&mut self, site: &str, attempts: u64, discards: u64, gave_up: bool) {
+        let index = match self.sites.iter().position(|stats| stats.site == site) {
+            Some(index) => index,
+            None => {
+                self.sites.push(SiteStats {
+                    site: site.to_string(),
+                    calls: 0,
+                    attempts: 0,
+                    discards: 0,
+                    gave_up: 0,
+                });
+                self.sites.len() - 1
+            }
+        };
+        let stats = &mut self.sites[index];
+        stats.calls += 1;
+        stats.attempts += attempts;
+        stats.discards += discards;
+        stats.gave_up += gave_up as u64;
+    }
+
+    fn record_crossing(&mut self, operation: &'static str, micros: f64) {
+        match self
+            .crossings
+            .iter_mut()
+            .find(|(name, ..)| *name == operation)
+        {
+            Some((_, calls, total)) => {
+                *calls += 1;
+                *total += micros;
+            }
+            None => self.crossings.push((operation, 1, micros)),
+        }
+    }
+}
+
+/// Histogram bucket for a latency in microseconds
+fn latency_bucket(micros: f64) -> usize {
+    if micros < 1.0 {
+        return 0;
+    }
+    let bucket = micros.log2().floor() as usize + 1;
+    bucket.min(LATENCY_BUCKETS - 1)
+}
+
+/// Monotonic clock in microseconds
+#[cfg(target_arch = "wasm32")]
+fn now_micros() -> f64 {
+    use js_sys::{Function, Reflect};
+    use wasm_bindgen::JsCast;
+
+    // `performance` exists in browsers, workers and Node; fall back to the
+    // millisecond wall clock where it does not.
+    let performance = Reflect::get(&js_sys::global(), &"performance".into()).ok();
+    let now = performance
+        .as_ref()
+        .and_then(|performance| Reflect::get(performance, &"now".into()).ok())
+        .and_then(|now| now.dyn_into::<Function>().ok());
+    match (performance, now) {
+        (Some(performance), Some(now)) => {
+            now.call0(&performance)
+                .ok()
+                .and_then(|value| value.as_f64())
+                .map_or_else(js_sys::Date::now, |ms| ms)
+                * 1000.0
+        }
+        _ => js_sys::Date::now() * 1000.0,
+    }
+}
+
+/// Monotonic clock in microseconds
+#[cfg(not(target_arch = "wasm32"))]
+fn now_micros() -> f64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    EPOCH.get_or_init(Instant::now).elapsed().as_secs_f64() * 1_000_000.0
+}
+
+/// Start timing a call, or `None` when metrics mode is off
+pub(crate) fn start() -> Option<f64> {
+    if ENABLED.load(Ordering::Relaxed) {
+        Some(now_micros())
+    } else {
+        None
+    }
+}
+
+/// Record one call into the module through `operation`
+pub(crate) fn record_call(operation: &'static str, started: Option<f64>) {
+    let Some(started) = started else {
+        return;
+    };
+    let micros = (now_micros() - started).max(0.0);
+    if let Ok(mut metrics) = METRICS.lock() {
+        metrics.record_crossing(operation, micros);
+    }
+}
+
+/// Record a successful buffer fill of `elements` values of `format`
+pub(crate) fn record_fill(
+    operation: &'static str,
+    format: DataFormat,
+    elements: u64,
+    bytes: u64,
+    started: Option<f64>,
+) {
+    let Some(started) = started else {
+        return;
+    };
+    let micros = (now_micros() - started).max(0.0);
+    if let Ok(mut metrics) = METRICS.lock() {
+        metrics.record_crossing(operation, micros);
+        let stats = &mut metrics.formats[format as usize];
+        stats.calls += 1;
+        stats.elements += elements;
+        stats.bytes += bytes;
+        stats.micros += micros;
+        stats.histogram[latency_bucket(micros)] += 1;
+    }
+}
+
+/// Record the outcome of one filtered generation at `site`
+pub(crate) fn record_discards(site: &str, attempts: u64, discards: u64, gave_up: bool) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Ok(mut metrics) = METRICS.lock() {
+        metrics.record_site(site, attempts, discards, gave_up);
+    }
+}
+
+/// Turn metrics mode on or off. Recorded metrics are kept until
+/// `reset_metrics` is called.
+#[wasm_bindgen]
+pub fn set_metrics_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether metrics mode is on
+#[wasm_bindgen]
+pub fn metrics_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Discard everything recorded so far
+#[wasm_bindgen]
+pub fn reset_metrics() {
+    if let Ok(mut metrics) = METRICS.lock() {
+        *metrics = Metrics::new();
+    }
+}
+
+/// Snapshot of the metrics recorded so far
+#[wasm_bindgen]
+pub fn metrics_report() -> MetricsReport {
+    let metrics = METRICS
+        .lock()
+        .map(|metrics| metrics.clone())
+        .unwrap_or_else(|_| Metrics::new());
+    MetricsReport { metrics }
+}
+
+/// Structured metrics report. Per-format columns cover only formats that
+/// were generated at least once, in format tag order.
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct MetricsReport {
+    metrics: Metrics,
+}
+
+impl MetricsReport {
+    fn used_formats(&self) -> impl Iterator<Item = (u8, &FormatStats)> {
+        self.metrics
+            .formats
+            .iter()
+            .enumerate()
+            .filter(|(_, stats)| stats.calls > 0)
+            .map(|(tag, stats)| (tag as u8, stats))
+    }
+}
+
+#[wasm_bindgen]
+impl MetricsReport {
+    /// Format tag of every generated format
+    #[wasm_bindgen(getter)]
+    pub fn formats(&self) -> Vec<u8> {
+        self.used_formats().map(|(tag, _)| tag).collect()
+    }
+
+    /// Buffer fills per format
+    #[wasm_bindgen(getter)]
+    pub fn format_calls(&self) -> Vec<u64> {
+        self.used_formats().map(|(_, stats)| stats.calls).collect()
+    }
+
+    /// Elements generated per format
+    #[wasm_bindgen(getter)]
+    pub fn format_elements(&self) -> Vec<u64> {
+        self.used_formats()
+            .map(|(_, stats)| stats.elements)
+            .collect()
+    }
+
+    /// Bytes written per format
+    #[wasm_bindgen(getter)]
+    pub fn format_bytes(&self) -> Vec<u64> {
+        self.used_formats().map(|(_, stats)| stats.bytes).collect()
+    }
+
+    /// Total time spent filling buffers per format, in milliseconds
+    #[wasm_bindgen(getter)]
+    pub fn format_ms(&self) -> Vec<f64> {
+        self.used_formats()
+            .map(|(_, stats)| stats.micros / 1000.0)
+            .collect()
+    }
+
+    /// Elements generated per second per format (0 when no time was measured)
+    #[wasm_bindgen(getter)]
+    pub fn format_throughput(&self) -> Vec<f64> {
+        self.used_formats()
+            .map(|(_, stats)| {
+                if stats.micros > 0.0 {
+                    stats.elements as f64 * 1_000_000.0 / stats.micros
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Per-call latency histogram for `format`, `LATENCY_BUCKETS` counts
+    /// where bucket 0 is under 1µs and bucket `i` is [2^(i-1), 2^i) µs
+    pub fn latency_histogram(&self, format: DataFormat) -> Vec<u64> {
+        self.metrics.formats[format as usize].histogram.to_vec()
+    }
+
+    /// Names of the entry points called across the JS/WASM boundary
+    #[wasm_bindgen(getter)]
+    pub fn operations(&self) -> Vec<String> {
+        self.metrics
+            .crossings
+            .iter()
+            .map(|(name, ..)| name.to_string())
+            .collect()
+    }
+
+    /// Boundary crossings per entry point
+    #[wasm_bindgen(getter)]
+    pub fn operation_calls(&self) -> Vec<u64> {
+        self.metrics
+            .crossings
+            .iter()
+            .map(|(_, calls, _)| *calls)
+            .collect()
+    }
+
+    /// Total time spent per entry point, in milliseconds
+    #[wasm_bindgen(getter)]
+    pub fn operation_ms(&self) -> Vec<f64> {
+        self.metrics
+            .crossings
+            .iter()
+            .map(|(_, _, micros)| micros / 1000.0)
+            .collect()
+    }
+
+    /// Call sites of filtered generation (`next_until`), named by
+    /// `Constraint.with_site`
+    #[wasm_bindgen(getter)]
+    pub fn sites(&self) -> Vec<String> {
+        self.metrics
+            .sites
+            .iter()
+            .map(|stats| stats.site.clone())
+            .collect()
+    }
+
+    /// Filtered generations per call site
+    #[wasm_bindgen(getter)]
+    pub fn site_calls(&self) -> Vec<u64> {
+        self.metrics.sites.iter().map(|stats| stats.calls).collect()
+    }
+
+    /// Candidates generated per call site, accepted or not
+    #[wasm_bindgen(getter)]
+    pub fn site_attempts(&self) -> Vec<u64> {
+        self.metrics
+            .sites
+            .iter()
+            .map(|stats| stats.attempts)
+            .collect()
+    }
+
+    /// Candidates rejected per call site
+    #[wasm_bindgen(getter)]
+    pub fn site_discards(&self) -> Vec<u64> {
+        self.metrics
+            .sites
+            .iter()
+            .map(|stats| stats.discards)
+            .collect()
+    }
+
+    /// Fraction of candidates rejected per call site (0 when none were
+    /// generated); a ratio near 1 points at a filter whose range should be
+    /// narrowed instead
+    #[wasm_bindgen(getter)]
+    pub fn site_discard_ratios(&self) -> Vec<f64> {
+        self.metrics
+            .sites
+            .iter()
+            .map(SiteStats::discard_ratio)
+            .collect()
+    }
+
+    /// Filtered generations per call site that ran out of attempts
+    #[wasm_bindgen(getter)]
+    pub fn site_gave_up(&self) -> Vec<u64> {
+        self.metrics
+            .sites
+            .iter()
+            .map(|stats| stats.gave_up)
+            .collect()
+    }
+
+    /// Call sites whose discard ratio is at least `threshold`, worst first
+    pub fn wasteful_sites(&self, threshold: f64) -> Vec<String> {
+        let mut sites: Vec<&SiteStats> = self
+            .metrics
+            .sites
+            .iter()
+            .filter(|stats| stats.attempts > 0 && stats.discard_ratio() >= threshold)
+            .collect();
+        sites.sort_by(|a, b| b.discard_ratio().total_cmp(&a.discard_ratio()));
+        sites.into_iter().map(|stats| stats.site.clone()).collect()
+    }
+
+    /// Total boundary crossings through instrumented entry points
+    #[wasm_bindgen(getter)]
+    pub fn crossings(&self) -> u64 {
+        self.metrics
+            .crossings
+            .iter()
+            .map(|(_, calls, _)| calls)
+            .sum()
+    }
+}
@@ -0,0 +1,231 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::{write_header, HEADER_SIZE, MAX_DECIMAL_SCALE};
+use crate::{check_probability, to_unit_f64, DataFormat, Seed};
+
+/// ISO 4217 codes whose minor unit is not the usual two decimal places,
+/// plus the most common two-place currencies
+const CURRENCIES: [(&str, u32); 20] = [
+    ("USD", 2),
+    ("EUR", 2),
+    ("GBP", 2),
+    ("CHF", 2),
+    ("CAD", 2),
+    ("AUD", 2),
+    ("CNY", 2),
+    ("INR", 2),
+    ("JPY", 0),
+    ("KRW", 0),
+    ("VND", 0),
+    ("ISK", 0),
+    ("CLP", 0),
+    ("BHD", 3),
+    ("IQD", 3),
+    ("JOD", 3),
+    ("KWD", 3),
+    ("OMR", 3),
+    ("TND", 3),
+    ("CLF", 4),
+];
+
+/// Parameters for `fill_amounts`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct MoneyParams {
+    scale: u32,
+    denomination: u64,
+    min: u64,
+    max: u64,
+    log_uniform: bool,
+    negative_probability: f64,
+}
+
+impl Default for MoneyParams {
+    fn default() -> MoneyParams {
+        MoneyParams {
+            scale: 2,
+            denomination: 1,
+            min: 0,
+            max: 1_000_000,
+            log_uniform: true,
+            negative_probability: 0.0,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl MoneyParams {
+    /// Non-negative amounts in cents up to one million whole units, spread
+    /// log-uniformly across magnitudes
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> MoneyParams {
+        MoneyParams::default()
+    }
+
+    /// Parameters using the minor unit of an ISO 4217 currency code, e.g. 0
+    /// decimal places for JPY and 3 for KWD
+    pub fn for_currency(code: &str) -> Result<MoneyParams, Error> {
+        let upper = code.to_ascii_uppercase();
+        match CURRENCIES.iter().find(|(currency, _)| *currency == upper) {
+            Some(&(_, scale)) => Ok(MoneyParams::default().with_scale(scale)),
+            None => Err(Error::invalid_parameter(
+                "currency",
+                &format!("unknown code {code}"),
+            )),
+        }
+    }
+
+    /// Decimal places of the minor unit, as for `DecimalI64`
+    pub fn with_scale(mut self, scale: u32) -> MoneyParams {
+        self.scale = scale;
+        self
+    }
+
+    /// Smallest step in minor units; every amount is a multiple of it, e.g.
+    /// 5 for cash amounts rounded to 0.05
+    pub fn with_denomination(mut self, denomination: u64) -> MoneyParams {
+        self.denomination = denomination;
+        self
+    }
+
+    /// Inclusive range of magnitudes in whole units
+    pub fn with_range(mut self, min: u64, max: u64) -> MoneyParams {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Whether magnitudes are log-uniform, as real transaction amounts
+    /// roughly are, rather than uniform
+    pub fn with_log_uniform(mut self, log_uniform: bool) -> MoneyParams {
+        self.log_uniform = log_uniform;
+        self
+    }
+
+    /// Probability of each amount being negated, e.g. for refunds
+    pub fn with_negative_probability(mut self, p: f64) -> MoneyParams {
+        self.negative_probability = p;
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn denomination(&self) -> u64 {
+        self.denomination
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min(&self) -> u64 {
+        self.min
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn log_uniform(&self) -> bool {
+        self.log_uniform
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn negative_probability(&self) -> f64 {
+        self.negative_probability
+    }
+}
+
+impl MoneyParams {
+    /// Smallest and largest valid magnitudes in minor units
+    fn minor_range(&self) -> Result<(i64, i64), Error> {
+        if self.scale > MAX_DECIMAL_SCALE {
+            return Err(Error::invalid_parameter(
+                "scale",
+                &format!("{} exceeds {MAX_DECIMAL_SCALE}", self.scale),
+            ));
+        }
+        if self.denomination == 0 {
+            return Err(Error::invalid_parameter("denomination", "0"));
+        }
+        let factor = 10u64.pow(self.scale);
+        let range = self
+            .min
+            .checked_mul(factor)
+            .zip(self.max.checked_mul(factor))
+            .filter(|&(_, max)| max <= i64::MAX as u64);
+        let Some((min, max)) = range else {
+            return Err(Error::invalid_parameter(
+                "range",
+                &format!(
+                    "[{}, {}] overflows i64 at scale {}",
+                    self.min, self.max, self.scale
+                ),
+            ));
+        };
+        // Snap inward to the nearest multiples of the denomination
+        let first = min
+            .div_ceil(self.denomination)
+            .saturating_mul(self.denomination);
+        let last = max / self.denomination * self.denomination;
+        if first > last {
+            return Err(Error::invalid_parameter(
+                "range",
+                &format!(
+                    "[{}, {}] holds no multiple of {} at scale {}",
+                    self.min, self.max, self.denomination, self.scale
+                ),
+            ));
+        }
+        Ok((first as i64, last as i64))
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Fill a `DecimalI64` buffer with `count` monetary amounts in minor
+    /// units (see `fill_buffer`), for fintech property suites. Every amount
+    /// is a multiple of the denomination within the range, drawn so that
+    /// each order of magnitude is about equally likely when log-uniform.
+    #[wasm_bindgen]
+    pub fn fill_amounts(
+        &self,
+        buffer: &mut [u8],
+        count: u64,
+        params: &MoneyParams,
+    ) -> Result<Seed, Error> {
+        let (first, last) = params.minor_range()?;
+        let negative_probability =
+            check_probability("negative_probability", params.negative_probability)?;
+        write_header(buffer, DataFormat::DecimalI64, count)?;
+
+        let mut seed = self.clone();
+        let denomination = params.denomination as i64;
+        let steps = ((last - first) / denomination) as u64;
+        // Offsetting by one keeps a range starting at zero finite; doing it
+        // in floating point keeps a range ending at i64::MAX from overflowing
+        let (low, high) = ((first as f64 + 1.0).ln(), (last as f64 + 1.0).ln());
+        for i in 0..count as usize {
+            let amount = if params.log_uniform {
+                let x = (low + to_unit_f64(seed.step()) * (high - low)).exp() - 1.0;
+                let snapped = (x as i64 - first) / denomination * denomination + first;
+                snapped.clamp(first, last)
+            } else {
+                first + seed.step_below(steps + 1) as i64 * denomination
+            };
+            let amount =
+                if negative_probability > 0.0 && to_unit_f64(seed.step()) < negative_probability {
+                    -amount
+                } else {
+                    amount
+                };
+            let offset = HEADER_SIZE as usize + i * 8;
+            buffer[offset..offset + 8].copy_from_slice(&amount.to_le_bytes());
+        }
+        Ok(seed)
+    }
+}
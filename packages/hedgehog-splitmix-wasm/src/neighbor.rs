@@ -0,0 +1,185 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::{HEADER_SIZE, SURROGATE_COUNT, SURROGATE_START};
+use crate::{check_probability, to_unit_f64, DataFormat, Seed};
+
+/// Scalar values a `CharU32` element can hold, surrogates excluded
+const SCALAR_COUNT: i128 = 0x110000 - SURROGATE_COUNT as i128;
+
+/// Move `value` by `delta` within [lo, hi], going the other way when that
+/// leaves the range and clamping when both ways do
+fn nudge(value: i128, lo: i128, hi: i128, delta: i128) -> i128 {
+    let moved = value + delta;
+    if (lo..=hi).contains(&moved) {
+        moved
+    } else {
+        (value - delta).clamp(lo, hi)
+    }
+}
+
+/// `nudge` for u128, which does not fit the signed arithmetic
+fn nudge_u128(value: u128, delta: i128) -> u128 {
+    let magnitude = delta.unsigned_abs();
+    let up = delta > 0;
+    let (forward, backward) = if up {
+        (value.checked_add(magnitude), value.checked_sub(magnitude))
+    } else {
+        (value.checked_sub(magnitude), value.checked_add(magnitude))
+    };
+    forward
+        .or(backward)
+        .unwrap_or(if up { u128::MAX } else { 0 })
+}
+
+impl Seed {
+    /// Draw an integer step of 1 to `epsilon` (at least 1) either way
+    fn step_delta(&mut self, epsilon: f64) -> i128 {
+        let magnitude = (self.step_below((epsilon as u64).max(1)) + 1) as i128;
+        if self.step() & 1 == 1 {
+            magnitude
+        } else {
+            -magnitude
+        }
+    }
+
+    /// Perturb one little-endian element in place, drawing from `self`
+    fn step_neighbor(&mut self, format: DataFormat, element: &mut [u8], epsilon: f64) {
+        let mut bytes = [0; 16];
+        bytes[..element.len()].copy_from_slice(element);
+        let unsigned = u128::from_le_bytes(bytes);
+        match format {
+            DataFormat::BoolU8 => element[0] = u8::from(element[0] == 0),
+            DataFormat::U8 | DataFormat::U16LE | DataFormat::U32LE | DataFormat::U64LE => {
+                let hi = (1i128 << (element.len() * 8)) - 1;
+                let moved = nudge(unsigned as i128, 0, hi, self.step_delta(epsilon)) as u128;
+                element.copy_from_slice(&moved.to_le_bytes()[..element.len()]);
+            }
+            DataFormat::I32LE => {
+                let value = i32::from_le_bytes([element[0], element[1], element[2], element[3]]);
+                let moved = nudge(
+                    value as i128,
+                    i32::MIN as i128,
+                    i32::MAX as i128,
+                    self.step_delta(epsilon),
+                );
+                element.copy_from_slice(&(moved as i32).to_le_bytes());
+            }
+            DataFormat::I64LE | DataFormat::DecimalI64 => {
+                let value = unsigned as u64 as i64;
+                let moved = nudge(
+                    value as i128,
+                    i64::MIN as i128,
+                    i64::MAX as i128,
+                    self.step_delta(epsilon),
+                );
+                element.copy_from_slice(&(moved as i64).to_le_bytes());
+            }
+            DataFormat::U128LE => {
+                let moved = nudge_u128(unsigned, self.step_delta(epsilon));
+                element.copy_from_slice(&moved.to_le_bytes());
+            }
+            DataFormat::CharU32 => {
+                // Move through the scalar values, stepping over surrogates
+                let value = (unsigned as u32).min(0x10ffff);
+                let index = if value < SURROGATE_START {
+                    value
+                } else {
+                    value.saturating_sub(SURROGATE_COUNT).max(SURROGATE_START)
+                };
+                let moved =
+                    nudge(index as i128, 0, SCALAR_COUNT - 1, self.step_delta(epsilon)) as u32;
+                let moved = if moved < SURROGATE_START {
+                    moved
+                } else {
+                    moved + SURROGATE_COUNT
+                };
+                element.copy_from_slice(&moved.to_le_bytes());
+            }
+            DataFormat::F32LE => {
+                let value = f32::from_le_bytes([element[0], element[1], element[2], element[3]]);
+                let shift = (2.0 * to_unit_f64(self.step()) - 1.0) * epsilon;
+                element.copy_from_slice(&((value as f64 + shift) as f32).to_le_bytes());
+            }
+            DataFormat::F64LE => {
+                let value = f64::from_bits(unsigned as u64);
+                let shift = (2.0 * to_unit_f64(self.step()) - 1.0) * epsilon;
+                element.copy_from_slice(&(value + shift).to_le_bytes());
+            }
+            // Big-endian and packed layouts are converted before this point
+            _ => {}
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Copy `source`, a filled buffer of any format, into `buffer` with each
+    /// element changed with probability `probability`, for metamorphic
+    /// properties comparing f(x) with f(x ± ε). Integers move by 1 to
+    /// `epsilon` (at least 1) staying inside their type, floats by up to
+    /// `epsilon` either way, and booleans flip; `CharU32` elements step over
+    /// the surrogate block. Only the header and values are copied, so a null
+    /// bitmap after them is left out. Framed strings and blobs have no
+    /// fixed-width elements to perturb and are rejected.
+    #[wasm_bindgen]
+    pub fn fill_neighbor(
+        &self,
+        source: &[u8],
+        buffer: &mut [u8],
+        probability: f64,
+        epsilon: f64,
+    ) -> Result<Seed, Error> {
+        let probability = check_probability("probability", probability)?;
+        if !(epsilon.is_finite() && epsilon >= 0.0) {
+            return Err(Error::invalid_parameter("epsilon", &epsilon.to_string()));
+        }
+        if (source.len() as u64) < HEADER_SIZE {
+            return Err(Error::buffer_too_small(HEADER_SIZE, source.len()));
+        }
+        let format = DataFormat::fixed_width(source[0])?;
+        let mut count = [0; 8];
+        count.copy_from_slice(&source[1..9]);
+        let count = u64::from_le_bytes(count);
+        // Every element takes at least a bit, which also keeps the size
+        // below from overflowing
+        if count / 8 > source.len() as u64 {
+            return Err(Error::buffer_too_small(count / 8, source.len()));
+        }
+        let required = HEADER_SIZE + format.data_size(count);
+        if (source.len() as u64) < required {
+            return Err(Error::buffer_too_small(required, source.len()));
+        }
+        if (buffer.len() as u64) < required {
+            return Err(Error::buffer_too_small(required, buffer.len()));
+        }
+
+        let required = required as usize;
+        buffer[..required].copy_from_slice(&source[..required]);
+        let data = &mut buffer[HEADER_SIZE as usize..required];
+        let mut seed = self.clone();
+        if format == DataFormat::BoolPacked {
+            for i in 0..count as usize {
+                if to_unit_f64(seed.step()) < probability {
+                    data[i / 8] ^= 1 << (i % 8);
+                }
+            }
+            return Ok(seed);
+        }
+
+        let (layout, big_endian) = format.byte_order();
+        for element in data.chunks_exact_mut(format.bytes_per_element() as usize) {
+            if to_unit_f64(seed.step()) >= probability {
+                continue;
+            }
+            if big_endian {
+                element.reverse();
+            }
+            seed.step_neighbor(layout, element, epsilon);
+            if big_endian {
+                element.reverse();
+            }
+        }
+        Ok(seed)
+    }
+}
@@ -0,0 +1,159 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::{to_unit_f64, Seed};
+
+/// Sequences available to float buffer formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[wasm_bindgen]
+pub enum Sequence {
+    /// Independent SplitMix64 draws
+    #[default]
+    PseudoRandom = 0,
+    /// Halton low-discrepancy sequence (radical inverses in prime bases)
+    Halton = 1,
+    /// Sobol low-discrepancy sequence with Joe-Kuo direction numbers
+    Sobol = 2,
+    /// The unit interval split into one bin per element, with one jittered
+    /// sample per bin, emitted in bin order
+    Stratified = 3,
+}
+
+/// Bases for the Halton sequence, one per dimension
+const HALTON_BASES: [u64; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// Joe-Kuo (new-joe-kuo-6.21201) parameters `(s, a, m)` for Sobol
+/// dimensions 2 to 8; dimension 1 is the van der Corput sequence
+const SOBOL_PARAMS: [(u32, u32, &[u32]); 7] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+];
+
+/// Bits of precision in each Sobol coordinate
+const SOBOL_BITS: usize = 32;
+
+/// Sobol direction numbers for `dimension` (0-based), scaled to 32 bits
+fn sobol_directions(dimension: usize) -> [u32; SOBOL_BITS] {
+    let mut v = [0u32; SOBOL_BITS];
+    if dimension == 0 {
+        for (j, direction) in v.iter_mut().enumerate() {
+            *direction = 1 << (31 - j);
+        }
+        return v;
+    }
+
+    let (s, a, m) = SOBOL_PARAMS[dimension - 1];
+    let s = s as usize;
+    for j in 0..SOBOL_BITS {
+        v[j] = if j < s {
+            m[j] << (31 - j)
+        } else {
+            let mut direction = v[j - s] ^ (v[j - s] >> s);
+            for k in 1..s {
+                if (a >> (s - 1 - k)) & 1 == 1 {
+                    direction ^= v[j - k];
+                }
+            }
+            direction
+        };
+    }
+    v
+}
+
+/// Radical inverse of `index` in `base`, in [0, 1)
+fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    let inverse_base = 1.0 / base as f64;
+    let mut scale = inverse_base;
+    let mut result = 0.0;
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inverse_base;
+    }
+    result
+}
+
+/// Randomized low-discrepancy points in [0, 1)^dimensions. Each dimension
+/// is scrambled with a shift drawn from the seed (a digital XOR shift for
+/// Sobol, a rotation modulo 1 for Halton) so different seeds give different
+/// but equally well-spread points.
+pub(crate) struct QuasiSampler {
+    kind: Kind,
+    dimensions: usize,
+    start: u64,
+    shifts: Vec<u64>,
+}
+
+enum Kind {
+    Halton,
+    /// Direction numbers per dimension
+    Sobol(Vec<[u32; SOBOL_BITS]>),
+}
+
+impl QuasiSampler {
+    /// Prepare a sampler for `sequence`, drawing one shift per dimension
+    /// from `seed`; `None` for pseudo-random and stratified sampling, which
+    /// draw per element instead
+    pub(crate) fn new(
+        seed: &mut Seed,
+        sequence: Sequence,
+        dimensions: u32,
+        start: u64,
+    ) -> Result<Option<QuasiSampler>, Error> {
+        let dimensions = dimensions as usize;
+        let max_dimensions = match sequence {
+            Sequence::PseudoRandom | Sequence::Stratified => return Ok(None),
+            Sequence::Halton => HALTON_BASES.len(),
+            Sequence::Sobol => SOBOL_PARAMS.len() + 1,
+        };
+        if dimensions == 0 || dimensions > max_dimensions {
+            return Err(Error::invalid_parameter(
+                "dimensions",
+                &format!("{dimensions} for {sequence:?} (1 to {max_dimensions})"),
+            ));
+        }
+
+        let kind = match sequence {
+            Sequence::Sobol => Kind::Sobol((0..dimensions).map(sobol_directions).collect()),
+            _ => Kind::Halton,
+        };
+        Ok(Some(QuasiSampler {
+            kind,
+            dimensions,
+            start,
+            shifts: (0..dimensions).map(|_| seed.step()).collect(),
+        }))
+    }
+
+    /// Coordinate for buffer element `element`: points are laid out one
+    /// after another, `dimensions` coordinates each
+    pub(crate) fn sample(&self, element: usize) -> f64 {
+        let index = self.start + (element / self.dimensions) as u64;
+        let dimension = element % self.dimensions;
+        let shift = self.shifts[dimension];
+        match &self.kind {
+            Kind::Sobol(directions) => {
+                let mut bits = (shift >> 32) as u32;
+                for (j, &direction) in directions[dimension].iter().enumerate() {
+                    if (index >> j) & 1 == 1 {
+                        bits ^= direction;
+                    }
+                }
+                bits as f64 / (1u64 << 32) as f64
+            }
+            Kind::Halton => {
+                let value = radical_inverse(index, HALTON_BASES[dimension]) + to_unit_f64(shift);
+                if value >= 1.0 {
+                    value - 1.0
+                } else {
+                    value
+                }
+            }
+        }
+    }
+}
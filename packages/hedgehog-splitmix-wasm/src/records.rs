@@ -0,0 +1,97 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::HEADER_SIZE;
+use crate::{DataFormat, FillParams, Seed};
+
+/// Formats of a record schema, and the bytes one record takes
+fn parse_schema(schema: &[u8]) -> Result<(Vec<DataFormat>, u64), Error> {
+    if schema.is_empty() || schema.len() > u8::MAX as usize {
+        return Err(Error::invalid_parameter(
+            "schema",
+            &format!("{} fields, expected 1 to {}", schema.len(), u8::MAX),
+        ));
+    }
+    let mut formats = Vec::with_capacity(schema.len());
+    let mut record_size = 0;
+    for &code in schema {
+        let format = DataFormat::fixed_width(code)?;
+        // Packed booleans share bytes between elements, so cannot be
+        // interleaved
+        if format == DataFormat::BoolPacked {
+            return Err(Error::invalid_parameter(
+                "schema",
+                &format!("format {code} cannot be a record field"),
+            ));
+        }
+        record_size += format.bytes_per_element();
+        formats.push(format);
+    }
+    Ok((formats, record_size))
+}
+
+/// Buffer size needed by `fill_records` for `count` records of `schema`,
+/// header included
+#[wasm_bindgen]
+pub fn records_buffer_size(schema: &[u8], count: u64) -> Result<u64, Error> {
+    let (_, record_size) = parse_schema(schema)?;
+    let data_size = count
+        .checked_mul(record_size)
+        .ok_or_else(|| Error::invalid_parameter("count", &count.to_string()))?;
+    Ok(1 + schema.len() as u64 + 8 + data_size)
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Fill a buffer with `count` interleaved records whose fields have the
+    /// formats listed in `schema`, e.g. `[U32LE, F64LE, BoolU8]`, so object
+    /// generators cross the boundary once for a whole batch.
+    /// Buffer layout: [1 byte field count][1 byte format per field]
+    /// [8 bytes count][records...], each record packed field after field.
+    /// Fields are generated a column at a time with `params` exactly as
+    /// `fill_buffer_with_params` would, so field `j` of every record matches
+    /// a plain fill from the seed left by the fields before it.
+    #[wasm_bindgen]
+    pub fn fill_records(
+        &self,
+        buffer: &mut [u8],
+        schema: &[u8],
+        count: u64,
+        params: &FillParams,
+    ) -> Result<Seed, Error> {
+        let (formats, record_size) = parse_schema(schema)?;
+        if params.null_probability().is_some() {
+            return Err(Error::invalid_parameter(
+                "null_probability",
+                "not supported for records",
+            ));
+        }
+        let required = records_buffer_size(schema, count)?;
+        if (buffer.len() as u64) < required {
+            return Err(Error::buffer_too_small(required, buffer.len()));
+        }
+
+        let fields_end = 1 + schema.len();
+        buffer[0] = schema.len() as u8;
+        buffer[1..fields_end].copy_from_slice(schema);
+        buffer[fields_end..fields_end + 8].copy_from_slice(&count.to_le_bytes());
+        let data_start = fields_end + 8;
+
+        let mut seed = self.clone();
+        let mut column = Vec::new();
+        let mut field_offset = 0;
+        for (format, &code) in formats.iter().zip(schema) {
+            let width = format.bytes_per_element() as usize;
+            column.resize((HEADER_SIZE + format.data_size(count)) as usize, 0);
+            seed = seed.fill_buffer_with_params(&mut column, code, count, params)?;
+            let values = column[HEADER_SIZE as usize..].chunks_exact(width);
+            let records =
+                buffer[data_start..required as usize].chunks_exact_mut(record_size as usize);
+            for (record, value) in records.zip(values) {
+                record[field_offset..field_offset + width].copy_from_slice(value);
+            }
+            field_offset += width;
+        }
+        Ok(seed)
+    }
+}
@@ -0,0 +1,84 @@
+use wasm_bindgen::prelude::*;
+
+use crate::encoding::{fnv1a, from_base64url, read_u8, read_varint, to_base64url, write_varint};
+use crate::error::Error;
+use crate::Seed;
+
+/// Prefix identifying repro tokens and their version
+const REPRO_PREFIX: &str = "hh1.";
+
+/// Everything needed to re-run a single failing case
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct Repro {
+    seed: Seed,
+    size: u32,
+    config_hash: u64,
+}
+
+#[wasm_bindgen]
+impl Repro {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn config_hash(&self) -> u64 {
+        self.config_hash
+    }
+}
+
+/// Encode a seed, size and configuration hash as a single short token,
+/// e.g. for printing "re-run with HEDGEHOG_REPRO=hh1.…"
+///
+/// Token layout: `hh1.` followed by base64url of
+/// `[varint state][varint gamma][varint size][varint config_hash][1 byte checksum]`
+#[wasm_bindgen]
+pub fn make_repro(seed: &Seed, size: u32, config_hash: u64) -> String {
+    let mut payload = Vec::with_capacity(32);
+    write_varint(&mut payload, seed.state);
+    write_varint(&mut payload, seed.gamma);
+    write_varint(&mut payload, size as u64);
+    write_varint(&mut payload, config_hash);
+    payload.push(checksum(&payload));
+    format!("{REPRO_PREFIX}{}", to_base64url(&payload))
+}
+
+/// Decode a token produced by `make_repro`
+#[wasm_bindgen]
+pub fn parse_repro(token: &str) -> Result<Repro, Error> {
+    let encoded = token
+        .trim()
+        .strip_prefix(REPRO_PREFIX)
+        .ok_or_else(|| Error::invalid_encoding("repro token must start with hh1."))?;
+    let payload = from_base64url(encoded)?;
+
+    let mut position = 0;
+    let state = read_varint(&payload, &mut position)?;
+    let gamma = read_varint(&payload, &mut position)?;
+    let size = read_varint(&payload, &mut position)?;
+    let config_hash = read_varint(&payload, &mut position)?;
+    let expected = checksum(&payload[..position]);
+    if read_u8(&payload, &mut position)? != expected || position != payload.len() {
+        return Err(Error::invalid_encoding("repro token checksum mismatch"));
+    }
+
+    let size =
+        u32::try_from(size).map_err(|_| Error::invalid_encoding("repro size out of range"))?;
+    Ok(Repro {
+        seed: Seed::from_parts(state, gamma),
+        size,
+        config_hash,
+    })
+}
+
+fn checksum(payload: &[u8]) -> u8 {
+    let hash = fnv1a(payload);
+    (hash ^ hash >> 32 ^ hash >> 16 ^ hash >> 8) as u8
+}
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::metrics;
+use crate::Seed;
+
+/// Site under which discards of constraints without `with_site` are reported
+const UNNAMED_SITE: &str = "(unnamed)";
+
+/// Constraint on generated values that can be checked without calling back
+/// into JavaScript
+#[derive(Debug, Clone)]
+enum Check {
+    /// `value & mask == expected`
+    Mask { mask: u64, expected: u64 },
+    /// Value is one of the set
+    OneOf(HashSet<u64>),
+    /// Value is none of the set
+    NoneOf(HashSet<u64>),
+}
+
+/// A Rust-checkable predicate for `next_until`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct Constraint {
+    check: Check,
+    /// Call site name for discard statistics
+    site: Option<String>,
+}
+
+#[wasm_bindgen]
+impl Constraint {
+    /// Accept values whose bits under `mask` equal `expected`, e.g. a mask
+    /// of 1 and expected 0 for even numbers
+    pub fn mask(mask: u64, expected: u64) -> Constraint {
+        Constraint {
+            check: Check::Mask { mask, expected },
+            site: None,
+        }
+    }
+
+    /// Accept only values in `values`
+    pub fn one_of(values: Vec<u64>) -> Constraint {
+        Constraint {
+            check: Check::OneOf(values.into_iter().collect()),
+            site: None,
+        }
+    }
+
+    /// Reject values in `values`
+    pub fn none_of(values: Vec<u64>) -> Constraint {
+        Constraint {
+            check: Check::NoneOf(values.into_iter().collect()),
+            site: None,
+        }
+    }
+
+    /// Name the generator using this constraint, e.g. `"evenPort"`, so that
+    /// metrics reports its discards separately from other call sites
+    pub fn with_site(mut self, site: &str) -> Constraint {
+        self.site = Some(site.to_string());
+        self
+    }
+
+    /// Call site name given by `with_site`
+    #[wasm_bindgen(getter)]
+    pub fn site(&self) -> Option<String> {
+        self.site.clone()
+    }
+
+    /// Whether `value` satisfies the constraint
+    pub fn accepts(&self, value: u64) -> bool {
+        match &self.check {
+            Check::Mask { mask, expected } => value & mask == *expected,
+            Check::OneOf(values) => values.contains(&value),
+            Check::NoneOf(values) => !values.contains(&value),
+        }
+    }
+}
+
+/// Outcome of `next_until`: either a value that satisfied the constraint or
+/// a "gave up" result once the attempt budget ran out
+#[wasm_bindgen]
+pub struct RetryResult {
+    seed: Seed,
+    value: Option<u64>,
+    attempts: u32,
+}
+
+#[wasm_bindgen]
+impl RetryResult {
+    #[wasm_bindgen(getter)]
+    pub fn seed(&self) -> Seed {
+        self.seed.clone()
+    }
+
+    /// Accepted value, or `undefined` if generation gave up
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> Option<u64> {
+        self.value
+    }
+
+    /// Number of values generated, including the accepted one
+    #[wasm_bindgen(getter)]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Whether every attempt was rejected
+    #[wasm_bindgen(getter)]
+    pub fn gave_up(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Number of rejected values, to report as discards
+    #[wasm_bindgen(getter)]
+    pub fn discards(&self) -> u32 {
+        self.attempts - self.value.map_or(0, |_| 1)
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Generate values in [0, bound) until one satisfies `constraint`,
+    /// making at most `max_attempts` attempts. In metrics mode the outcome
+    /// is added to the discard statistics of the constraint's site.
+    #[wasm_bindgen]
+    pub fn next_until(
+        &self,
+        bound: u64,
+        constraint: &Constraint,
+        max_attempts: u32,
+    ) -> Result<RetryResult, Error> {
+        if bound == 0 {
+            return Err(Error::invalid_parameter("bound", "0"));
+        }
+
+        let mut seed = self.clone();
+        let mut outcome = RetryResult {
+            seed: seed.clone(),
+            value: None,
+            attempts: max_attempts,
+        };
+        for attempt in 1..=max_attempts {
+            let result = seed.next_bounded(bound);
+            seed = result.seed;
+            if constraint.accepts(result.value) {
+                outcome.value = Some(result.value);
+                outcome.attempts = attempt;
+                break;
+            }
+        }
+        outcome.seed = seed;
+
+        let site = constraint.site.as_deref().unwrap_or(UNNAMED_SITE);
+        metrics::record_discards(
+            site,
+            outcome.attempts as u64,
+            outcome.discards() as u64,
+            outcome.gave_up(),
+        );
+        Ok(outcome)
+    }
+}
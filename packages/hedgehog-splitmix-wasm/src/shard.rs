@@ -0,0 +1,72 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::Seed;
+
+/// The test cases assigned to one worker, with the seed for each case
+#[wasm_bindgen]
+pub struct Shard {
+    indices: Vec<u32>,
+    seeds: Vec<Seed>,
+}
+
+#[wasm_bindgen]
+impl Shard {
+    /// Test-case indices assigned to this worker, in ascending order
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u32> {
+        self.indices.clone()
+    }
+
+    /// Number of test cases assigned to this worker
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Seed for the `position`-th assigned case (not the case index)
+    pub fn seed(&self, position: usize) -> Option<Seed> {
+        self.seeds.get(position).cloned()
+    }
+}
+
+/// Assign test cases `0..test_limit` round-robin to worker `worker_index`
+/// of `worker_count` and derive each case's seed. Seeds match the
+/// sequential runner, which first splits once for each of the property's
+/// `examples` and then gives case `i` the left seed of the next `i`-th
+/// split along the right-hand chain, so shards never overlap and every
+/// sampled case reproduces exactly as in a single-worker run.
+#[wasm_bindgen]
+pub fn shard(
+    seed: &Seed,
+    examples: u32,
+    test_limit: u32,
+    worker_index: u32,
+    worker_count: u32,
+) -> Result<Shard, Error> {
+    if worker_count == 0 {
+        return Err(Error::invalid_parameter("worker_count", "0"));
+    }
+    if worker_index >= worker_count {
+        return Err(Error::invalid_parameter(
+            "worker_index",
+            &format!("{worker_index} for {worker_count} workers"),
+        ));
+    }
+
+    let mut current = seed.clone();
+    for _ in 0..examples {
+        current = current.split().right;
+    }
+    let mut indices = Vec::new();
+    let mut seeds = Vec::new();
+    for index in 0..test_limit {
+        let pair = current.split();
+        if index % worker_count == worker_index {
+            indices.push(index);
+            seeds.push(pair.left);
+        }
+        current = pair.right;
+    }
+    Ok(Shard { indices, seeds })
+}
@@ -0,0 +1,108 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::{check_probability, to_unit_f64, FillParams, Seed};
+
+/// Most entries one sparse fill will produce (128MB of indices)
+const MAX_SPARSE_ENTRIES: usize = 1 << 24;
+
+/// Sparse vector produced by `fill_sparse`: ascending indices into a
+/// logical length, with one value per index
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct SparseFill {
+    length: u64,
+    indices: Vec<u64>,
+    values: Vec<u8>,
+    final_seed: Seed,
+}
+
+#[wasm_bindgen]
+impl SparseFill {
+    /// Logical length of the dense vector
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Positions of the present entries, in ascending order
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u64> {
+        self.indices.clone()
+    }
+
+    /// Values of the present entries as a `fill_buffer` buffer whose count
+    /// is the number of entries, so the usual format readers apply
+    #[wasm_bindgen(getter)]
+    pub fn values(&self) -> Vec<u8> {
+        self.values.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn final_seed(&self) -> Seed {
+        self.final_seed.clone()
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Generate a sparse vector over `length` positions, each present with
+    /// probability `density`, as `(index, value)` pairs. Indices are drawn
+    /// by geometric skipping, so the cost follows the number of entries
+    /// rather than the length, and values are then filled in `format_u8`
+    /// with `params` exactly as `fill_buffer_with_params` would.
+    #[wasm_bindgen]
+    pub fn fill_sparse(
+        &self,
+        length: u64,
+        density: f64,
+        format_u8: u8,
+        params: &FillParams,
+    ) -> Result<SparseFill, Error> {
+        let density = check_probability("density", density)?;
+        if length as f64 * density > MAX_SPARSE_ENTRIES as f64 {
+            return Err(Error::invalid_parameter(
+                "density",
+                &format!("{density} over {length} positions exceeds {MAX_SPARSE_ENTRIES} entries"),
+            ));
+        }
+
+        let mut seed = self.clone();
+        let mut indices = Vec::new();
+        let mut next: u64 = 0;
+        // ln(1 - density) scales a uniform draw into a geometric skip;
+        // ln_1p keeps it accurate for tiny densities, where 1 - density
+        // would round to 1
+        let log_miss = (-density).ln_1p();
+        while density > 0.0 && next < length {
+            let skip = if density >= 1.0 {
+                0.0
+            } else {
+                // 1 - u lies in (0, 1], keeping the logarithm finite
+                ((1.0 - to_unit_f64(seed.step())).ln() / log_miss).floor()
+            };
+            if skip >= (length - next) as f64 {
+                break;
+            }
+            if indices.len() == MAX_SPARSE_ENTRIES {
+                return Err(Error::invalid_parameter(
+                    "density",
+                    &format!("drew more than {MAX_SPARSE_ENTRIES} entries"),
+                ));
+            }
+            let index = next + skip as u64;
+            indices.push(index);
+            next = index + 1;
+        }
+
+        let count = indices.len() as u64;
+        let mut values = vec![0; params.buffer_size(format_u8, count)? as usize];
+        let final_seed = seed.fill_buffer_with_params(&mut values, format_u8, count, params)?;
+        Ok(SparseFill {
+            length,
+            indices,
+            values,
+            final_seed,
+        })
+    }
+}
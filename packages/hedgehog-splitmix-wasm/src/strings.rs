@@ -0,0 +1,218 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::{HEADER_SIZE, SURROGATE_COUNT, SURROGATE_START};
+use crate::{DataFormat, Seed};
+
+/// Bytes of the length prefix before each string or blob
+const LENGTH_PREFIX: u64 = 4;
+
+/// Bytes recording the alphabet after the `fill_strings` header
+const ALPHABET_SIZE: u64 = 1;
+
+/// Characters drawn by `fill_strings`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[wasm_bindgen]
+pub enum StringAlphabet {
+    /// Printable ASCII, space to tilde
+    #[default]
+    PrintableAscii = 0,
+    /// All of ASCII, control characters included
+    Ascii = 1,
+    /// Every Unicode scalar value, uniformly
+    Unicode = 2,
+}
+
+impl StringAlphabet {
+    /// First character and number of characters, surrogates excluded
+    fn span(self) -> (u32, u32) {
+        match self {
+            StringAlphabet::PrintableAscii => (0x20, 0x5f),
+            StringAlphabet::Ascii => (0, 0x80),
+            StringAlphabet::Unicode => (0, 0x110000 - SURROGATE_COUNT),
+        }
+    }
+
+    /// Most UTF-8 bytes one character takes
+    fn max_char_bytes(self) -> u64 {
+        match self {
+            StringAlphabet::Unicode => 4,
+            _ => 1,
+        }
+    }
+}
+
+/// Parameters for `fill_strings`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct StringParams {
+    alphabet: StringAlphabet,
+    min_length: u32,
+    max_length: u32,
+}
+
+impl Default for StringParams {
+    fn default() -> StringParams {
+        StringParams {
+            alphabet: StringAlphabet::PrintableAscii,
+            min_length: 0,
+            max_length: 16,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl StringParams {
+    /// Printable ASCII strings of 0 to 16 characters
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> StringParams {
+        StringParams::default()
+    }
+
+    pub fn with_alphabet(mut self, alphabet: StringAlphabet) -> StringParams {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Inclusive range of string lengths, in characters
+    pub fn with_length(mut self, min_length: u32, max_length: u32) -> StringParams {
+        self.min_length = min_length;
+        self.max_length = max_length;
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn alphabet(&self) -> StringAlphabet {
+        self.alphabet
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min_length(&self) -> u32 {
+        self.min_length
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max_length(&self) -> u32 {
+        self.max_length
+    }
+
+    /// Buffer size `fill_strings` needs for `count` strings: enough for
+    /// every string to reach the maximum length in the widest characters
+    pub fn buffer_size(&self, count: u64) -> Result<u64, Error> {
+        let per_string = LENGTH_PREFIX + self.max_length as u64 * self.alphabet.max_char_bytes();
+        count
+            .checked_mul(per_string)
+            .and_then(|size| size.checked_add(HEADER_SIZE + ALPHABET_SIZE))
+            .ok_or_else(|| Error::invalid_parameter("count", &count.to_string()))
+    }
+}
+
+/// Buffer size `fill_blobs` needs for `count` blobs: enough for every blob
+/// to reach `max_length` bytes
+#[wasm_bindgen]
+pub fn blobs_buffer_size(count: u64, max_length: u32) -> Result<u64, Error> {
+    count
+        .checked_mul(LENGTH_PREFIX + max_length as u64)
+        .and_then(|size| size.checked_add(HEADER_SIZE))
+        .ok_or_else(|| Error::invalid_parameter("count", &count.to_string()))
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Fill a buffer with `count` random byte blobs, each of `min_length` to
+    /// `max_length` bytes, for fuzz-style inputs and `Uint8Array`
+    /// generators. The buffer must hold `blobs_buffer_size(count,
+    /// max_length)` bytes.
+    /// Buffer layout: [1 byte format FramedBlobs][8 bytes count]
+    /// [4 bytes LE length, bytes]... per blob
+    #[wasm_bindgen]
+    pub fn fill_blobs(
+        &self,
+        buffer: &mut [u8],
+        count: u64,
+        min_length: u32,
+        max_length: u32,
+    ) -> Result<Seed, Error> {
+        if min_length > max_length {
+            return Err(Error::invalid_parameter(
+                "length",
+                &format!("[{min_length}, {max_length}]"),
+            ));
+        }
+        let required = blobs_buffer_size(count, max_length)?;
+        if (buffer.len() as u64) < required {
+            return Err(Error::buffer_too_small(required, buffer.len()));
+        }
+
+        buffer[0] = DataFormat::FramedBlobs as u8;
+        buffer[1..9].copy_from_slice(&count.to_le_bytes());
+
+        let mut seed = self.clone();
+        let lengths = (max_length - min_length) as u64 + 1;
+        let mut offset = HEADER_SIZE as usize;
+        for _ in 0..count {
+            let length = min_length + seed.step_below(lengths) as u32;
+            let start = offset + LENGTH_PREFIX as usize;
+            buffer[offset..start].copy_from_slice(&length.to_le_bytes());
+            offset = start + length as usize;
+            seed.step_fill(&mut buffer[start..offset]);
+        }
+        Ok(seed)
+    }
+
+    /// Fill a buffer with `count` random strings, so string-heavy generators
+    /// cross the boundary once per batch. Each string's length in characters
+    /// is drawn from the range in `params`, then each character uniformly
+    /// from its alphabet. The buffer must hold `params.buffer_size(count)`
+    /// bytes; the strings themselves usually take less.
+    /// Buffer layout: [1 byte format FramedStrings][8 bytes count]
+    /// [1 byte alphabet][4 bytes LE byte length, UTF-8 bytes]... per string
+    #[wasm_bindgen]
+    pub fn fill_strings(
+        &self,
+        buffer: &mut [u8],
+        count: u64,
+        params: &StringParams,
+    ) -> Result<Seed, Error> {
+        if params.min_length > params.max_length {
+            return Err(Error::invalid_parameter(
+                "length",
+                &format!("[{}, {}]", params.min_length, params.max_length),
+            ));
+        }
+        let required = params.buffer_size(count)?;
+        if (buffer.len() as u64) < required {
+            return Err(Error::buffer_too_small(required, buffer.len()));
+        }
+
+        buffer[0] = DataFormat::FramedStrings as u8;
+        buffer[1..9].copy_from_slice(&count.to_le_bytes());
+        buffer[HEADER_SIZE as usize] = params.alphabet as u8;
+
+        let mut seed = self.clone();
+        let (first, size) = params.alphabet.span();
+        let lengths = (params.max_length - params.min_length) as u64 + 1;
+        let mut offset = (HEADER_SIZE + ALPHABET_SIZE) as usize;
+        for _ in 0..count {
+            let length = params.min_length as u64 + seed.step_below(lengths);
+            let start = offset + LENGTH_PREFIX as usize;
+            let mut end = start;
+            for _ in 0..length {
+                // Draw an index over the alphabet and step over the
+                // surrogate block, so no draw is rejected
+                let index = first + ((seed.step() as u128 * size as u128) >> 64) as u32;
+                let scalar = if index < SURROGATE_START {
+                    index
+                } else {
+                    index + SURROGATE_COUNT
+                };
+                let c = char::from_u32(scalar).unwrap_or(char::REPLACEMENT_CHARACTER);
+                end += c.encode_utf8(&mut buffer[end..]).len();
+            }
+            let bytes = (end - start) as u32;
+            buffer[offset..start].copy_from_slice(&bytes.to_le_bytes());
+            offset = end;
+        }
+        Ok(seed)
+    }
+}
@@ -0,0 +1,188 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::HEADER_SIZE;
+use crate::{DataFormat, FillParams, Seed};
+
+/// Matrix structure applied by `fill_tensor`
+#[derive(Debug, Clone, Default)]
+#[wasm_bindgen]
+pub struct TensorParams {
+    symmetric: bool,
+    diagonally_dominant: bool,
+}
+
+#[wasm_bindgen]
+impl TensorParams {
+    /// Unstructured tensors
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> TensorParams {
+        TensorParams::default()
+    }
+
+    /// Mirror the upper triangle of a square matrix onto the lower one
+    pub fn with_symmetric(mut self, symmetric: bool) -> TensorParams {
+        self.symmetric = symmetric;
+        self
+    }
+
+    /// Replace each diagonal element of a square float matrix with a
+    /// positive value exceeding the sum of magnitudes of its row
+    pub fn with_diagonally_dominant(mut self, diagonally_dominant: bool) -> TensorParams {
+        self.diagonally_dominant = diagonally_dominant;
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn symmetric(&self) -> bool {
+        self.symmetric
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn diagonally_dominant(&self) -> bool {
+        self.diagonally_dominant
+    }
+}
+
+/// Element count of `shape`, checking that it is a valid tensor shape
+fn element_count(shape: &[u32]) -> Result<u64, Error> {
+    if shape.is_empty() || shape.len() > u8::MAX as usize {
+        return Err(Error::invalid_parameter(
+            "shape",
+            &format!("{} dimensions, expected 1 to {}", shape.len(), u8::MAX),
+        ));
+    }
+    shape
+        .iter()
+        .try_fold(1u64, |count, &dimension| {
+            count.checked_mul(dimension as u64)
+        })
+        .ok_or_else(|| Error::invalid_parameter("shape", &format!("{shape:?} overflows")))
+}
+
+/// Size of the tensor header for a shape of `rank` dimensions
+fn header_size(rank: usize) -> u64 {
+    2 + 4 * rank as u64
+}
+
+/// Buffer size needed by `fill_tensor` for a tensor of `shape`, header
+/// included
+#[wasm_bindgen]
+pub fn tensor_buffer_size(format_u8: u8, shape: &[u32]) -> Result<u64, Error> {
+    let format = DataFormat::fixed_width(format_u8)?;
+    let count = element_count(shape)?;
+    Ok(header_size(shape.len()) + format.data_size(count))
+}
+
+/// Read element `index` of a float matrix in `format`
+fn read_float(format: DataFormat, data: &[u8], index: usize) -> f64 {
+    match format {
+        DataFormat::F32LE => {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&data[index * 4..index * 4 + 4]);
+            f32::from_le_bytes(bytes) as f64
+        }
+        _ => {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&data[index * 8..index * 8 + 8]);
+            f64::from_le_bytes(bytes)
+        }
+    }
+}
+
+/// Overwrite element `index` of a float matrix in `format`
+fn write_float(format: DataFormat, data: &mut [u8], index: usize, value: f64) {
+    match format {
+        DataFormat::F32LE => {
+            data[index * 4..index * 4 + 4].copy_from_slice(&(value as f32).to_le_bytes())
+        }
+        _ => data[index * 8..index * 8 + 8].copy_from_slice(&value.to_le_bytes()),
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Fill a buffer with a dense row-major tensor of `shape`, whose
+    /// elements are generated in `format_u8` with `params` exactly as
+    /// `fill_buffer_with_params` would, then given the matrix structure in
+    /// `structure`.
+    /// Buffer layout: [1 byte format][1 byte rank][4 bytes LE per dimension]
+    /// [data bytes...]
+    #[wasm_bindgen]
+    pub fn fill_tensor(
+        &self,
+        buffer: &mut [u8],
+        format_u8: u8,
+        shape: &[u32],
+        params: &FillParams,
+        structure: &TensorParams,
+    ) -> Result<Seed, Error> {
+        let format = DataFormat::fixed_width(format_u8)?;
+        let count = element_count(shape)?;
+        if format == DataFormat::BoolPacked {
+            return Err(Error::invalid_parameter(
+                "format",
+                &format!("{format_u8} cannot be a tensor element"),
+            ));
+        }
+        if params.null_probability().is_some() {
+            return Err(Error::invalid_parameter(
+                "null_probability",
+                "not supported for tensors",
+            ));
+        }
+        let structured = structure.symmetric || structure.diagonally_dominant;
+        if structured && (shape.len() != 2 || shape[0] != shape[1]) {
+            return Err(Error::invalid_parameter(
+                "shape",
+                &format!("{shape:?} is not a square matrix"),
+            ));
+        }
+        if structure.diagonally_dominant && !matches!(format, DataFormat::F64LE | DataFormat::F32LE)
+        {
+            return Err(Error::invalid_parameter(
+                "diagonally_dominant",
+                &format!("not supported for format {format_u8}"),
+            ));
+        }
+        let required = tensor_buffer_size(format_u8, shape)?;
+        if (buffer.len() as u64) < required {
+            return Err(Error::buffer_too_small(required, buffer.len()));
+        }
+
+        let mut column = vec![0; (HEADER_SIZE + format.data_size(count)) as usize];
+        let final_seed = self.fill_buffer_with_params(&mut column, format_u8, count, params)?;
+
+        buffer[0] = format_u8;
+        buffer[1] = shape.len() as u8;
+        for (i, dimension) in shape.iter().enumerate() {
+            buffer[2 + i * 4..6 + i * 4].copy_from_slice(&dimension.to_le_bytes());
+        }
+        let data_start = header_size(shape.len()) as usize;
+        let data = &mut buffer[data_start..required as usize];
+        data.copy_from_slice(&column[HEADER_SIZE as usize..]);
+
+        let n = shape.first().copied().unwrap_or_default() as usize;
+        if structure.symmetric {
+            let width = format.bytes_per_element() as usize;
+            for row in 1..n {
+                for col in 0..row {
+                    let (upper, lower) = (col * n + row, row * n + col);
+                    data.copy_within(upper * width..(upper + 1) * width, lower * width);
+                }
+            }
+        }
+        if structure.diagonally_dominant {
+            for row in 0..n {
+                let off_diagonal: f64 = (0..n)
+                    .filter(|&col| col != row)
+                    .map(|col| read_float(format, data, row * n + col).abs())
+                    .sum();
+                // The relative margin survives rounding the sum to f32
+                let diagonal = off_diagonal + 1.0 + off_diagonal * f32::EPSILON as f64;
+                write_float(format, data, row * n + row, diagonal);
+            }
+        }
+        Ok(final_seed)
+    }
+}
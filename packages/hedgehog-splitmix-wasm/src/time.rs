@@ -0,0 +1,307 @@
+use wasm_bindgen::prelude::*;
+
+use crate::error::Error;
+use crate::fill::{write_header, HEADER_SIZE};
+use crate::{check_probability, to_unit_f64, DataFormat, Seed, SeedAndI64};
+
+/// Instants (milliseconds since the Unix epoch, UTC) where calendar and
+/// timestamp handling commonly breaks
+const TIME_BOUNDARIES_MS: [i64; 16] = [
+    0,                      // Unix epoch
+    -1,                     // last millisecond before the epoch
+    -86_400_000,            // one day before the epoch
+    946_684_800_000,        // 2000-01-01T00:00:00Z
+    1_709_164_800_000,      // 2024-02-29T00:00:00Z, leap day
+    1_710_054_000_000,      // 2024-03-10T07:00:00Z, US DST start
+    1_711_846_800_000,      // 2024-03-31T01:00:00Z, EU DST start
+    1_729_990_800_000,      // 2024-10-27T01:00:00Z, EU DST end
+    1_730_613_600_000,      // 2024-11-03T06:00:00Z, US DST end
+    2_147_483_647_000,      // 2038-01-19T03:14:07Z, last i32 second
+    2_147_483_648_000,      // first second past the i32 limit
+    -2_147_483_648_000,     // 1901-12-13T20:45:52Z, first i32 second
+    253_402_300_799_999,    // 9999-12-31T23:59:59.999Z
+    253_402_300_800_000,    // 10000-01-01T00:00:00Z
+    8_640_000_000_000_000,  // largest JavaScript Date
+    -8_640_000_000_000_000, // smallest JavaScript Date
+];
+
+/// Time boundaries restricted to [min, max]
+fn time_boundaries(min: i64, max: i64) -> Vec<i64> {
+    TIME_BOUNDARIES_MS
+        .iter()
+        .copied()
+        .filter(|&t| t >= min && t <= max)
+        .collect()
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Generate a timestamp in milliseconds within [min_ms, max_ms], injecting
+    /// calendar boundaries (epoch 0, negative timestamps, the 2038 limit,
+    /// year 9999, DST transitions) with probability `boundary_probability`
+    #[wasm_bindgen]
+    pub fn next_timestamp_ms(
+        &self,
+        min_ms: i64,
+        max_ms: i64,
+        boundary_probability: f64,
+    ) -> Result<SeedAndI64, Error> {
+        if min_ms > max_ms {
+            return Err(Error::invalid_parameter(
+                "range",
+                &format!("[{min_ms}, {max_ms}]"),
+            ));
+        }
+        let p = check_probability("boundary_probability", boundary_probability)?;
+
+        let boundaries = time_boundaries(min_ms, max_ms);
+        let (seed, value) = self.next_range_with_edges(min_ms, max_ms, p, &boundaries);
+        Ok(SeedAndI64 { seed, value })
+    }
+}
+
+/// Parameters for `fill_latencies`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct LatencyParams {
+    median_ms: f64,
+    sigma: f64,
+    spike_probability: f64,
+    spike_multiplier: f64,
+}
+
+impl Default for LatencyParams {
+    fn default() -> LatencyParams {
+        LatencyParams {
+            median_ms: 20.0,
+            sigma: 0.5,
+            spike_probability: 0.01,
+            spike_multiplier: 10.0,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl LatencyParams {
+    /// Lognormal latencies with a 20ms median, sigma 0.5 and 1% of samples
+    /// spiking tenfold
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> LatencyParams {
+        LatencyParams::default()
+    }
+
+    /// Median of the lognormal body, in milliseconds
+    pub fn with_median_ms(mut self, median_ms: f64) -> LatencyParams {
+        self.median_ms = median_ms;
+        self
+    }
+
+    /// Shape of the lognormal body: the standard deviation of the
+    /// logarithm, where larger values give a longer tail
+    pub fn with_sigma(mut self, sigma: f64) -> LatencyParams {
+        self.sigma = sigma;
+        self
+    }
+
+    /// Probability of each sample being a spike, such as a GC pause or a
+    /// retransmit
+    pub fn with_spike_probability(mut self, p: f64) -> LatencyParams {
+        self.spike_probability = p;
+        self
+    }
+
+    /// Factor a spike multiplies its sample by
+    pub fn with_spike_multiplier(mut self, multiplier: f64) -> LatencyParams {
+        self.spike_multiplier = multiplier;
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn median_ms(&self) -> f64 {
+        self.median_ms
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn spike_probability(&self) -> f64 {
+        self.spike_probability
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn spike_multiplier(&self) -> f64 {
+        self.spike_multiplier
+    }
+}
+
+/// Parameters for `fill_clock`
+#[derive(Debug, Clone)]
+#[wasm_bindgen]
+pub struct ClockParams {
+    start_ms: i64,
+    tick_ms: i64,
+    jitter_ms: i64,
+    stall_probability: f64,
+}
+
+impl Default for ClockParams {
+    fn default() -> ClockParams {
+        ClockParams {
+            start_ms: 0,
+            tick_ms: 1000,
+            jitter_ms: 100,
+            stall_probability: 0.0,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl ClockParams {
+    /// Clock starting at 0 and ticking every 1000ms, give or take 100ms
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ClockParams {
+        ClockParams::default()
+    }
+
+    /// First reading, in milliseconds
+    pub fn with_start_ms(mut self, start_ms: i64) -> ClockParams {
+        self.start_ms = start_ms;
+        self
+    }
+
+    /// Mean gap between readings, in milliseconds
+    pub fn with_tick_ms(mut self, tick_ms: i64) -> ClockParams {
+        self.tick_ms = tick_ms;
+        self
+    }
+
+    /// Largest deviation of a gap from the tick, drawn uniformly from
+    /// [-jitter, jitter]; at most the tick so readings never go backwards
+    pub fn with_jitter_ms(mut self, jitter_ms: i64) -> ClockParams {
+        self.jitter_ms = jitter_ms;
+        self
+    }
+
+    /// Probability of a reading repeating the previous one, as a coarse or
+    /// cached clock does
+    pub fn with_stall_probability(mut self, p: f64) -> ClockParams {
+        self.stall_probability = p;
+        self
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn start_ms(&self) -> i64 {
+        self.start_ms
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn tick_ms(&self) -> i64 {
+        self.tick_ms
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn jitter_ms(&self) -> i64 {
+        self.jitter_ms
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn stall_probability(&self) -> f64 {
+        self.stall_probability
+    }
+}
+
+#[wasm_bindgen]
+impl Seed {
+    /// Fill an F64LE buffer (see `fill_buffer`) with `count` latency samples
+    /// in milliseconds: a lognormal body with occasional multiplicative
+    /// spikes, for property tests of timeouts and retries
+    #[wasm_bindgen]
+    pub fn fill_latencies(
+        &self,
+        buffer: &mut [u8],
+        count: u64,
+        params: &LatencyParams,
+    ) -> Result<Seed, Error> {
+        if !params.median_ms.is_finite() || params.median_ms <= 0.0 {
+            return Err(Error::invalid_parameter(
+                "median_ms",
+                &params.median_ms.to_string(),
+            ));
+        }
+        if !params.sigma.is_finite() || params.sigma < 0.0 {
+            return Err(Error::invalid_parameter("sigma", &params.sigma.to_string()));
+        }
+        if !params.spike_multiplier.is_finite() || params.spike_multiplier < 1.0 {
+            return Err(Error::invalid_parameter(
+                "spike_multiplier",
+                &params.spike_multiplier.to_string(),
+            ));
+        }
+        let spike_probability = check_probability("spike_probability", params.spike_probability)?;
+        write_header(buffer, DataFormat::F64LE, count)?;
+
+        let mut seed = self.clone();
+        let log_median = params.median_ms.ln();
+        for i in 0..count as usize {
+            // Box-Muller with 1 - u in (0, 1] so the logarithm stays finite
+            let u1 = 1.0 - to_unit_f64(seed.step());
+            let u2 = to_unit_f64(seed.step());
+            let normal = (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+            let mut latency = (log_median + params.sigma * normal).exp();
+            if to_unit_f64(seed.step()) < spike_probability {
+                latency *= params.spike_multiplier;
+            }
+            let offset = HEADER_SIZE as usize + i * 8;
+            buffer[offset..offset + 8].copy_from_slice(&latency.to_le_bytes());
+        }
+        Ok(seed)
+    }
+
+    /// Fill an I64LE buffer (see `fill_buffer`) with `count` readings of a
+    /// monotonic clock in milliseconds whose gaps jitter around the tick and
+    /// occasionally stall, for property tests of scheduling logic. Readings
+    /// never decrease and saturate at `i64::MAX`.
+    #[wasm_bindgen]
+    pub fn fill_clock(
+        &self,
+        buffer: &mut [u8],
+        count: u64,
+        params: &ClockParams,
+    ) -> Result<Seed, Error> {
+        if params.tick_ms < 0 {
+            return Err(Error::invalid_parameter(
+                "tick_ms",
+                &params.tick_ms.to_string(),
+            ));
+        }
+        if params.jitter_ms < 0 || params.jitter_ms > params.tick_ms {
+            return Err(Error::invalid_parameter(
+                "jitter_ms",
+                &format!("{} with tick {}", params.jitter_ms, params.tick_ms),
+            ));
+        }
+        let stall_probability = check_probability("stall_probability", params.stall_probability)?;
+        write_header(buffer, DataFormat::I64LE, count)?;
+
+        let mut seed = self.clone();
+        let mut now = params.start_ms;
+        let spread = 2 * params.jitter_ms as u64 + 1;
+        for i in 0..count as usize {
+            if i > 0 {
+                let stalled =
+                    stall_probability > 0.0 && to_unit_f64(seed.step()) < stall_probability;
+                if !stalled {
+                    let offset = seed.step_below(spread) as i128 - params.jitter_ms as i128;
+                    let gap = params.tick_ms as i128 + offset;
+                    now = (now as i128 + gap).min(i64::MAX as i128) as i64;
+                }
+            }
+            let offset = HEADER_SIZE as usize + i * 8;
+            buffer[offset..offset + 8].copy_from_slice(&now.to_le_bytes());
+        }
+        Ok(seed)
+    }
+}
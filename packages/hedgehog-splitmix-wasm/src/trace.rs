@@ -0,0 +1,332 @@
+use wasm_bindgen::prelude::*;
+
+use crate::encoding::{read_bytes, read_delta, read_u8, read_varint, write_delta, write_varint};
+use crate::error::Error;
+
+/// Magic bytes at the start of a serialized trace
+const TRACE_MAGIC: &[u8; 4] = b"HHTR";
+
+/// Current trace encoding version
+const TRACE_VERSION: u8 = 1;
+
+/// Trace encoding version written by `serialize_compressed`
+const TRACE_VERSION_COMPRESSED: u8 = 2;
+
+/// Operation tags recorded for each draw
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum DrawOp {
+    /// Raw 64-bit output
+    U64 = 0,
+    /// Value in [0, bound)
+    Bounded = 1,
+    /// Boolean stored as 0 or 1
+    Bool = 2,
+    /// Seed split; the result is the state of the new stream
+    Split = 3,
+}
+
+/// A single recorded draw
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Draw {
+    pub(crate) op: DrawOp,
+    pub(crate) bound: u64,
+    pub(crate) result: u64,
+}
+
+/// First point where two draw sequences differ in operation or bound.
+/// A missing operation means that side ended before the other.
+#[derive(Debug, Clone, PartialEq)]
+#[wasm_bindgen]
+pub struct Divergence {
+    index: usize,
+    expected: Option<(DrawOp, u64)>,
+    actual: Option<(DrawOp, u64)>,
+}
+
+impl Divergence {
+    /// Compare the draw at `index` of two sequences, returning a divergence
+    /// if the operations or bounds differ
+    pub(crate) fn check(
+        index: usize,
+        expected: Option<&Draw>,
+        actual: Option<&Draw>,
+    ) -> Option<Divergence> {
+        let expected = expected.map(|draw| (draw.op, draw.bound));
+        let actual = actual.map(|draw| (draw.op, draw.bound));
+        if expected == actual {
+            None
+        } else {
+            Some(Divergence {
+                index,
+                expected,
+                actual,
+            })
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Divergence {
+    /// Index of the first differing draw
+    #[wasm_bindgen(getter)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Recorded operation, or `undefined` if the recording ended first
+    #[wasm_bindgen(getter)]
+    pub fn expected_op(&self) -> Option<DrawOp> {
+        self.expected.map(|(op, _)| op)
+    }
+
+    /// Recorded bound, or `undefined` if the recording ended first
+    #[wasm_bindgen(getter)]
+    pub fn expected_bound(&self) -> Option<u64> {
+        self.expected.map(|(_, bound)| bound)
+    }
+
+    /// Observed operation, or `undefined` if the run ended first
+    #[wasm_bindgen(getter)]
+    pub fn actual_op(&self) -> Option<DrawOp> {
+        self.actual.map(|(op, _)| op)
+    }
+
+    /// Observed bound, or `undefined` if the run ended first
+    #[wasm_bindgen(getter)]
+    pub fn actual_bound(&self) -> Option<u64> {
+        self.actual.map(|(_, bound)| bound)
+    }
+}
+
+/// Recorded history of draws, stored one row per draw; `ops`, `bounds` and
+/// `results` copy out a column each for JavaScript as a typed array
+#[derive(Debug, Clone, Default)]
+#[wasm_bindgen]
+pub struct Trace {
+    draws: Vec<Draw>,
+}
+
+/// Append a draw count followed by the encoded draws
+pub(crate) fn write_draws(out: &mut Vec<u8>, draws: &[Draw]) {
+    write_varint(out, draws.len() as u64);
+    for draw in draws {
+        out.push(draw.op as u8);
+        if draw.op == DrawOp::Bounded {
+            write_varint(out, draw.bound);
+        }
+        write_varint(out, draw.result);
+    }
+}
+
+/// Read draws written by `write_draws` starting at `*position`
+pub(crate) fn read_draws(bytes: &[u8], position: &mut usize) -> Result<Vec<Draw>, Error> {
+    let count = read_varint(bytes, position)?;
+    // Every draw takes at least two bytes, which bounds the allocation
+    let mut draws = Vec::with_capacity(count.min(bytes.len() as u64 / 2) as usize);
+    for _ in 0..count {
+        let op = DrawOp::from_u8(read_u8(bytes, position)?)?;
+        let bound = if op == DrawOp::Bounded {
+            read_varint(bytes, position)?
+        } else {
+            0
+        };
+        let result = read_varint(bytes, position)?;
+        draws.push(Draw { op, bound, result });
+    }
+    Ok(draws)
+}
+
+/// Append a draw count followed by the draws column by column: operations
+/// packed four to a byte, bounds as deltas from the previous bound, results
+/// of non-boolean draws, then booleans packed eight to a byte
+pub(crate) fn write_draws_compressed(out: &mut Vec<u8>, draws: &[Draw]) {
+    write_varint(out, draws.len() as u64);
+
+    for ops in draws.chunks(4) {
+        out.push(ops.iter().enumerate().fold(0, |packed, (slot, draw)| {
+            packed | (draw.op as u8) << (2 * slot)
+        }));
+    }
+
+    let mut previous = 0;
+    for draw in draws.iter().filter(|draw| draw.op == DrawOp::Bounded) {
+        write_delta(out, previous, draw.bound);
+        previous = draw.bound;
+    }
+
+    let mut bools = Vec::new();
+    for draw in draws {
+        if draw.op == DrawOp::Bool {
+            bools.push(draw.result != 0);
+        } else {
+            write_varint(out, draw.result);
+        }
+    }
+    for byte in bools.chunks(8) {
+        out.push(
+            byte.iter()
+                .enumerate()
+                .fold(0, |packed, (bit, &set)| packed | (set as u8) << bit),
+        );
+    }
+}
+
+/// Read draws written by `write_draws_compressed` starting at `*position`
+pub(crate) fn read_draws_compressed(
+    bytes: &[u8],
+    position: &mut usize,
+) -> Result<Vec<Draw>, Error> {
+    let count = read_varint(bytes, position)?;
+    // The packed operations must all be present, which bounds the allocation
+    let packed_ops = read_bytes(
+        bytes,
+        position,
+        count.div_ceil(4).try_into().unwrap_or(usize::MAX),
+    )?;
+    let mut draws = Vec::with_capacity(count as usize);
+    for index in 0..count as usize {
+        let op = DrawOp::from_u8(packed_ops[index / 4] >> (2 * (index % 4)) & 3)?;
+        draws.push(Draw {
+            op,
+            bound: 0,
+            result: 0,
+        });
+    }
+    let mut previous = 0;
+    for draw in draws.iter_mut().filter(|draw| draw.op == DrawOp::Bounded) {
+        draw.bound = read_delta(bytes, position, previous)?;
+        previous = draw.bound;
+    }
+    for draw in draws.iter_mut().filter(|draw| draw.op != DrawOp::Bool) {
+        draw.result = read_varint(bytes, position)?;
+    }
+    let bools = draws.iter().filter(|draw| draw.op == DrawOp::Bool).count();
+    let packed = read_bytes(bytes, position, bools.div_ceil(8))?;
+    for (index, draw) in draws
+        .iter_mut()
+        .filter(|draw| draw.op == DrawOp::Bool)
+        .enumerate()
+    {
+        draw.result = (packed[index / 8] >> (index % 8) & 1) as u64;
+    }
+    Ok(draws)
+}
+
+impl DrawOp {
+    fn from_u8(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(DrawOp::U64),
+            1 => Ok(DrawOp::Bounded),
+            2 => Ok(DrawOp::Bool),
+            3 => Ok(DrawOp::Split),
+            _ => Err(Error::invalid_encoding(&format!("unknown draw op {value}"))),
+        }
+    }
+}
+
+impl Trace {
+    pub(crate) fn from_draws(draws: Vec<Draw>) -> Trace {
+        Trace { draws }
+    }
+
+    pub(crate) fn draws(&self) -> &[Draw] {
+        &self.draws
+    }
+}
+
+#[wasm_bindgen]
+impl Trace {
+    /// Number of recorded draws
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.draws.len()
+    }
+
+    /// Operation tag of every draw
+    #[wasm_bindgen(getter)]
+    pub fn ops(&self) -> Vec<u8> {
+        self.draws.iter().map(|draw| draw.op as u8).collect()
+    }
+
+    /// Bound of every draw (0 for unbounded operations)
+    #[wasm_bindgen(getter)]
+    pub fn bounds(&self) -> Vec<u64> {
+        self.draws.iter().map(|draw| draw.bound).collect()
+    }
+
+    /// Result of every draw
+    #[wasm_bindgen(getter)]
+    pub fn results(&self) -> Vec<u64> {
+        self.draws.iter().map(|draw| draw.result).collect()
+    }
+
+    /// Serialize into the versioned binary trace format:
+    ///
+    /// ```text
+    /// [4 bytes "HHTR"][1 byte version = 1][varint draw count]
+    /// per draw: [1 byte op][varint bound, Bounded only][varint result]
+    /// ```
+    ///
+    /// Varints are unsigned LEB128.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(6 + self.draws.len() * 4);
+        out.extend_from_slice(TRACE_MAGIC);
+        out.push(TRACE_VERSION);
+        write_draws(&mut out, &self.draws);
+        out
+    }
+
+    /// Serialize like `serialize`, but with version 2 and the draws stored
+    /// column by column:
+    ///
+    /// ```text
+    /// [4 bytes "HHTR"][1 byte version = 2][varint draw count]
+    /// [ops, 4 per byte, 2 bits each, low bits first]
+    /// per Bounded draw: [zigzag varint bound - previous bound]
+    /// per non-Bool draw: [varint result]
+    /// [Bool results, 8 per byte, low bit first]
+    /// ```
+    ///
+    /// Bounded draws from list-heavy generators shrink to about two bytes
+    /// and booleans to a few bits. `parse` reads both versions.
+    pub fn serialize_compressed(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(6 + self.draws.len() * 2);
+        out.extend_from_slice(TRACE_MAGIC);
+        out.push(TRACE_VERSION_COMPRESSED);
+        write_draws_compressed(&mut out, &self.draws);
+        out
+    }
+
+    /// Parse a trace produced by `serialize` or `serialize_compressed`
+    pub fn parse(bytes: &[u8]) -> Result<Trace, Error> {
+        if bytes.len() < TRACE_MAGIC.len() || &bytes[..TRACE_MAGIC.len()] != TRACE_MAGIC {
+            return Err(Error::invalid_encoding("missing trace header"));
+        }
+        let mut position = TRACE_MAGIC.len();
+
+        let draws = match read_u8(bytes, &mut position)? {
+            TRACE_VERSION => read_draws(bytes, &mut position)?,
+            TRACE_VERSION_COMPRESSED => read_draws_compressed(bytes, &mut position)?,
+            version => {
+                return Err(Error::invalid_encoding(&format!(
+                    "unsupported trace version {version}"
+                )))
+            }
+        };
+
+        if position != bytes.len() {
+            return Err(Error::invalid_encoding("trailing bytes after trace"));
+        }
+        Ok(Trace { draws })
+    }
+
+    /// Find the first draw where `other` differs from this trace in
+    /// operation or bound, treating this trace as the expected sequence
+    pub fn first_divergence(&self, other: &Trace) -> Option<Divergence> {
+        let length = self.draws.len().max(other.draws.len());
+        (0..length).find_map(|index| {
+            Divergence::check(index, self.draws.get(index), other.draws.get(index))
+        })
+    }
+}
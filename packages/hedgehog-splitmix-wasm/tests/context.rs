@@ -0,0 +1,191 @@
+use hedgehog_splitmix_wasm::{GenContext, Seed, Trace};
+
+/// Interleave every draw kind, including draws from a split-off child, and
+/// return the values in draw order
+fn draw_mixed(context: &mut GenContext) -> Vec<u64> {
+    let mut values = Vec::new();
+    for round in 0..16 {
+        values.push(context.next_u64().unwrap());
+        values.push(context.next_bounded(round * 37 + 1).unwrap());
+        values.push(context.next_bool().unwrap() as u64);
+        if round % 4 == 0 {
+            let mut child = context.split().unwrap();
+            values.push(child.next_bounded(1000).unwrap());
+            values.push(child.next_u64().unwrap());
+        }
+    }
+    values
+}
+
+fn draw_words(context: &mut GenContext, count: usize) -> Vec<u64> {
+    (0..count).map(|_| context.next_u64().unwrap()).collect()
+}
+
+fn word_trace(seed: u64, count: usize) -> Trace {
+    let mut context = GenContext::new(&Seed::new(seed));
+    context.start_recording();
+    draw_words(&mut context, count);
+    context.stop_recording()
+}
+
+fn recorded_trace(seed: u64) -> (Trace, Vec<u64>) {
+    let mut context = GenContext::new(&Seed::new(seed));
+    context.start_recording();
+    let values = draw_mixed(&mut context);
+    (context.stop_recording(), values)
+}
+
+#[test]
+fn trace_round_trips_through_both_encodings() {
+    let (trace, values) = recorded_trace(42);
+    for bytes in [trace.serialize(), trace.serialize_compressed()] {
+        let parsed = Trace::parse(&bytes).unwrap();
+        assert_eq!(parsed.ops(), trace.ops());
+        assert_eq!(parsed.bounds(), trace.bounds());
+        assert_eq!(parsed.results(), trace.results());
+
+        let mut replay = GenContext::replay(&parsed);
+        assert_eq!(draw_mixed(&mut replay), values);
+    }
+}
+
+#[test]
+fn verifying_context_accepts_its_own_trace() {
+    let (trace, values) = recorded_trace(7);
+    let mut context = GenContext::verifying(&Seed::new(7), &trace);
+    assert_eq!(draw_mixed(&mut context), values);
+    assert!(context.divergence().is_none());
+}
+
+#[test]
+fn truncated_trace_is_rejected() {
+    let (trace, _) = recorded_trace(3);
+    for bytes in [trace.serialize(), trace.serialize_compressed()] {
+        for end in 0..bytes.len() {
+            assert!(
+                Trace::parse(&bytes[..end]).is_err(),
+                "prefix of {end} bytes"
+            );
+        }
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(Trace::parse(&extended).is_err());
+    }
+}
+
+#[test]
+fn corrupted_trace_header_is_rejected() {
+    let (trace, _) = recorded_trace(3);
+    let mut bytes = trace.serialize();
+    bytes[0] ^= 0xff;
+    assert!(Trace::parse(&bytes).is_err());
+
+    let mut bytes = trace.serialize();
+    bytes[4] = 0xff;
+    assert!(Trace::parse(&bytes).is_err());
+}
+
+#[test]
+fn replay_rejects_draws_past_the_trace() {
+    let (trace, _) = recorded_trace(11);
+    let mut replay = GenContext::replay(&trace);
+    draw_mixed(&mut replay);
+    assert!(replay.next_u64().is_err());
+}
+
+#[test]
+fn checkpoint_restores_the_next_draws() {
+    let trace = word_trace(5, 8);
+    let bytes: Vec<u8> = (0..=255).collect();
+    let contexts = [
+        GenContext::new(&Seed::new(5)),
+        GenContext::replay(&trace),
+        GenContext::from_bytes(&bytes),
+    ];
+    for mut context in contexts {
+        draw_words(&mut context, 3);
+        let token = context.checkpoint();
+        let mut restored = GenContext::restore(&token).unwrap();
+        assert_eq!(restored.bytes_consumed(), context.bytes_consumed());
+        assert_eq!(draw_words(&mut restored, 5), draw_words(&mut context, 5));
+    }
+}
+
+#[test]
+fn checkpoint_keeps_the_recording() {
+    let mut context = GenContext::new(&Seed::new(9));
+    context.start_recording();
+    draw_words(&mut context, 4);
+    let mut restored = GenContext::restore(&context.checkpoint()).unwrap();
+    assert!(restored.recording());
+
+    draw_words(&mut context, 2);
+    draw_words(&mut restored, 2);
+    let original = context.stop_recording();
+    let copy = restored.stop_recording();
+    assert_eq!(copy.results(), original.results());
+}
+
+#[test]
+fn checkpoint_past_the_end_of_the_stream_reads_zeros() {
+    let mut context = GenContext::from_bytes(&[1, 2, 3]);
+    draw_words(&mut context, 2);
+    assert!(context.overrun());
+
+    let mut restored = GenContext::restore(&context.checkpoint()).unwrap();
+    assert!(restored.overrun());
+    assert_eq!(draw_words(&mut restored, 2), vec![0, 0]);
+}
+
+#[test]
+fn truncated_checkpoint_is_rejected() {
+    let trace = word_trace(13, 2);
+    let mut recording = GenContext::from_bytes(&[9; 40]);
+    recording.start_recording();
+    let contexts = [
+        GenContext::new(&Seed::new(13)),
+        GenContext::replay(&trace),
+        recording,
+    ];
+    for mut context in contexts {
+        draw_words(&mut context, 2);
+        let token = context.checkpoint();
+        for end in 0..token.len() {
+            assert!(
+                GenContext::restore(&token[..end]).is_err(),
+                "prefix of {end} bytes"
+            );
+        }
+        let mut extended = token.clone();
+        extended.push(0);
+        assert!(GenContext::restore(&extended).is_err());
+    }
+}
+
+#[test]
+fn checkpoint_with_impossible_stream_position_is_rejected() {
+    // "HHCK", version 1, byte stream source, then the stream position as a
+    // varint, an empty stream and no recording
+    let token = |position: &[u8]| {
+        let mut token = b"HHCK\x01\x02".to_vec();
+        token.extend_from_slice(position);
+        token.extend_from_slice(&[0, 0]);
+        token
+    };
+    let max = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+    assert!(GenContext::restore(&token(&max)).is_err());
+    assert!(GenContext::restore(&token(&[3])).is_err());
+
+    let mut restored = GenContext::restore(&token(&[16])).unwrap();
+    assert_eq!(draw_words(&mut restored, 2), vec![0, 0]);
+}
+
+#[test]
+fn corrupted_checkpoint_header_is_rejected() {
+    let token = GenContext::new(&Seed::new(1)).checkpoint();
+    for index in 0..6 {
+        let mut corrupted = token.clone();
+        corrupted[index] ^= 0x80;
+        assert!(GenContext::restore(&corrupted).is_err(), "byte {index}");
+    }
+}
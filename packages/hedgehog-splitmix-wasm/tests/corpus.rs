@@ -0,0 +1,162 @@
+use hedgehog_splitmix_wasm::{Corpus, FailureClusters, GoldenCorpus, Seed};
+
+/// A corpus of seeds and choice streams that share prefixes, so the
+/// compressed export has something to elide
+fn sample_corpus() -> Corpus {
+    let mut corpus = Corpus::new();
+    let mut choices: Vec<u8> = (0..40).collect();
+    for round in 0..6u8 {
+        corpus.add_seed(&Seed::new(round as u64 * 1_000_003));
+        choices[20 + round as usize] ^= 0xa5;
+        corpus.add_choices(&choices);
+        choices.truncate(38 - round as usize);
+    }
+    corpus.add_choices(&[]);
+    corpus
+}
+
+fn assert_same_entries(left: &Corpus, right: &Corpus) {
+    assert_eq!(left.length(), right.length());
+    for index in 0..left.length() {
+        let (left, right) = (left.get(index).unwrap(), right.get(index).unwrap());
+        assert_eq!(left.choices(), right.choices(), "entry {index}");
+        assert_eq!(
+            left.seed().map(|seed| (seed.state(), seed.gamma())),
+            right.seed().map(|seed| (seed.state(), seed.gamma())),
+            "entry {index}"
+        );
+    }
+}
+
+#[test]
+fn corpus_round_trips_through_both_encodings() {
+    let corpus = sample_corpus();
+    for bytes in [corpus.export(), corpus.export_compressed()] {
+        let mut imported = Corpus::new();
+        assert_eq!(imported.import(&bytes).unwrap(), corpus.length());
+        assert_same_entries(&imported, &corpus);
+        assert_eq!(imported.import(&bytes).unwrap(), 0);
+    }
+}
+
+#[test]
+fn truncated_corpus_is_rejected_without_adding_entries() {
+    let corpus = sample_corpus();
+    for bytes in [corpus.export(), corpus.export_compressed()] {
+        let mut imported = Corpus::new();
+        for end in 0..bytes.len() {
+            assert!(
+                imported.import(&bytes[..end]).is_err(),
+                "prefix of {end} bytes"
+            );
+        }
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(imported.import(&extended).is_err());
+        assert_eq!(imported.length(), 0);
+    }
+}
+
+#[test]
+fn corrupted_corpus_header_is_rejected() {
+    let bytes = sample_corpus().export();
+    for index in 0..5 {
+        let mut corrupted = bytes.clone();
+        corrupted[index] ^= 0x80;
+        assert!(Corpus::new().import(&corrupted).is_err(), "byte {index}");
+    }
+}
+
+#[test]
+fn golden_corpus_round_trips() {
+    let corpus = sample_corpus();
+    let golden = GoldenCorpus::new(&corpus, &Seed::new(17), 30, 0xdead_beef, "sorted list");
+    let parsed = GoldenCorpus::parse(&golden.serialize()).unwrap();
+    assert_eq!(parsed.seed().state(), golden.seed().state());
+    assert_eq!(parsed.seed().gamma(), golden.seed().gamma());
+    assert_eq!(parsed.size(), 30);
+    assert_eq!(parsed.config_hash(), 0xdead_beef);
+    assert_eq!(parsed.label(), "sorted list");
+    assert!(parsed.matches(&corpus));
+    assert_same_entries(&parsed.corpus(), &corpus);
+
+    let mut other = corpus.clone();
+    other.add_seed(&Seed::new(99));
+    assert!(!parsed.matches(&other));
+}
+
+#[test]
+fn truncated_golden_corpus_is_rejected() {
+    let golden = GoldenCorpus::new(&sample_corpus(), &Seed::new(17), 30, 1, "label");
+    let bytes = golden.serialize();
+    for end in 0..bytes.len() {
+        assert!(
+            GoldenCorpus::parse(&bytes[..end]).is_err(),
+            "prefix of {end} bytes"
+        );
+    }
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert!(GoldenCorpus::parse(&extended).is_err());
+}
+
+#[test]
+fn corrupted_golden_corpus_is_rejected() {
+    let golden = GoldenCorpus::new(&sample_corpus(), &Seed::new(17), 30, 1, "label");
+    let bytes = golden.serialize();
+    for index in 0..bytes.len() {
+        let mut corrupted = bytes.clone();
+        corrupted[index] ^= 0x01;
+        assert!(GoldenCorpus::parse(&corrupted).is_err(), "byte {index}");
+    }
+}
+
+fn sample_clusters() -> FailureClusters {
+    let mut clusters = FailureClusters::new();
+    for round in 0..8u8 {
+        let mut choices = vec![round % 3; 24];
+        choices.extend_from_slice(&[0; 16]);
+        clusters.add(&choices);
+    }
+    clusters
+}
+
+#[test]
+fn failure_clusters_round_trip() {
+    let clusters = sample_clusters();
+    let mut imported = FailureClusters::new();
+    assert_eq!(
+        imported.import(&clusters.export()).unwrap(),
+        clusters.length()
+    );
+    for index in 0..clusters.length() {
+        assert_eq!(imported.fingerprint(index), clusters.fingerprint(index));
+        assert_eq!(imported.occurrences(index), clusters.occurrences(index));
+        assert_eq!(
+            imported.representative(index),
+            clusters.representative(index)
+        );
+    }
+
+    assert_eq!(imported.import(&clusters.export()).unwrap(), 0);
+    assert_eq!(
+        imported.occurrences(0),
+        clusters.occurrences(0).map(|n| n * 2)
+    );
+}
+
+#[test]
+fn truncated_failure_clusters_are_rejected() {
+    let bytes = sample_clusters().export();
+    let mut imported = FailureClusters::new();
+    for end in 0..bytes.len() {
+        assert!(
+            imported.import(&bytes[..end]).is_err(),
+            "prefix of {end} bytes"
+        );
+    }
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert!(imported.import(&extended).is_err());
+    assert_eq!(imported.length(), 0);
+}
@@ -0,0 +1,176 @@
+use hedgehog_splitmix_wasm::Seed;
+
+const SAMPLES: usize = 20_000;
+
+/// Sample mean and (unbiased) variance
+fn moments(values: &[f64]) -> (f64, f64) {
+    let count = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (count - 1.0);
+    (mean, variance)
+}
+
+/// Check sample moments against the expected mean and variance, allowing
+/// five standard errors for the mean and a relative `slack` for the
+/// variance
+fn assert_moments(values: &[f64], mean: f64, variance: f64, slack: f64, what: &str) {
+    let (sample_mean, sample_variance) = moments(values);
+    let error = 5.0 * (variance / values.len() as f64).sqrt();
+    assert!(
+        (sample_mean - mean).abs() <= error,
+        "{what}: mean {sample_mean}, expected {mean} ± {error}"
+    );
+    assert!(
+        (sample_variance / variance - 1.0).abs() <= slack,
+        "{what}: variance {sample_variance}, expected {variance}"
+    );
+}
+
+fn binomials(seed: u64, n: u32, p: f64) -> Vec<f64> {
+    let mut seed = Seed::new(seed);
+    (0..SAMPLES)
+        .map(|_| {
+            let result = seed.next_binomial(n, p).unwrap();
+            seed = result.seed();
+            result.value() as f64
+        })
+        .collect()
+}
+
+#[test]
+fn poisson_matches_mean_and_variance_on_both_paths() {
+    // 9.9 is the last mean drawn by inversion and 10 the first drawn by PTRS
+    for lambda in [0.5, 9.9, 10.0, 1e6] {
+        let batch = Seed::new(41)
+            .next_poisson_batch(lambda, SAMPLES as u32)
+            .unwrap();
+        let values: Vec<f64> = batch.values().iter().map(|&k| k as f64).collect();
+        assert_moments(&values, lambda, lambda, 0.1, &format!("lambda {lambda}"));
+    }
+}
+
+#[test]
+fn poisson_batch_matches_chained_draws() {
+    for lambda in [3.0, 250.0] {
+        let batch = Seed::new(8).next_poisson_batch(lambda, 64).unwrap();
+        let mut seed = Seed::new(8);
+        for &expected in &batch.values() {
+            let result = seed.next_poisson(lambda).unwrap();
+            assert_eq!(result.value(), expected);
+            seed = result.seed();
+        }
+        assert_eq!(seed.state(), batch.final_seed().state());
+    }
+}
+
+#[test]
+fn binomial_matches_mean_and_variance_on_both_paths() {
+    // n * min(p, 1 - p) of 29 is drawn by inversion and 30 by BTPE; p above
+    // one half flips the count on either path
+    let cases = [
+        (100, 0.29),
+        (100, 0.3),
+        (100, 0.71),
+        (100, 0.7),
+        (1000, 0.9),
+        (1_000_000, 0.4),
+        (u32::MAX, 1e-3),
+    ];
+    for (n, p) in cases {
+        let values = binomials(59, n, p);
+        let (mean, variance) = (n as f64 * p, n as f64 * p * (1.0 - p));
+        assert_moments(&values, mean, variance, 0.1, &format!("n {n}, p {p}"));
+        assert!(values.iter().all(|&k| k <= n as f64));
+    }
+}
+
+#[test]
+fn binomial_with_certain_outcomes_is_constant() {
+    assert!(binomials(2, 50, 0.0).iter().all(|&k| k == 0.0));
+    assert!(binomials(2, 50, 1.0).iter().all(|&k| k == 50.0));
+    assert!(binomials(2, 0, 0.5).iter().all(|&k| k == 0.0));
+}
+
+#[test]
+fn zipf_ranks_follow_the_power_law() {
+    let mut seed = Seed::new(97);
+    let mut counts = [0u32; 101];
+    for _ in 0..SAMPLES {
+        let result = seed.next_zipf(100, 1.5).unwrap();
+        seed = result.seed();
+        counts[result.value() as usize] += 1;
+    }
+    assert_eq!(counts[0], 0);
+    // Rank 1 is 2^1.5 times as likely as rank 2
+    let ratio = counts[1] as f64 / counts[2] as f64;
+    assert!((ratio / 2f64.powf(1.5) - 1.0).abs() < 0.1, "ratio {ratio}");
+    assert!(counts[1] > counts[10] && counts[10] > counts[100]);
+}
+
+#[test]
+fn zipf_without_skew_is_uniform() {
+    let mut seed = Seed::new(3);
+    let values: Vec<f64> = (0..SAMPLES)
+        .map(|_| {
+            let result = seed.next_zipf(1000, 0.0).unwrap();
+            seed = result.seed();
+            assert!((1..=1000).contains(&result.value()));
+            result.value() as f64
+        })
+        .collect();
+    let variance = (1000f64 * 1000.0 - 1.0) / 12.0;
+    assert_moments(&values, 500.5, variance, 0.05, "uniform zipf");
+}
+
+#[test]
+fn normal_matches_mean_and_variance() {
+    let mut seed = Seed::new(71);
+    let values: Vec<f64> = (0..SAMPLES)
+        .map(|_| {
+            let result = seed.next_normal(-3.0, 2.5).unwrap();
+            seed = result.seed();
+            result.value()
+        })
+        .collect();
+    assert_moments(&values, -3.0, 6.25, 0.05, "normal");
+}
+
+#[test]
+fn exponential_matches_mean_and_variance() {
+    let mut seed = Seed::new(13);
+    let values: Vec<f64> = (0..SAMPLES)
+        .map(|_| {
+            let result = seed.next_exponential(4.0).unwrap();
+            seed = result.seed();
+            assert!(result.value() >= 0.0);
+            result.value()
+        })
+        .collect();
+    assert_moments(&values, 0.25, 0.0625, 0.1, "exponential");
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    let seed = Seed::new(1);
+    for lambda in [-1.0, f64::NAN, f64::INFINITY, 1e9 + 1.0] {
+        assert!(seed.next_poisson(lambda).is_err(), "lambda {lambda}");
+        assert!(
+            seed.next_poisson_batch(lambda, 4).is_err(),
+            "lambda {lambda}"
+        );
+    }
+    for p in [-0.1, 1.1, f64::NAN] {
+        assert!(seed.next_binomial(10, p).is_err(), "p {p}");
+    }
+    assert!(seed.next_zipf(0, 1.0).is_err());
+    for s in [-1.0, f64::NAN, f64::INFINITY] {
+        assert!(seed.next_zipf(10, s).is_err(), "s {s}");
+    }
+    assert!(seed.next_normal(f64::INFINITY, 1.0).is_err());
+    for stddev in [-1.0, f64::NAN, f64::INFINITY] {
+        assert!(seed.next_normal(0.0, stddev).is_err(), "stddev {stddev}");
+    }
+    for lambda in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(seed.next_exponential(lambda).is_err(), "lambda {lambda}");
+    }
+}
@@ -0,0 +1,46 @@
+use hedgehog_splitmix_wasm::{fill_buffer_size, DataFormat, MoneyParams, Seed};
+
+fn amounts(params: &MoneyParams, count: u64) -> Vec<i64> {
+    let size = fill_buffer_size(DataFormat::DecimalI64 as u8, count).unwrap();
+    let mut buffer = vec![0u8; size as usize];
+    Seed::new(23)
+        .fill_amounts(&mut buffer, count, params)
+        .unwrap();
+    buffer[9..]
+        .chunks_exact(8)
+        .map(|chunk| i64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
+
+#[test]
+fn log_uniform_amounts_stay_in_range() {
+    let params = MoneyParams::new()
+        .with_scale(2)
+        .with_denomination(5)
+        .with_range(0, 1_000_000)
+        .with_log_uniform(true);
+    for amount in amounts(&params, 500) {
+        assert!((0..=100_000_000).contains(&amount), "{amount}");
+        assert_eq!(amount % 5, 0);
+    }
+}
+
+#[test]
+fn log_uniform_amounts_reach_i64_max() {
+    let params = MoneyParams::new()
+        .with_scale(0)
+        .with_range(0, i64::MAX as u64)
+        .with_log_uniform(true);
+    let values = amounts(&params, 500);
+    assert!(values.iter().all(|&amount| amount >= 0));
+    assert!(values.iter().any(|&amount| amount > i64::MAX / 2));
+}
+
+#[test]
+fn range_past_i64_is_rejected() {
+    let params = MoneyParams::new()
+        .with_scale(2)
+        .with_range(0, i64::MAX as u64);
+    let mut buffer = vec![0u8; 64];
+    assert!(Seed::new(1).fill_amounts(&mut buffer, 1, &params).is_err());
+}
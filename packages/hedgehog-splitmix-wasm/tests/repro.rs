@@ -0,0 +1,31 @@
+use hedgehog_splitmix_wasm::{make_repro, parse_repro, Seed};
+
+#[test]
+fn repro_round_trips() {
+    let seed = Seed::new(u64::MAX);
+    let token = make_repro(&seed, u32::MAX, 0x0123_4567_89ab_cdef);
+    let repro = parse_repro(&format!("  {token}\n")).unwrap();
+    assert_eq!(repro.seed().state(), seed.state());
+    assert_eq!(repro.seed().gamma(), seed.gamma());
+    assert_eq!(repro.size(), u32::MAX);
+    assert_eq!(repro.config_hash(), 0x0123_4567_89ab_cdef);
+}
+
+#[test]
+fn truncated_repro_is_rejected() {
+    let token = make_repro(&Seed::new(5), 100, 7);
+    for end in 0..token.len() {
+        assert!(
+            parse_repro(&token[..end]).is_err(),
+            "prefix of {end} characters"
+        );
+    }
+    assert!(parse_repro(&format!("{token}A")).is_err());
+}
+
+#[test]
+fn corrupted_repro_is_rejected() {
+    let token = make_repro(&Seed::new(5), 100, 7);
+    assert!(parse_repro(&token.replacen("hh1.", "hh2.", 1)).is_err());
+    assert!(parse_repro(&format!("{}!", &token[..token.len() - 1])).is_err());
+}
@@ -0,0 +1,44 @@
+use hedgehog_splitmix_wasm::{shard, Seed};
+
+/// Seeds of the first `count` sampled cases as the sequential runner
+/// derives them, after splitting once per example
+fn sequential_seeds(seed: &Seed, examples: u32, count: u32) -> Vec<(u64, u64)> {
+    let mut current = seed.clone();
+    for _ in 0..examples {
+        current = current.split().right();
+    }
+    (0..count)
+        .map(|_| {
+            let pair = current.split();
+            current = pair.right();
+            (pair.left().state(), pair.left().gamma())
+        })
+        .collect()
+}
+
+#[test]
+fn shards_partition_the_sequential_run() {
+    let seed = Seed::new(2024);
+    for examples in [0, 3] {
+        let expected = sequential_seeds(&seed, examples, 50);
+        let mut covered = [false; 50];
+        for worker in 0..4 {
+            let assigned = shard(&seed, examples, 50, worker, 4).unwrap();
+            for (position, &index) in assigned.indices().iter().enumerate() {
+                assert_eq!(index % 4, worker);
+                assert!(!covered[index as usize], "case {index} assigned twice");
+                covered[index as usize] = true;
+                let case = assigned.seed(position).unwrap();
+                assert_eq!((case.state(), case.gamma()), expected[index as usize]);
+            }
+        }
+        assert!(covered.iter().all(|&done| done));
+    }
+}
+
+#[test]
+fn invalid_workers_are_rejected() {
+    let seed = Seed::new(1);
+    assert!(shard(&seed, 0, 10, 0, 0).is_err());
+    assert!(shard(&seed, 0, 10, 4, 4).is_err());
+}
@@ -1,5 +1,8 @@
 import { describe, test, expect } from 'vitest';
-import { Config } from './config.js';
+import { Config, Enumeration } from './config.js';
+import { SizeProgression } from './data/size.js';
+import { ShrinkOrder } from './gen/shrink.js';
+import { ResultCache } from './result-cache.js';
 
 describe('Config', () => {
   test('creates default configuration', () => {
@@ -34,6 +37,46 @@ describe('Config', () => {
     expect(config.testLimit).toBe(100); // Others unchanged
   });
 
+  test('creates config with custom size progression', () => {
+    const progression = SizeProgression.constant(10);
+    const config = Config.default()
+      .withSizeProgression(progression)
+      .withTests(50);
+    expect(config.sizeProgression).toBe(progression);
+    expect(config.testLimit).toBe(50);
+    expect(
+      new Config({ sizeProgression: progression }).sizeProgression
+    ).toBe(progression);
+  });
+
+  test('creates config with custom shrink order', () => {
+    const order = ShrinkOrder.structural();
+    const config = Config.default().withShrinkOrder(order).withShrinks(10);
+    expect(config.shrinkOrder).toBe(order);
+    expect(config.shrinkLimit).toBe(10);
+    expect(new Config({ shrinkOrder: order }).shrinkOrder).toBe(order);
+  });
+
+  test('creates config with enumeration mode', () => {
+    const config = Config.default()
+      .withEnumeration(Enumeration.shuffled(50))
+      .withTests(10);
+    expect(config.enumeration.enabled).toBe(true);
+    expect(config.enumeration.shuffled).toBe(true);
+    expect(config.enumeration.limit).toBe(50);
+    expect(Config.default().enumeration.enabled).toBe(false);
+    expect(() => Enumeration.ordered(0)).toThrow();
+  });
+
+  test('creates config with a result cache', () => {
+    const cache = new ResultCache({ maxEntries: 10 });
+    const config = Config.default().withResultCache(cache).withTests(10);
+    expect(config.resultCache).toBe(cache);
+    expect(config.testLimit).toBe(10);
+    expect(new Config({ resultCache: cache }).resultCache).toBe(cache);
+    expect(Config.default().resultCache).toBeUndefined();
+  });
+
   test('configuration edge cases', () => {
     // Zero limits should be allowed (though may not be practical)
     const zeroConfig = Config.default()
@@ -1,3 +1,54 @@
+import { SizeProgression } from './data/size.js';
+import { ShrinkOrder } from './gen/shrink.js';
+import { ResultCache } from './result-cache.js';
+
+/**
+ * Whether properties over small finite domains are checked exhaustively.
+ *
+ * When enabled and the generator's whole domain fits within `limit` values
+ * (small integer ranges, booleans, enums and tuples of these), every value
+ * is tested once and a pass is a proof rather than a sample. Generators
+ * with larger or unknown domains fall back to random sampling.
+ */
+export class Enumeration {
+  private constructor(
+    /** Whether enumeration is attempted at all. */
+    readonly enabled: boolean,
+    /** Whether enumerated values are tested in a seed-determined order. */
+    readonly shuffled: boolean,
+    /** Largest domain that is enumerated instead of sampled. */
+    readonly limit: number
+  ) {}
+
+  /**
+   * Always sample (the default).
+   */
+  static off(): Enumeration {
+    return new Enumeration(false, false, 0);
+  }
+
+  /**
+   * Enumerate domains of up to `limit` values, simplest value first.
+   */
+  static ordered(limit: number = 1000): Enumeration {
+    return new Enumeration(true, false, Enumeration.checkLimit(limit));
+  }
+
+  /**
+   * Enumerate domains of up to `limit` values in random order.
+   */
+  static shuffled(limit: number = 1000): Enumeration {
+    return new Enumeration(true, true, Enumeration.checkLimit(limit));
+  }
+
+  private static checkLimit(limit: number): number {
+    if (!Number.isInteger(limit) || limit < 1) {
+      throw new Error('Enumeration limit must be a positive integer');
+    }
+    return limit;
+  }
+}
+
 /**
  * Configuration for property testing.
  */
@@ -9,11 +60,19 @@ export class Config {
           shrinkLimit?: number;
           sizeLimit?: number;
           discardLimit?: number;
+          sizeProgression?: SizeProgression;
+          shrinkOrder?: ShrinkOrder;
+          enumeration?: Enumeration;
+          resultCache?: ResultCache;
         }
       | number,
     shrinkLimit: number = 1000,
     sizeLimit: number = 100,
-    discardLimit: number = 100
+    discardLimit: number = 100,
+    sizeProgression: SizeProgression = SizeProgression.linear(),
+    shrinkOrder: ShrinkOrder = ShrinkOrder.towardOrigin(),
+    enumeration: Enumeration = Enumeration.off(),
+    resultCache?: ResultCache
   ) {
     if (typeof configOrTestLimit === 'object' && configOrTestLimit !== null) {
       // Object-style constructor
@@ -21,12 +80,22 @@ export class Config {
       this.shrinkLimit = configOrTestLimit.shrinkLimit ?? 1000;
       this.sizeLimit = configOrTestLimit.sizeLimit ?? 100;
       this.discardLimit = configOrTestLimit.discardLimit ?? 100;
+      this.sizeProgression =
+        configOrTestLimit.sizeProgression ?? SizeProgression.linear();
+      this.shrinkOrder =
+        configOrTestLimit.shrinkOrder ?? ShrinkOrder.towardOrigin();
+      this.enumeration = configOrTestLimit.enumeration ?? Enumeration.off();
+      this.resultCache = configOrTestLimit.resultCache;
     } else {
       // Parameter-style constructor
       this.testLimit = configOrTestLimit ?? 100;
       this.shrinkLimit = shrinkLimit;
       this.sizeLimit = sizeLimit;
       this.discardLimit = discardLimit;
+      this.sizeProgression = sizeProgression;
+      this.shrinkOrder = shrinkOrder;
+      this.enumeration = enumeration;
+      this.resultCache = resultCache;
     }
   }
 
@@ -38,6 +107,14 @@ export class Config {
   public readonly sizeLimit: number;
   /** Maximum number of discards before giving up (for filtered generators). */
   public readonly discardLimit: number;
+  /** How the size parameter evolves across test cases. */
+  public readonly sizeProgression: SizeProgression;
+  /** Order in which shrink candidates are tried. */
+  public readonly shrinkOrder: ShrinkOrder;
+  /** Whether small finite domains are enumerated instead of sampled. */
+  public readonly enumeration: Enumeration;
+  /** Cache of outcomes for repeated test cases, if any. */
+  public readonly resultCache: ResultCache | undefined;
 
   /**
    * Create the default configuration.
@@ -54,7 +131,11 @@ export class Config {
       tests,
       this.shrinkLimit,
       this.sizeLimit,
-      this.discardLimit
+      this.discardLimit,
+      this.sizeProgression,
+      this.shrinkOrder,
+      this.enumeration,
+      this.resultCache
     );
   }
 
@@ -66,7 +147,11 @@ export class Config {
       this.testLimit,
       shrinks,
       this.sizeLimit,
-      this.discardLimit
+      this.discardLimit,
+      this.sizeProgression,
+      this.shrinkOrder,
+      this.enumeration,
+      this.resultCache
     );
   }
 
@@ -78,7 +163,11 @@ export class Config {
       this.testLimit,
       this.shrinkLimit,
       size,
-      this.discardLimit
+      this.discardLimit,
+      this.sizeProgression,
+      this.shrinkOrder,
+      this.enumeration,
+      this.resultCache
     );
   }
 
@@ -90,7 +179,75 @@ export class Config {
       this.testLimit,
       this.shrinkLimit,
       this.sizeLimit,
-      discards
+      discards,
+      this.sizeProgression,
+      this.shrinkOrder,
+      this.enumeration,
+      this.resultCache
+    );
+  }
+
+  /**
+   * Create a new config with the given size progression.
+   */
+  withSizeProgression(progression: SizeProgression): Config {
+    return new Config(
+      this.testLimit,
+      this.shrinkLimit,
+      this.sizeLimit,
+      this.discardLimit,
+      progression,
+      this.shrinkOrder,
+      this.enumeration,
+      this.resultCache
+    );
+  }
+
+  /**
+   * Create a new config with the given shrink candidate ordering.
+   */
+  withShrinkOrder(order: ShrinkOrder): Config {
+    return new Config(
+      this.testLimit,
+      this.shrinkLimit,
+      this.sizeLimit,
+      this.discardLimit,
+      this.sizeProgression,
+      order,
+      this.enumeration,
+      this.resultCache
+    );
+  }
+
+  /**
+   * Create a new config with the given enumeration mode.
+   */
+  withEnumeration(enumeration: Enumeration): Config {
+    return new Config(
+      this.testLimit,
+      this.shrinkLimit,
+      this.sizeLimit,
+      this.discardLimit,
+      this.sizeProgression,
+      this.shrinkOrder,
+      enumeration,
+      this.resultCache
+    );
+  }
+
+  /**
+   * Create a new config that caches outcomes in the given result cache.
+   */
+  withResultCache(cache: ResultCache): Config {
+    return new Config(
+      this.testLimit,
+      this.shrinkLimit,
+      this.sizeLimit,
+      this.discardLimit,
+      this.sizeProgression,
+      this.shrinkOrder,
+      this.enumeration,
+      cache
     );
   }
 
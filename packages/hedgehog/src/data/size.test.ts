@@ -1,5 +1,11 @@
 import { describe, test, expect } from 'vitest';
-import { Size, Range, Distribution, Ranges } from './size.js';
+import {
+  Size,
+  SizeProgression,
+  Range,
+  Distribution,
+  Ranges,
+} from './size.js';
 
 describe('Size', () => {
   test('creates size with valid value', () => {
@@ -57,6 +63,26 @@ describe('Size', () => {
     expect(zeroSize.scale(2).get()).toBe(0);
     expect(zeroSize.golden().get()).toBe(0);
   });
+
+  test('divides size among weighted children', () => {
+    const shares = (size: number, weights: number[]) =>
+      Size.of(size)
+        .divide(weights)
+        .map((share) => share.get());
+
+    expect(shares(30, [1, 1, 1])).toEqual([10, 10, 10]);
+    expect(shares(10, [1, 1, 1])).toEqual([4, 3, 3]);
+    expect(shares(100, [3, 1])).toEqual([75, 25]);
+    expect(shares(7, [0, 1])).toEqual([0, 7]);
+    expect(shares(5, [])).toEqual([]);
+    expect(shares(0, [2, 1])).toEqual([0, 0]);
+  });
+
+  test('rejects invalid size weights', () => {
+    expect(() => Size.of(10).divide([1, -1])).toThrow('non-negative');
+    expect(() => Size.of(10).divide([1, NaN])).toThrow('non-negative');
+    expect(() => Size.of(10).divide([0, 0])).toThrow('all be zero');
+  });
 });
 
 describe('Range', () => {
@@ -192,3 +218,39 @@ describe('Ranges', () => {
     expect(pointRange.contains(41)).toBe(false);
   });
 });
+
+describe('SizeProgression', () => {
+  const sizes = (progression: SizeProgression, testLimit: number) =>
+    Array.from({ length: testLimit }, (_, testNum) =>
+      progression.sizeAt(testNum, testLimit, 100).get()
+    );
+
+  test('linear grows from 0 towards the size limit', () => {
+    expect(sizes(SizeProgression.linear(), 100)).toEqual(
+      Array.from({ length: 100 }, (_, i) => i)
+    );
+    expect(sizes(SizeProgression.linear(), 4)).toEqual([0, 25, 50, 75]);
+  });
+
+  test('constant uses the same size and respects the limit', () => {
+    expect(sizes(SizeProgression.constant(30), 3)).toEqual([30, 30, 30]);
+    expect(SizeProgression.constant(500).sizeAt(0, 10, 100).get()).toBe(100);
+    expect(() => SizeProgression.constant(-1)).toThrow(
+      'Size must be non-negative'
+    );
+  });
+
+  test('sawtooth restarts every period', () => {
+    expect(sizes(SizeProgression.sawtooth(4), 8)).toEqual([
+      0, 25, 50, 75, 0, 25, 50, 75,
+    ]);
+    expect(() => SizeProgression.sawtooth(0)).toThrow();
+  });
+
+  test('custom cycles through the given sizes', () => {
+    expect(sizes(SizeProgression.custom([5, 99, 200]), 5)).toEqual([
+      5, 99, 100, 5, 99,
+    ]);
+    expect(() => SizeProgression.custom([])).toThrow();
+  });
+});
@@ -40,11 +40,114 @@ export class Size {
     return new Size(Math.floor(this.value * 0.61803398875));
   }
 
+  /**
+   * Divide the size budget among children in proportion to `weights`, as
+   * Hedgehog does for nested structures, so that generating every child at
+   * its share keeps the total work linear in the size rather than
+   * exponential in the depth. Shares are whole and add up to the size,
+   * with leftover units going to the largest remainders.
+   */
+  divide(weights: readonly number[]): Size[] {
+    if (weights.some((weight) => !Number.isFinite(weight) || weight < 0)) {
+      throw new Error('Size weights must be finite and non-negative');
+    }
+    const total = weights.reduce((sum, weight) => sum + weight, 0);
+    if (weights.length > 0 && total <= 0) {
+      throw new Error('Size weights must not all be zero');
+    }
+
+    const exact = weights.map((weight) => (this.value * weight) / total);
+    const shares = exact.map(Math.floor);
+    let leftover = Math.floor(this.value) - shares.reduce((a, b) => a + b, 0);
+    const byRemainder = exact
+      .map((share, index) => ({ index, remainder: share - shares[index] }))
+      .sort((a, b) => b.remainder - a.remainder || a.index - b.index);
+    for (const { index } of byRemainder) {
+      if (leftover <= 0) {
+        break;
+      }
+      shares[index] += 1;
+      leftover -= 1;
+    }
+    return shares.map((share) => new Size(share));
+  }
+
   toString(): string {
     return `Size(${this.value})`;
   }
 }
 
+/**
+ * How the size parameter evolves across the test cases of a property run.
+ */
+export class SizeProgression {
+  private constructor(
+    private readonly sizeFor: (
+      testNum: number,
+      testLimit: number,
+      sizeLimit: number
+    ) => number
+  ) {}
+
+  /**
+   * Grow linearly from 0 towards the size limit over the run (the default).
+   */
+  static linear(): SizeProgression {
+    return new SizeProgression((testNum, testLimit, sizeLimit) =>
+      Math.floor((testNum * sizeLimit) / testLimit)
+    );
+  }
+
+  /**
+   * Use the same size for every test case.
+   */
+  static constant(size: number): SizeProgression {
+    if (size < 0) {
+      throw new Error('Size must be non-negative');
+    }
+    return new SizeProgression(() => size);
+  }
+
+  /**
+   * Ramp from 0 towards the size limit every `period` test cases, so small
+   * and large sizes are both revisited throughout the run.
+   */
+  static sawtooth(period: number): SizeProgression {
+    if (!Number.isInteger(period) || period < 1) {
+      throw new Error('Sawtooth period must be a positive integer');
+    }
+    return new SizeProgression((testNum, _testLimit, sizeLimit) =>
+      Math.floor(((testNum % period) * sizeLimit) / period)
+    );
+  }
+
+  /**
+   * Cycle through an explicit list of sizes.
+   */
+  static custom(sizes: readonly number[]): SizeProgression {
+    if (sizes.length === 0) {
+      throw new Error('Custom size progression needs at least one size');
+    }
+    if (sizes.some((size) => size < 0)) {
+      throw new Error('Size must be non-negative');
+    }
+    const copy = [...sizes];
+    return new SizeProgression((testNum) => copy[testNum % copy.length]);
+  }
+
+  /**
+   * Size for test case `testNum` of `testLimit`, capped at `sizeLimit`.
+   */
+  sizeAt(testNum: number, testLimit: number, sizeLimit: number): Size {
+    return Size.of(
+      Math.min(
+        sizeLimit,
+        Math.floor(this.sizeFor(testNum, testLimit, sizeLimit))
+      )
+    );
+  }
+}
+
 /**
  * Distribution shapes for value generation within ranges.
  */
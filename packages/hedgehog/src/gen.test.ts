@@ -1,7 +1,7 @@
 import { describe, test, expect } from 'vitest';
 import { Gen } from './gen.js';
 import { Tree } from './data/tree.js';
-import { Size } from './data/size.js';
+import { Size, Range } from './data/size.js';
 import { Seed } from './data/seed.js';
 
 describe('Gen', () => {
@@ -291,3 +291,44 @@ describe('Gen', () => {
     // 'light' might not appear in small sample due to low probability
   });
 });
+
+describe('Gen.enumerate', () => {
+  test('enumerates finite primitive domains', () => {
+    expect(Gen.bool().enumerate(10)).toEqual([false, true]);
+    expect(Gen.enum(['a', 'b', 'c'] as const).enumerate(10)).toEqual([
+      'a',
+      'b',
+      'c',
+    ]);
+    expect(Gen.int(Range.uniform(-2, 2)).enumerate(10)).toEqual([
+      0, -1, 1, -2, 2,
+    ]);
+  });
+
+  test('respects the limit and unknown domains', () => {
+    expect(Gen.int(Range.uniform(0, 100)).enumerate(10)).toBeUndefined();
+    expect(Gen.string().enumerate(10)).toBeUndefined();
+  });
+
+  test('enumerates ranges without building them up front', () => {
+    expect(Gen.range(3, 6).enumerate(10)).toEqual([3, 4, 5, 6]);
+    const large = Gen.range(0, 1e9);
+    expect(large.enumerate(10)).toBeUndefined();
+    const { value } = large.generate(Size.of(10), Seed.fromNumber(1));
+    expect(Number.isInteger(value) && value >= 0 && value <= 1e9).toBe(true);
+  });
+
+  test('combines domains through map, filter and tuple', () => {
+    const evens = Gen.int(Range.uniform(0, 5))
+      .filter((n) => n % 2 === 0)
+      .map((n) => n * 10);
+    expect(evens.enumerate(10)).toEqual([0, 20, 40]);
+    expect(Gen.tuple(Gen.bool(), Gen.bool()).enumerate(10)).toEqual([
+      [false, false],
+      [false, true],
+      [true, false],
+      [true, true],
+    ]);
+    expect(Gen.tuple(Gen.bool(), Gen.bool()).enumerate(3)).toBeUndefined();
+  });
+});
@@ -26,6 +26,7 @@ import {
   object,
   tuple,
   ArrayOptions,
+  ObjectOptions,
 } from './gen/collection.js';
 import {
   optional,
@@ -54,21 +55,104 @@ export interface DateOptions {
   max?: Date;
 }
 
+/**
+ * Enumerates a finite domain of at most `limit` values, or returns
+ * undefined when the domain is larger.
+ */
+type Domain<T> = (limit: number) => readonly T[] | undefined;
+
+/**
+ * Domain of a fixed list of values.
+ */
+function finiteDomain<T>(values: readonly T[]): Domain<T> {
+  const copy = [...values];
+  return (limit) => (copy.length <= limit ? copy : undefined);
+}
+
+/**
+ * Domain of an integer range, ordered by distance from the origin so the
+ * simplest values come first.
+ */
+function intDomain(range: Range<number>): Domain<number> {
+  return (limit) => {
+    const count = Math.max(1, range.max - range.min + 1);
+    if (count > limit) {
+      return undefined;
+    }
+    const origin = Math.max(range.min, Math.min(range.max, range.origin ?? 0));
+    const values: number[] = [];
+    for (let offset = 0; offset < count; offset++) {
+      values.push(range.min + offset);
+    }
+    return values.sort(
+      (a, b) => Math.abs(a - origin) - Math.abs(b - origin) || a - b
+    );
+  };
+}
+
+/**
+ * Domain of the `count` consecutive numbers from `start`, in ascending
+ * order.
+ */
+function stepDomain(start: number, count: number): Domain<number> {
+  return (limit) =>
+    count <= limit
+      ? Array.from({ length: count }, (_, index) => start + index)
+      : undefined;
+}
+
+/**
+ * Cartesian product of the element domains, when every element has one.
+ */
+function tupleDomain<T extends readonly unknown[]>(
+  generators: readonly Gen<unknown>[]
+): Domain<T> {
+  return (limit) => {
+    let product: unknown[][] = [[]];
+    for (const gen of generators) {
+      const values = gen.enumerate(limit);
+      if (values === undefined || product.length * values.length > limit) {
+        return undefined;
+      }
+      product = product.flatMap((prefix) =>
+        values.map((value) => [...prefix, value])
+      );
+    }
+    return product as unknown as T[];
+  };
+}
+
 /**
  * Main Generator class - the public interface for property-based testing.
  */
 export class Gen<T> {
-  constructor(public readonly generator: GeneratorFn<T>) {}
+  constructor(
+    public readonly generator: GeneratorFn<T>,
+    private readonly domain?: Domain<T>
+  ) {}
 
   generate(size: Size, seed: Seed): Tree<T> {
     return this.generator(size, seed);
   }
 
+  /**
+   * Every value this generator can produce, simplest first, when the
+   * domain is finite and has at most `limit` values. Returns undefined for
+   * generators with unknown or larger domains.
+   */
+  enumerate(limit: number): readonly T[] | undefined {
+    return this.domain?.(limit);
+  }
+
   map<U>(fn: (value: T) => U): Gen<U> {
-    return Gen.create((size, seed) => {
-      const tree = this.generate(size, seed);
-      return tree.map(fn);
-    });
+    const domain = this.domain;
+    return new Gen(
+      (size, seed) => {
+        const tree = this.generate(size, seed);
+        return tree.map(fn);
+      },
+      domain && ((limit) => domain(limit)?.map(fn))
+    );
   }
 
   chain<U>(fn: (value: T) => Gen<U>): Gen<U> {
@@ -84,7 +168,8 @@ export class Gen<T> {
   }
 
   filter(predicate: (value: T) => boolean, maxRetries = 100): Gen<T> {
-    return Gen.create((size, seed) => {
+    const domain = this.domain;
+    return new Gen<T>((size, seed) => {
       let currentSeed = seed;
 
       for (let i = 0; i < maxRetries; i++) {
@@ -104,7 +189,7 @@ export class Gen<T> {
       throw new Error(
         `Failed to generate value satisfying predicate after ${maxRetries} attempts`
       );
-    });
+    }, domain && ((limit) => domain(limit)?.filter(predicate)));
   }
 
   resize(fn: (size: Size) => Size): Gen<T> {
@@ -158,7 +243,7 @@ export class Gen<T> {
   }
 
   static constant<T>(value: T): Gen<T> {
-    return new Gen(generators.constant(value));
+    return new Gen(generators.constant(value), finiteDomain([value]));
   }
 
   static oneOf<T>(
@@ -196,12 +281,12 @@ export class Gen<T> {
   // Basic primitive generators
   static bool(): Gen<boolean> {
     const generatorFn = bool();
-    return new Gen(generatorFn);
+    return new Gen(generatorFn, finiteDomain([false, true]));
   }
 
   static int(range: Range<number>): Gen<number> {
     const generatorFn = int(range);
-    return new Gen(generatorFn);
+    return new Gen(generatorFn, intDomain(range));
   }
 
   static string(...args: any[]): Gen<string> {
@@ -244,12 +329,12 @@ export class Gen<T> {
     values: T
   ): Gen<T[number]> {
     const generatorFn = enumValue(values);
-    return new Gen(generatorFn);
+    return new Gen(generatorFn, finiteDomain(values));
   }
 
   static literal<T extends string | number | boolean>(value: T): Gen<T> {
     const generatorFn = literal(value);
-    return new Gen(generatorFn);
+    return new Gen(generatorFn, finiteDomain([value]));
   }
 
   // Zod schema integration (moved to separate package)
@@ -263,7 +348,7 @@ export class Gen<T> {
   }
 
   // Collection generators
-  static array<T>(gen: Gen<T>, options?: ArrayOptions): Gen<T[]> {
+  static array<T>(gen: Gen<T>, options?: ArrayOptions<T>): Gen<T[]> {
     const generatorFn = array(gen.generator, options);
     return new Gen(generatorFn);
   }
@@ -273,14 +358,17 @@ export class Gen<T> {
     return new Gen(generatorFn);
   }
 
-  static object<T extends Record<string, unknown>>(generators: {
-    [K in keyof T]: Gen<T[K]>;
-  }): Gen<T> {
+  static object<T extends Record<string, unknown>>(
+    generators: {
+      [K in keyof T]: Gen<T[K]>;
+    },
+    options?: ObjectOptions<T>
+  ): Gen<T> {
     const generatorFns = {} as { [K in keyof T]: GeneratorFn<T[K]> };
     for (const key in generators) {
       generatorFns[key] = generators[key].generator;
     }
-    const generatorFn = object(generatorFns);
+    const generatorFn = object(generatorFns, options);
     return new Gen(generatorFn);
   }
 
@@ -291,7 +379,7 @@ export class Gen<T> {
       [K in keyof T]: GeneratorFn<T[K]>;
     };
     const generatorFn = tuple<T>(...generatorFns);
-    return new Gen(generatorFn);
+    return new Gen(generatorFn, tupleDomain<T>(generators));
   }
 
   // Union generators
@@ -340,7 +428,7 @@ export class Gen<T> {
 
   // Additional utility methods for state machine testing
   static pure<T>(value: T): Gen<T> {
-    return new Gen(() => Tree.singleton(value));
+    return new Gen(() => Tree.singleton(value), finiteDomain([value]));
   }
 
   static delay<T>(fn: () => Gen<T>): Gen<T> {
@@ -351,21 +439,18 @@ export class Gen<T> {
     if (items.length === 0) {
       throw new Error('item requires at least one item');
     }
-    return Gen.create((_, seed) => {
+    return new Gen((_, seed) => {
       const [index] = seed.nextBounded(items.length);
       return Tree.singleton(items[index]);
-    });
+    }, finiteDomain(items));
   }
 
   static range(start: number, end: number): Gen<number> {
-    return Gen.create((_, seed) => {
-      const values: number[] = [];
-      for (let i = start; i <= end; i++) {
-        values.push(i);
-      }
-      const [index] = seed.nextBounded(values.length);
-      return Tree.singleton(values[index]);
-    });
+    const count = end >= start ? Math.floor(end - start) + 1 : 0;
+    return new Gen((_, seed) => {
+      const [index] = seed.nextBounded(count);
+      return Tree.singleton(start + index);
+    }, stepDomain(start, count));
   }
 
   static foldM<A, B>(
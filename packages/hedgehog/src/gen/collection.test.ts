@@ -65,6 +65,40 @@ describe('Collection generators', () => {
       expect(elementShrinks.length).toBeGreaterThan(0);
     });
 
+    it('drops the most expensive elements first when given costs', () => {
+      const gen = Gen.array(Gen.int(Range.uniform(1, 100)), {
+        length: 4,
+        elementCost: (x) => x,
+      });
+      const tree = gen.generate(size, seed);
+      const elements = tree.value;
+      const shrinks = tree.shrinks();
+
+      // The first shrinks each drop one element, in descending cost
+      const dropped = shrinks
+        .slice(0, elements.length)
+        .map((shrink) => elements.find((x, i) => shrink[i] !== x));
+      const sorted = [...elements].sort((a, b) => b - a);
+      expect(shrinks.slice(0, 4).every((s) => s.length === 3)).toBe(true);
+      expect(dropped).toEqual(sorted);
+    });
+
+    it('divides the size among elements when asked', () => {
+      const sizeOf = Gen.sized((s) => Gen.constant(s.get()));
+      const divided = Gen.array(sizeOf, { length: 3, divideSize: true });
+      const full = Gen.array(sizeOf, { length: 3 });
+
+      expect(divided.generate(size, seed).value).toEqual([4, 3, 3]);
+      expect(full.generate(size, seed).value).toEqual([10, 10, 10]);
+    });
+
+    it('keeps the default shrinks without costs', () => {
+      const gen = Gen.array(Gen.int(Range.uniform(1, 100)), { length: 4 });
+      const tree = gen.generate(size, seed);
+
+      expect(tree.shrinks()[0]).toEqual([]);
+    });
+
     it('works with Gen.array() static method', () => {
       const gen = Gen.array(Gen.string(), { maxLength: 5 });
       const tree = gen.generate(size, seed);
@@ -181,6 +215,20 @@ describe('Collection generators', () => {
       expect(tree.value).toHaveProperty('x');
       expect(tree.value).toHaveProperty('y');
     });
+
+    it('divides the size among properties by weight', () => {
+      const sizeOf = Gen.sized((s) => Gen.constant(s.get()));
+      const gen = Gen.object(
+        { items: sizeOf, name: sizeOf, id: sizeOf },
+        { divideSize: true, sizeWeights: { items: 3 } }
+      );
+
+      expect(gen.generate(size, seed).value).toEqual({
+        items: 6,
+        name: 2,
+        id: 2,
+      });
+    });
   });
 
   describe('Gen.tuple()', () => {
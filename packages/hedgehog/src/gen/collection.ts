@@ -1,15 +1,39 @@
 import { GeneratorFn, create, sized, buildCollectionShrinks } from './core.js';
 import { Tree } from '../data/tree.js';
-import { shrinkBuilder } from './shrink.js';
+import { costlyRemovals, shrinkBuilder } from './shrink.js';
 
 /**
  * Collection generators for arrays, objects, and tuples.
  */
 
-export interface ArrayOptions {
+export interface ArrayOptions<T = unknown> {
   minLength?: number;
   maxLength?: number;
   length?: number;
+  /**
+   * Cost of keeping an element, e.g. its serialized size or the work the
+   * property does with it. When given, shrinking first tries dropping
+   * single elements, most expensive first, before shortening the array.
+   */
+  elementCost?: (element: T) => number;
+  /**
+   * Generate elements at an equal share of the size (see `Size.divide`)
+   * instead of the full size, so nested collections stay small overall.
+   */
+  divideSize?: boolean;
+}
+
+export interface ObjectOptions<T> {
+  /**
+   * Generate properties at a share of the size (see `Size.divide`) instead
+   * of the full size, so nested records stay small overall.
+   */
+  divideSize?: boolean;
+  /**
+   * Relative share of the size per property when dividing; unlisted
+   * properties weigh 1.
+   */
+  sizeWeights?: { [K in keyof T]?: number };
 }
 
 /**
@@ -17,12 +41,18 @@ export interface ArrayOptions {
  */
 export function array<T>(
   elementGen: GeneratorFn<T>,
-  options: ArrayOptions = {}
+  options: ArrayOptions<T> = {}
 ): GeneratorFn<T[]> {
-  const { minLength = 0, maxLength, length } = options;
+  const {
+    minLength = 0,
+    maxLength,
+    length,
+    elementCost,
+    divideSize = false,
+  } = options;
 
   if (length !== undefined) {
-    return arrayOfLength(elementGen, length);
+    return arrayOfLength(elementGen, length, elementCost, divideSize);
   }
 
   return sized((size) => {
@@ -35,17 +65,26 @@ export function array<T>(
         finalMaxLength - minLength + 1
       );
       const actualLength = minLength + length;
-      return arrayOfLength(elementGen, actualLength)(_size, newSeed);
+      return arrayOfLength(
+        elementGen,
+        actualLength,
+        elementCost,
+        divideSize
+      )(_size, newSeed);
     });
   });
 }
 
 /**
- * Generate arrays of exactly the specified length.
+ * Generate arrays of exactly the specified length. With `elementCost`,
+ * shrinking first tries dropping the most expensive elements; with
+ * `divideSize`, elements share the size equally.
  */
 export function arrayOfLength<T>(
   elementGen: GeneratorFn<T>,
-  length: number
+  length: number,
+  elementCost?: (element: T) => number,
+  divideSize = false
 ): GeneratorFn<T[]> {
   return create((size, seed) => {
     if (length === 0) {
@@ -54,12 +93,15 @@ export function arrayOfLength<T>(
 
     const elements: T[] = [];
     const elementTrees: Tree<T>[] = [];
+    const sizes = divideSize
+      ? size.divide(new Array<number>(length).fill(1))
+      : undefined;
     let currentSeed = seed;
 
     // Generate all elements
     for (let i = 0; i < length; i++) {
       const [seed1, seed2] = currentSeed.split();
-      const tree = elementGen(size, seed1);
+      const tree = elementGen(sizes?.[i] ?? size, seed1);
       elements.push(tree.value);
       elementTrees.push(tree);
       currentSeed = seed2;
@@ -67,6 +109,11 @@ export function arrayOfLength<T>(
 
     const builder = shrinkBuilder<T[]>();
 
+    // Drop expensive elements first when costs are supplied
+    if (elementCost !== undefined) {
+      builder.addAll(costlyRemovals(elements, elementCost));
+    }
+
     // Shrink by reducing length
     for (let newLength = 0; newLength < length; newLength++) {
       const shorterArray = elements.slice(0, newLength);
@@ -92,11 +139,17 @@ export function arrayOfLength<T>(
 }
 
 /**
- * Generate objects with typed properties.
+ * Generate objects with typed properties. With `divideSize`, properties
+ * share the size according to `sizeWeights`.
  */
-export function object<T extends Record<string, unknown>>(schema: {
-  [K in keyof T]: GeneratorFn<T[K]>;
-}): GeneratorFn<T> {
+export function object<T extends Record<string, unknown>>(
+  schema: {
+    [K in keyof T]: GeneratorFn<T[K]>;
+  },
+  options: ObjectOptions<T> = {}
+): GeneratorFn<T> {
+  const { divideSize = false, sizeWeights = {} } = options;
+
   return create((size, seed) => {
     const keys = Object.keys(schema) as Array<keyof T>;
     if (keys.length === 0) {
@@ -105,13 +158,16 @@ export function object<T extends Record<string, unknown>>(schema: {
 
     const values: Partial<T> = {};
     const valueTrees = {} as Record<keyof T, Tree<T[keyof T]>>;
+    const sizes = divideSize
+      ? size.divide(keys.map((key) => sizeWeights[key] ?? 1))
+      : undefined;
     let currentSeed = seed;
 
     // Generate all property values
-    for (const key of keys) {
+    for (const [index, key] of keys.entries()) {
       const [seed1, seed2] = currentSeed.split();
       const gen = schema[key];
-      const tree = gen(size, seed1);
+      const tree = gen(sizes?.[index] ?? size, seed1);
       values[key] = tree.value;
       valueTrees[key] = tree;
       currentSeed = seed2;
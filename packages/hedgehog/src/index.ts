@@ -1,5 +1,11 @@
 export { Seed } from './data/seed.js';
-export { Size, Range, Distribution, Ranges } from './data/size.js';
+export {
+  Size,
+  SizeProgression,
+  Range,
+  Distribution,
+  Ranges,
+} from './data/size.js';
 export { Tree } from './data/tree.js';
 export {
   renderTree,
@@ -13,9 +19,12 @@ export {
   NumberOptions,
   DateOptions,
   ArrayOptions,
+  ObjectOptions,
 } from './gen.js';
 export { Property, forAll, forAllNamed } from './property.js';
-export { Config } from './config.js';
+export { Config, Enumeration } from './config.js';
+export { ShrinkOrder } from './gen/shrink.js';
+export { ResultCache, ResultCacheOptions } from './result-cache.js';
 export {
   TestResult,
   TestCase,
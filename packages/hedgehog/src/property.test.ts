@@ -2,9 +2,11 @@ import { describe, test, expect } from 'vitest';
 import { forAll } from './property.js';
 import { Gen, Ints } from './gen.js';
 import { Tree } from './data/tree.js';
-import { Config } from './config.js';
+import { Config, Enumeration } from './config.js';
 import { Seed } from './data/seed.js';
 import { Range } from './data/size.js';
+import { ShrinkOrder } from './gen/shrink.js';
+import { ResultCache } from './result-cache.js';
 
 describe('Property testing', () => {
   test('simple passing property', () => {
@@ -165,6 +167,87 @@ describe('Property testing', () => {
     }
   });
 
+  test('shrink order controls which failing candidate is followed', () => {
+    // Both children fail; the default order follows the first one
+    const gen = Gen.create(() =>
+      Tree.withChildren(10, [Tree.singleton(8), Tree.singleton(3)])
+    );
+    const prop = forAll(gen, (n) => n < 2);
+
+    const byDefault = prop.run(
+      Config.default().withTests(1),
+      Seed.fromNumber(1)
+    );
+    const byCost = prop.run(
+      Config.default()
+        .withTests(1)
+        .withShrinkOrder(ShrinkOrder.byCost((n: number) => n)),
+      Seed.fromNumber(1)
+    );
+
+    expect(byDefault.type).toBe('fail');
+    expect(byCost.type).toBe('fail');
+    if (byDefault.type === 'fail' && byCost.type === 'fail') {
+      expect(byDefault.counterexample.value).toBe(8);
+      expect(byCost.counterexample.value).toBe(3);
+    }
+  });
+
+  test('result cache skips cases already evaluated on a rerun', () => {
+    let calls = 0;
+    const prop = forAll(Ints.range(0, 100), (x) => {
+      calls++;
+      return x >= 0;
+    });
+    const config = Config.default()
+      .withTests(20)
+      .withResultCache(new ResultCache());
+
+    expect(prop.run(config, Seed.fromNumber(7)).type).toBe('pass');
+    expect(calls).toBe(20);
+    expect(prop.run(config, Seed.fromNumber(7)).type).toBe('pass');
+    expect(calls).toBe(20);
+    expect(config.resultCache?.hits).toBe(20);
+  });
+
+  test('result cache evaluates repeated shrink candidates once', () => {
+    const gen = Gen.create(() =>
+      Tree.withChildren(10, [
+        Tree.singleton(2),
+        Tree.withChildren(8, [Tree.singleton(2), Tree.singleton(6)]),
+      ])
+    );
+    const seen: number[] = [];
+    const prop = forAll(gen, (n) => {
+      seen.push(n);
+      return n < 5;
+    });
+    const result = prop.run(
+      Config.default().withTests(1).withResultCache(new ResultCache()),
+      Seed.fromNumber(1)
+    );
+
+    expect(result.type).toBe('fail');
+    if (result.type === 'fail') {
+      expect(result.counterexample.value).toBe(6);
+    }
+    expect(seen).toEqual([10, 2, 8, 6]);
+  });
+
+  test('structural shrink order tries smaller structures first', () => {
+    const candidates = [
+      Tree.singleton([[1, 2], [3]]),
+      Tree.singleton([1]),
+      Tree.singleton('ab'),
+      Tree.singleton({ a: [1, 2, 3] }),
+    ];
+    const ordered = ShrinkOrder.structural()
+      .order(candidates)
+      .map((tree) => tree.value);
+    expect(ordered).toEqual([[1], 'ab', { a: [1, 2, 3] }, [[1, 2], [3]]]);
+    expect(ShrinkOrder.towardOrigin().order(candidates)).toBe(candidates);
+  });
+
   test('property shrinking with no shrinkable generators', () => {
     // Generator that produces no shrinks
     const noShrinkGen = Gen.create(() => Tree.singleton(42));
@@ -201,4 +284,70 @@ describe('Property testing', () => {
     expect(result2.type).toBe('pass');
     expect(result1.stats.testsRun).toBe(result2.stats.testsRun);
   });
+
+  test('enumeration tests every value of a small domain', () => {
+    const seen = new Set<number>();
+    const prop = forAll(Gen.int(Range.uniform(0, 9)), (n) => {
+      seen.add(n);
+      return n < 10;
+    });
+    const result = prop.run(
+      Config.default().withTests(3).withEnumeration(Enumeration.ordered()),
+      Seed.fromNumber(1)
+    );
+
+    expect(result.type).toBe('pass');
+    if (result.type === 'pass') {
+      expect(result.exhaustive).toBe(true);
+      expect(result.stats.testsRun).toBe(10);
+    }
+    expect(seen.size).toBe(10);
+  });
+
+  test('shuffled enumeration still finds the simplest counterexample', () => {
+    const prop = forAll(Gen.int(Range.uniform(0, 50)), (n) => n < 30);
+    const result = prop.run(
+      Config.default().withEnumeration(Enumeration.shuffled()),
+      Seed.fromNumber(7)
+    );
+
+    expect(result.type).toBe('fail');
+    if (result.type === 'fail') {
+      expect(result.counterexample.value).toBe(30);
+    }
+  });
+
+  test('enumeration reports domain indices rather than seeds', () => {
+    // The domain of [0, 50] is ordered from the origin, so index = value
+    const prop = forAll(Gen.int(Range.uniform(0, 50)), (n) => n < 30);
+    const config = Config.default().withEnumeration(Enumeration.shuffled());
+    const result = prop.run(config, Seed.fromNumber(7));
+
+    expect(result.type).toBe('fail');
+    if (result.type === 'fail') {
+      expect(result.originalFailure.domainIndex).toBe(
+        result.originalFailure.value
+      );
+      expect(result.counterexample.domainIndex).toBe(30);
+    }
+    expect(() => prop.check(config, Seed.fromNumber(7))).toThrow(
+      'domain index: 30'
+    );
+  });
+
+  test('enumeration falls back to sampling for large domains', () => {
+    const prop = forAll(Gen.int(Range.uniform(0, 100000)), (n) => n >= 0);
+    const result = prop.run(
+      Config.default()
+        .withTests(20)
+        .withEnumeration(Enumeration.ordered(100)),
+      Seed.fromNumber(1)
+    );
+
+    expect(result.type).toBe('pass');
+    if (result.type === 'pass') {
+      expect(result.exhaustive).toBe(false);
+      expect(result.stats.testsRun).toBe(20);
+    }
+  });
 });
@@ -186,14 +186,29 @@ function runProperty<T>(
     stats = addTest(stats);
   }
 
+  // Enumerate small finite domains instead of sampling them
+  if (config.enumeration.enabled) {
+    const domain = generator.enumerate(config.enumeration.limit);
+    if (domain !== undefined) {
+      return runEnumeration(
+        domain,
+        predicate,
+        labels,
+        config,
+        currentSeed,
+        stats
+      );
+    }
+  }
+
   // Main test loop
   for (let testNum = 0; testNum < config.testLimit; testNum++) {
-    // Calculate size for this test (grows linearly with test number)
-    const sizeValue = Math.min(
-      config.sizeLimit,
-      Math.floor((testNum * config.sizeLimit) / config.testLimit)
+    // Calculate size for this test from the configured progression
+    const size = config.sizeProgression.sizeAt(
+      testNum,
+      config.testLimit,
+      config.sizeLimit
     );
-    const size = Size.of(sizeValue);
 
     // Split seed for this test
     const [testSeed, nextSeed] = currentSeed.split();
@@ -216,8 +231,12 @@ function runProperty<T>(
         }
       }
 
-      // Test the predicate
-      const passed = predicate(tree.value);
+      // Test the predicate, unless this seed and size were already tested
+      const passed = config.resultCache
+        ? config.resultCache.forSeed(testSeed, size, () =>
+            predicate(tree.value)
+          )
+        : predicate(tree.value);
 
       if (passed) {
         // Test passed, continue
@@ -251,6 +270,88 @@ function runProperty<T>(
   return passResult(stats);
 }
 
+/**
+ * Test every value of a finite domain once. A failing value shrinks
+ * through the domain values that precede it, so the counterexample is the
+ * simplest failing value even when the domain was tested shuffled.
+ */
+function runEnumeration<T>(
+  domain: readonly T[],
+  predicate: (value: T) => boolean,
+  labels: Array<(value: T) => string | null>,
+  config: Config,
+  seed: Seed,
+  initialStats: TestStats
+): TestResult<T> {
+  let stats = initialStats;
+  let currentSeed = seed;
+  let discardCount = 0;
+
+  const order = domain.map((_, index) => index);
+  if (config.enumeration.shuffled) {
+    for (let i = order.length - 1; i > 0; i--) {
+      const [j, nextSeed] = currentSeed.nextBounded(i + 1);
+      currentSeed = nextSeed;
+      [order[i], order[j]] = [order[j], order[i]];
+    }
+  }
+
+  for (const index of order) {
+    const value = domain[index];
+    const [testSeed, nextSeed] = currentSeed.split();
+    currentSeed = nextSeed;
+
+    try {
+      for (const labelFn of labels) {
+        const label = labelFn(value);
+        if (label !== null) {
+          stats = addLabel(stats, label);
+        }
+      }
+
+      if (predicate(value)) {
+        stats = addTest(stats);
+      } else {
+        const testCase: TestCase<T> = {
+          value,
+          size: Size.of(0),
+          seed: testSeed,
+          domainIndex: index,
+        };
+        const tree = Tree.withChildren(
+          value,
+          domain.slice(0, index).map((simpler) => Tree.singleton(simpler))
+        );
+        const result = shrinkFailure(tree, predicate, config, testCase, stats);
+        if (result.type !== 'fail') {
+          return result;
+        }
+        // Shrinking picks earlier domain values, which the seed and size
+        // cannot regenerate, so report where the counterexample sits
+        return {
+          ...result,
+          counterexample: {
+            ...result.counterexample,
+            domainIndex: domain.indexOf(result.counterexample.value),
+          },
+        };
+      }
+    } catch (_error) {
+      discardCount++;
+      stats = addTest(stats, true);
+
+      if (discardCount >= config.discardLimit) {
+        return gaveUpResult(
+          stats,
+          `Too many discarded tests (${discardCount}/${config.discardLimit})`
+        );
+      }
+    }
+  }
+
+  return passResult(stats, discardCount === 0);
+}
+
 /**
  * Format a failing test result for display.
  */
@@ -278,8 +379,12 @@ function formatFailure<T>(
 
   lines.push('');
   lines.push('Reproduce with:');
-  lines.push(`  seed: ${result.counterexample.seed.toString()}`);
-  lines.push(`  size: ${result.counterexample.size.get()}`);
+  if (result.counterexample.domainIndex !== undefined) {
+    lines.push(`  domain index: ${result.counterexample.domainIndex}`);
+  } else {
+    lines.push(`  seed: ${result.counterexample.seed.toString()}`);
+    lines.push(`  size: ${result.counterexample.size.get()}`);
+  }
 
   if (result.stats.testsRun > 0) {
     lines.push('');
@@ -339,8 +444,13 @@ function shrinkFailure<T>(
   originalFailure: TestCase<T>,
   stats: TestStats
 ): TestResult<T> {
+  // Candidates reached along different shrink branches are evaluated once
+  const shrinkPredicate = config.resultCache
+    ? config.resultCache.memoize(predicate)
+    : predicate;
+
   // Find the minimal counterexample using depth-first traversal
-  const shrinkResult = shrinkToMinimal(failingTree, predicate, config);
+  const shrinkResult = shrinkToMinimal(failingTree, shrinkPredicate, config);
 
   const finalStats = addShrinks(stats, shrinkResult.steps);
 
@@ -378,8 +488,8 @@ function shrinkToMinimal<T>(
   steps: number = 0,
   path: TestCase<T>[] = []
 ): ShrinkResult<T> {
-  // Try each child in order (leftmost first)
-  for (const child of tree.children) {
+  // Try each child in the configured order
+  for (const child of config.shrinkOrder.order(tree.children)) {
     if (steps >= config.shrinkLimit) {
       break;
     }
@@ -0,0 +1,104 @@
+import { describe, test, expect } from 'vitest';
+import { ResultCache } from './result-cache.js';
+
+describe('ResultCache', () => {
+  test('memoizes structurally equal values', () => {
+    const cache = new ResultCache();
+    let calls = 0;
+    const predicate = cache.memoize((value: unknown) => {
+      calls++;
+      return value !== null;
+    });
+
+    predicate({ a: [1, 2], b: new Map([['k', 1n]]) });
+    predicate({ b: new Map([['k', 1n]]), a: [1, 2] });
+    expect(calls).toBe(1);
+
+    predicate({ a: [1, 2], b: new Map([['k', 2n]]) });
+    predicate(-0);
+    predicate(0);
+    predicate('1');
+    predicate(1);
+    expect(calls).toBe(6);
+    expect(cache.hits).toBe(1);
+    expect(cache.misses).toBe(6);
+  });
+
+  test('evicts least recently used entries beyond maxEntries', () => {
+    const cache = new ResultCache({ maxEntries: 2 });
+    let calls = 0;
+    const predicate = cache.memoize((n: number) => {
+      calls++;
+      return n > 0;
+    });
+
+    predicate(1);
+    predicate(2);
+    predicate(1); // 1 is now most recently used
+    predicate(3); // evicts 2
+    expect(cache.size).toBe(2);
+    expect(calls).toBe(3);
+
+    predicate(1);
+    expect(calls).toBe(3);
+    predicate(2);
+    expect(calls).toBe(4);
+  });
+
+  test('never caches values it cannot fingerprint', () => {
+    const cache = new ResultCache({ maxKeyLength: 8 });
+    let calls = 0;
+    const predicate = cache.memoize((_value: unknown) => {
+      calls++;
+      return true;
+    });
+    const cyclic: { self?: unknown } = {};
+    cyclic.self = cyclic;
+
+    for (let i = 0; i < 2; i++) {
+      predicate(cyclic);
+      predicate(() => 1);
+      predicate('a long string value');
+    }
+    expect(calls).toBe(6);
+    expect(cache.size).toBe(0);
+  });
+
+  test('never caches objects other than plain objects', () => {
+    const cache = new ResultCache();
+    const seen: unknown[] = [];
+    const predicate = cache.memoize((value: unknown) => {
+      seen.push(value);
+      return true;
+    });
+    class Point {
+      constructor(readonly x: number) {}
+    }
+
+    // Distinct RegExps have no enumerable keys to tell them apart
+    predicate(/a/);
+    predicate(/b/);
+    predicate(new Point(1));
+    predicate(new Point(1));
+    predicate(Object.assign(Object.create(null), { a: 1 }));
+    predicate(Object.assign(Object.create(null), { a: 1 }));
+    expect(seen).toHaveLength(5);
+    expect(cache.size).toBe(1);
+  });
+
+  test('clear forgets outcomes and statistics', () => {
+    const cache = new ResultCache();
+    const predicate = cache.memoize((n: number) => n > 0);
+    predicate(1);
+    predicate(1);
+    cache.clear();
+    expect(cache.size).toBe(0);
+    expect(cache.hits).toBe(0);
+    expect(cache.misses).toBe(0);
+  });
+
+  test('rejects invalid bounds', () => {
+    expect(() => new ResultCache({ maxEntries: 0 })).toThrow();
+    expect(() => new ResultCache({ maxKeyLength: 1.5 })).toThrow();
+  });
+});
@@ -0,0 +1,202 @@
+/**
+ * Cache of property outcomes, so identical test cases are not evaluated
+ * twice across shrink branches and reruns.
+ */
+
+import { Seed } from './data/seed.js';
+import { Size } from './data/size.js';
+
+/**
+ * Memory bounds for a result cache.
+ */
+export interface ResultCacheOptions {
+  /** Most outcomes kept; the least recently used are evicted first. */
+  maxEntries?: number;
+  /** Values whose fingerprint is longer than this are never cached. */
+  maxKeyLength?: number;
+}
+
+/**
+ * Bounded cache from test case fingerprints to pass/fail outcomes.
+ *
+ * Generated test cases are keyed by their seed and size, and shrink
+ * candidates by a structural fingerprint of their value. Outcomes are only
+ * meaningful for a single deterministic predicate, so use one cache per
+ * property.
+ */
+export class ResultCache {
+  private readonly entries = new Map<string, boolean>();
+  private hitCount = 0;
+  private missCount = 0;
+
+  /** Most outcomes kept. */
+  readonly maxEntries: number;
+  /** Longest value fingerprint that is cached. */
+  readonly maxKeyLength: number;
+
+  constructor(options: ResultCacheOptions = {}) {
+    this.maxEntries = options.maxEntries ?? 10000;
+    this.maxKeyLength = options.maxKeyLength ?? 1024;
+    if (!Number.isInteger(this.maxEntries) || this.maxEntries < 1) {
+      throw new Error('Result cache maxEntries must be a positive integer');
+    }
+    if (!Number.isInteger(this.maxKeyLength) || this.maxKeyLength < 1) {
+      throw new Error('Result cache maxKeyLength must be a positive integer');
+    }
+  }
+
+  /** Number of cached outcomes. */
+  get size(): number {
+    return this.entries.size;
+  }
+
+  /** Lookups answered from the cache. */
+  get hits(): number {
+    return this.hitCount;
+  }
+
+  /** Lookups that had to evaluate the predicate. */
+  get misses(): number {
+    return this.missCount;
+  }
+
+  /**
+   * Outcome of the test case generated from `seed` at `size`, evaluating
+   * `run` on a miss.
+   */
+  forSeed(seed: Seed, size: Size, run: () => boolean): boolean {
+    const key = `seed:${seed.state}:${seed.gamma}:${size.get()}`;
+    return this.lookup(key, run);
+  }
+
+  /**
+   * Wrap `predicate` so repeated values are answered from the cache.
+   * Values that cannot be fingerprinted (functions, symbols, cycles) or
+   * whose fingerprint is too long are always evaluated.
+   */
+  memoize<T>(predicate: (value: T) => boolean): (value: T) => boolean {
+    return (value) => {
+      const key = fingerprint(value);
+      if (key === undefined || key.length > this.maxKeyLength) {
+        return predicate(value);
+      }
+      return this.lookup(`value:${key}`, () => predicate(value));
+    };
+  }
+
+  /**
+   * Forget every cached outcome and reset the statistics.
+   */
+  clear(): void {
+    this.entries.clear();
+    this.hitCount = 0;
+    this.missCount = 0;
+  }
+
+  private lookup(key: string, run: () => boolean): boolean {
+    const cached = this.entries.get(key);
+    if (cached !== undefined) {
+      // Re-insert to mark as most recently used
+      this.entries.delete(key);
+      this.entries.set(key, cached);
+      this.hitCount++;
+      return cached;
+    }
+
+    this.missCount++;
+    const outcome = run();
+    this.entries.set(key, outcome);
+    if (this.entries.size > this.maxEntries) {
+      const oldest = this.entries.keys().next().value;
+      if (oldest !== undefined) {
+        this.entries.delete(oldest);
+      }
+    }
+    return outcome;
+  }
+}
+
+/**
+ * Whether `value` is an object literal or a null-prototype object.
+ */
+function isPlainObject(value: object): boolean {
+  const prototype: unknown = Object.getPrototypeOf(value);
+  return prototype === Object.prototype || prototype === null;
+}
+
+/**
+ * Structural fingerprint of a value: equal fingerprints mean structurally
+ * equal values. Returns undefined for values that cannot be fingerprinted,
+ * including objects other than arrays, maps, sets, dates and plain objects.
+ */
+function fingerprint(
+  value: unknown,
+  seen: Set<object> = new Set()
+): string | undefined {
+  switch (typeof value) {
+    case 'string':
+      return JSON.stringify(value);
+    case 'number':
+      return Object.is(value, -0) ? '-0' : String(value);
+    case 'bigint':
+      return `${value}n`;
+    case 'boolean':
+    case 'undefined':
+      return String(value);
+    case 'function':
+    case 'symbol':
+      return undefined;
+  }
+
+  if (value === null) {
+    return 'null';
+  }
+  if (value instanceof Date) {
+    return `Date(${value.getTime()})`;
+  }
+  if (seen.has(value as object)) {
+    return undefined;
+  }
+  seen.add(value as object);
+
+  const parts: string[] = [];
+  const add = (...items: unknown[]): boolean => {
+    for (const item of items) {
+      const part = fingerprint(item, seen);
+      if (part === undefined) {
+        return false;
+      }
+      parts.push(part);
+    }
+    return true;
+  };
+
+  let result: string | undefined;
+  if (Array.isArray(value)) {
+    result = value.every((item) => add(item))
+      ? `[${parts.join(',')}]`
+      : undefined;
+  } else if (value instanceof Map) {
+    result = [...value.entries()].every(([k, v]) => add(k, v))
+      ? `Map{${parts.join(',')}}`
+      : undefined;
+  } else if (value instanceof Set) {
+    result = [...value].every((item) => add(item))
+      ? `Set{${parts.join(',')}}`
+      : undefined;
+  } else if (!isPlainObject(value as object)) {
+    // RegExps, class instances and the like keep state that their own
+    // enumerable keys do not show, so distinct ones could share a key
+    result = undefined;
+  } else {
+    const record = value as Record<string, unknown>;
+    result = Object.keys(record)
+      .sort()
+      .every((key) => add(key, record[key]))
+      ? `{${parts.join(',')}}`
+      : undefined;
+  }
+
+  seen.delete(value as object);
+  return result;
+}
@@ -15,6 +15,11 @@ export interface TestCase<T> {
   readonly size: Size;
   /** The seed used for generation. */
   readonly seed: Seed;
+  /**
+   * Position of the value in the enumerated domain, when it came from
+   * enumeration rather than from the seed and size.
+   */
+  readonly domainIndex?: number;
 }
 
 /**
@@ -42,6 +47,8 @@ export type TestResult<T> = PassResult | FailResult<T> | GaveUpResult;
 export interface PassResult {
   readonly type: 'pass';
   readonly stats: TestStats;
+  /** Whether every value of the generator's domain was tested. */
+  readonly exhaustive: boolean;
 }
 
 /**
@@ -71,8 +78,11 @@ export interface GaveUpResult {
 /**
  * Create a successful test result.
  */
-export function passResult(stats: TestStats): PassResult {
-  return { type: 'pass', stats };
+export function passResult(
+  stats: TestStats,
+  exhaustive: boolean = false
+): PassResult {
+  return { type: 'pass', stats, exhaustive };
 }
 
 /**
@@ -161,6 +161,613 @@ describe('WASM Buffer API', () => {
 
       expect(result1.values).toEqual(result2.values);
     });
+
+    it('always gives false at probability 0 and true at probability 1', () => {
+      const never = seed.nextBoolsBulkBuffer(1000, 0);
+      const always = seed.nextBoolsBulkBuffer(1000, 1);
+
+      expect(never.values.every((value) => value === false)).toBe(true);
+      expect(always.values.every((value) => value === true)).toBe(true);
+    });
+
+    it('keeps the default stream without a probability', () => {
+      const testSeed = Seed.fromNumber(789);
+      const count = 100;
+
+      const result = testSeed.nextBoolsBulkBuffer(count);
+      // The default takes the low bit of each raw 64-bit output
+      const raw = testSeed.nextUint64sBulkBuffer(count);
+
+      expect(result.values).toEqual(
+        raw.values.map((value) => (value & 1n) === 1n)
+      );
+      expect(testSeed.nextBoolsBulkBuffer(count, undefined).values).toEqual(
+        result.values
+      );
+    });
+
+    it('rejects probabilities outside [0, 1]', () => {
+      expect(() => {
+        seed.nextBoolsBulkBuffer(10, -0.1);
+      }).toThrow(/Boolean buffer operation failed/);
+    });
+  });
+
+  describe('nextUint64sBulkBuffer()', () => {
+    it('generates bounded 64-bit integers', () => {
+      const bound = 1000n;
+      const count = 1000;
+      const result = seed.nextUint64sBulkBuffer(count, bound);
+
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(typeof value).toBe('bigint');
+        expect(value).toBeGreaterThanOrEqual(0n);
+        expect(value).toBeLessThan(bound);
+      });
+    });
+
+    it('handles bounds beyond 2^53 and the full range', () => {
+      const bound = 2n ** 63n + 1n;
+      const bounded = seed.nextUint64sBulkBuffer(100, bound);
+      bounded.values.forEach((value) => {
+        expect(value).toBeGreaterThanOrEqual(0n);
+        expect(value).toBeLessThan(bound);
+      });
+
+      const full = seed.nextUint64sBulkBuffer(100);
+      full.values.forEach((value) => {
+        expect(value).toBeGreaterThanOrEqual(0n);
+        expect(value).toBeLessThan(2n ** 64n);
+      });
+      // Values above 2^53 are not rounded through number
+      expect(full.values.some((value) => value > 2n ** 53n)).toBe(true);
+    });
+
+    it('produces different values with different seeds', () => {
+      const result1 = Seed.fromNumber(1).nextUint64sBulkBuffer(50);
+      const result2 = Seed.fromNumber(2).nextUint64sBulkBuffer(50);
+
+      expect(result1.values).not.toEqual(result2.values);
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(123);
+
+      const result1 = testSeed.nextUint64sBulkBuffer(50, 100n);
+      const result2 = testSeed.nextUint64sBulkBuffer(50, 100n);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+
+    it('rejects a zero bound', () => {
+      expect(() => {
+        seed.nextUint64sBulkBuffer(10, 0n);
+      }).toThrow(/Uint64 buffer operation failed/);
+    });
+  });
+
+  describe('nextInt32sBulkBuffer()', () => {
+    it('generates values within an inclusive range, both ends included', () => {
+      const count = 2000;
+      const result = seed.nextInt32sBulkBuffer(count, { min: -10, max: 10 });
+
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(Number.isInteger(value)).toBe(true);
+        expect(value).toBeGreaterThanOrEqual(-10);
+        expect(value).toBeLessThanOrEqual(10);
+      });
+      expect(result.values).toContain(-10);
+      expect(result.values).toContain(10);
+    });
+
+    it('decodes negative values across the full i32 range', () => {
+      const result = seed.nextInt32sBulkBuffer(1000);
+
+      result.values.forEach((value) => {
+        expect(value).toBeGreaterThanOrEqual(-(2 ** 31));
+        expect(value).toBeLessThanOrEqual(2 ** 31 - 1);
+      });
+      expect(result.values.some((value) => value < 0)).toBe(true);
+      expect(result.values.some((value) => value > 0)).toBe(true);
+    });
+
+    it('accepts the i32 limits as bounds', () => {
+      const result = seed.nextInt32sBulkBuffer(100, {
+        min: -(2 ** 31),
+        max: -(2 ** 31) + 1,
+      });
+
+      result.values.forEach((value) => {
+        expect([-(2 ** 31), -(2 ** 31) + 1]).toContain(value);
+      });
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(321);
+      const range = { min: -1000, max: 1000 };
+
+      const result1 = testSeed.nextInt32sBulkBuffer(50, range);
+      const result2 = testSeed.nextInt32sBulkBuffer(50, range);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+
+    it('rejects inverted and out-of-range bounds', () => {
+      expect(() => {
+        seed.nextInt32sBulkBuffer(10, { min: 5, max: -5 });
+      }).toThrow(/Int32 buffer operation failed/);
+      expect(() => {
+        seed.nextInt32sBulkBuffer(10, { min: 0, max: 2 ** 31 });
+      }).toThrow(/Int32 buffer operation failed/);
+    });
+  });
+
+  describe('nextInt64sBulkBuffer()', () => {
+    it('generates values within an inclusive range, both ends included', () => {
+      const count = 1000;
+      const result = seed.nextInt64sBulkBuffer(count, { min: -3n, max: 3n });
+
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(typeof value).toBe('bigint');
+        expect(value).toBeGreaterThanOrEqual(-3n);
+        expect(value).toBeLessThanOrEqual(3n);
+      });
+      expect(result.values).toContain(-3n);
+      expect(result.values).toContain(3n);
+    });
+
+    it('decodes negative values across the full i64 range', () => {
+      const result = seed.nextInt64sBulkBuffer(1000);
+
+      result.values.forEach((value) => {
+        expect(value).toBeGreaterThanOrEqual(-(2n ** 63n));
+        expect(value).toBeLessThan(2n ** 63n);
+      });
+      expect(result.values.some((value) => value < 0n)).toBe(true);
+      expect(result.values.some((value) => value > 0n)).toBe(true);
+    });
+
+    it('accepts the i64 limits as bounds', () => {
+      const min = -(2n ** 63n);
+      const max = 2n ** 63n - 1n;
+      const low = seed.nextInt64sBulkBuffer(100, { min, max: min + 1n });
+      const high = seed.nextInt64sBulkBuffer(100, { min: max - 1n, max });
+
+      low.values.forEach((value) => {
+        expect([min, min + 1n]).toContain(value);
+      });
+      high.values.forEach((value) => {
+        expect([max - 1n, max]).toContain(value);
+      });
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(654);
+
+      const result1 = testSeed.nextInt64sBulkBuffer(50);
+      const result2 = testSeed.nextInt64sBulkBuffer(50);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+
+    it('rejects an inverted range', () => {
+      expect(() => {
+        seed.nextInt64sBulkBuffer(10, { min: 1n, max: -1n });
+      }).toThrow(/Int64 buffer operation failed/);
+    });
+  });
+
+  describe('nextFloat32sBulkBuffer()', () => {
+    it('generates single-precision floats in [0, 1) range', () => {
+      const count = 1000;
+      const result = seed.nextFloat32sBulkBuffer(count);
+
+      expect(result.values).toBeInstanceOf(Float32Array);
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(value).toBeGreaterThanOrEqual(0);
+        expect(value).toBeLessThan(1);
+        expect(Math.fround(value)).toBe(value);
+      });
+    });
+
+    it('produces different values with different seeds', () => {
+      const result1 = Seed.fromNumber(1).nextFloat32sBulkBuffer(50);
+      const result2 = Seed.fromNumber(2).nextFloat32sBulkBuffer(50);
+
+      expect(result1.values).not.toEqual(result2.values);
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(456);
+
+      const result1 = testSeed.nextFloat32sBulkBuffer(100);
+      const result2 = testSeed.nextFloat32sBulkBuffer(100);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+  });
+
+  describe('nextUint16sBulkBuffer()', () => {
+    it('enforces the bound', () => {
+      const bound = 1000;
+      const count = 1000;
+      const result = seed.nextUint16sBulkBuffer(count, bound);
+
+      expect(result.values).toBeInstanceOf(Uint16Array);
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(value).toBeLessThan(bound);
+      });
+    });
+
+    it('covers the full u16 range without a bound', () => {
+      const result = seed.nextUint16sBulkBuffer(1000);
+
+      expect(result.values.some((value) => value > 0xff)).toBe(true);
+      expect(
+        seed.nextUint16sBulkBuffer(100, 2 ** 16).values.some((v) => v > 0xff)
+      ).toBe(true);
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(16);
+
+      const result1 = testSeed.nextUint16sBulkBuffer(50, 500);
+      const result2 = testSeed.nextUint16sBulkBuffer(50, 500);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+
+    it('rejects bounds beyond the 16-bit range', () => {
+      expect(() => {
+        seed.nextUint16sBulkBuffer(10, 2 ** 16 + 1);
+      }).toThrow(/Uint16 buffer operation failed/);
+    });
+  });
+
+  describe('nextUint128sBulkBuffer()', () => {
+    it('generates 128-bit integers', () => {
+      const count = 500;
+      const result = seed.nextUint128sBulkBuffer(count);
+
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(value).toBeGreaterThanOrEqual(0n);
+        expect(value).toBeLessThan(2n ** 128n);
+      });
+      expect(result.values.some((value) => value >= 2n ** 64n)).toBe(true);
+    });
+
+    it('reconstructs each value from the low then the high u64', () => {
+      const testSeed = Seed.fromNumber(128);
+      const wide = testSeed.nextUint128sBulkBuffer(20);
+      const halves = testSeed.nextUint64sBulkBuffer(40);
+
+      wide.values.forEach((value, i) => {
+        expect(value & (2n ** 64n - 1n)).toBe(halves.values[2 * i]);
+        expect(value >> 64n).toBe(halves.values[2 * i + 1]);
+      });
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(999);
+
+      const result1 = testSeed.nextUint128sBulkBuffer(50);
+      const result2 = testSeed.nextUint128sBulkBuffer(50);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+  });
+
+  describe('nextCharsBulkBuffer()', () => {
+    const isSurrogate = (code: number) => code >= 0xd800 && code <= 0xdfff;
+
+    it('generates Unicode scalar values, never surrogates', () => {
+      const count = 5000;
+      const result = seed.nextCharsBulkBuffer(count);
+
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(value).toBeLessThanOrEqual(0x10ffff);
+        expect(isSurrogate(value)).toBe(false);
+        expect(() => String.fromCodePoint(value)).not.toThrow();
+      });
+      expect(result.values.some((value) => value > 0xffff)).toBe(true);
+    });
+
+    it('stays in the BMP, still without surrogates, with bmpOnly', () => {
+      const result = seed.nextCharsBulkBuffer(5000, true);
+
+      result.values.forEach((value) => {
+        expect(value).toBeLessThan(0x10000);
+        expect(isSurrogate(value)).toBe(false);
+      });
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(1114111);
+
+      const result1 = testSeed.nextCharsBulkBuffer(50);
+      const result2 = testSeed.nextCharsBulkBuffer(50);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+  });
+
+  describe('nextBoolsPackedBulkBuffer()', () => {
+    const unpack = (bits: Uint8Array, count: number) =>
+      Array.from(
+        { length: count },
+        (_, i) => ((bits[i >> 3] >> (i % 8)) & 1) === 1
+      );
+
+    it('packs eight booleans per byte and zeroes the padding bits', () => {
+      const count = 13;
+      const result = seed.nextBoolsPackedBulkBuffer(count);
+
+      expect(result.bits).toHaveLength(2);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+      expect(result.bits[1] >> 5).toBe(0);
+
+      const all = seed.nextBoolsPackedBulkBuffer(count, 1);
+      expect(Array.from(all.bits)).toEqual([0xff, 0x1f]);
+      expect(unpack(all.bits, count)).toEqual(Array(count).fill(true));
+    });
+
+    it('unpacks to the same booleans as the unpacked API', () => {
+      const testSeed = Seed.fromNumber(8);
+      const count = 29;
+
+      const packed = testSeed.nextBoolsPackedBulkBuffer(count, 0.3);
+      const unpacked = testSeed.nextBoolsBulkBuffer(count, 0.3);
+
+      expect(unpack(packed.bits, count)).toEqual(unpacked.values);
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(789);
+
+      const result1 = testSeed.nextBoolsPackedBulkBuffer(100);
+      const result2 = testSeed.nextBoolsPackedBulkBuffer(100);
+
+      expect(result1.bits).toEqual(result2.bits);
+    });
+
+    it('rejects probabilities outside [0, 1]', () => {
+      expect(() => {
+        seed.nextBoolsPackedBulkBuffer(10, 1.5);
+      }).toThrow(/Packed boolean buffer operation failed/);
+    });
+  });
+
+  describe('nextFloatsInRangeBulkBuffer()', () => {
+    it('generates floats in [min, max)', () => {
+      const count = 1000;
+      const result = seed.nextFloatsInRangeBulkBuffer(count, -5, 5);
+
+      expect(result.values).toBeInstanceOf(Float64Array);
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(value).toBeGreaterThanOrEqual(-5);
+        expect(value).toBeLessThan(5);
+      });
+      expect(result.values.some((value) => value < 0)).toBe(true);
+    });
+
+    it('stays finite for spans wider than Number.MAX_VALUE', () => {
+      const max = Number.MAX_VALUE;
+      const result = seed.nextFloatsInRangeBulkBuffer(1000, -max, max);
+
+      result.values.forEach((value) => {
+        expect(Number.isFinite(value)).toBe(true);
+        expect(value).toBeGreaterThanOrEqual(-max);
+        expect(value).toBeLessThan(max);
+      });
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(260);
+
+      const result1 = testSeed.nextFloatsInRangeBulkBuffer(50, 10, 20);
+      const result2 = testSeed.nextFloatsInRangeBulkBuffer(50, 10, 20);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+
+    it('rejects empty, inverted and non-finite ranges', () => {
+      const attempts: Array<[number, number]> = [
+        [1, 1],
+        [2, 1],
+        [0, Infinity],
+        [NaN, 1],
+      ];
+      for (const [min, max] of attempts) {
+        expect(() => {
+          seed.nextFloatsInRangeBulkBuffer(10, min, max);
+        }).toThrow(/Float range buffer operation failed/);
+      }
+    });
+  });
+
+  describe('nextDecimalsBulkBuffer()', () => {
+    it('scales whole-unit bounds and includes both ends', () => {
+      const count = 5000;
+      const result = seed.nextDecimalsBulkBuffer(count, -1n, 1n, 2);
+
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      // [-1, 1] in whole units is [-100, 100] in cents
+      result.values.forEach((value) => {
+        expect(value).toBeGreaterThanOrEqual(-100n);
+        expect(value).toBeLessThanOrEqual(100n);
+      });
+      expect(result.values).toContain(-100n);
+      expect(result.values).toContain(100n);
+    });
+
+    it('keeps whole units at scale 0', () => {
+      const result = seed.nextDecimalsBulkBuffer(500, 0n, 3n, 0);
+
+      result.values.forEach((value) => {
+        expect([0n, 1n, 2n, 3n]).toContain(value);
+      });
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(263);
+
+      const result1 = testSeed.nextDecimalsBulkBuffer(50, 0n, 100n, 2);
+      const result2 = testSeed.nextDecimalsBulkBuffer(50, 0n, 100n, 2);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+
+    it('rejects scales beyond 18, inverted and overflowing ranges', () => {
+      expect(() => {
+        seed.nextDecimalsBulkBuffer(10, 0n, 1n, 19);
+      }).toThrow(/Decimal buffer operation failed/);
+      expect(() => {
+        seed.nextDecimalsBulkBuffer(10, 1n, 0n, 2);
+      }).toThrow(/Decimal buffer operation failed/);
+      expect(() => {
+        seed.nextDecimalsBulkBuffer(10, 0n, 2n ** 62n, 2);
+      }).toThrow(/Decimal buffer operation failed/);
+    });
+  });
+
+  describe('nextStringsBulkBuffer()', () => {
+    const alphabets = [
+      {
+        name: 'printable',
+        test: (code: number) => code >= 0x20 && code < 0x7f,
+      },
+      { name: 'ascii', test: (code: number) => code < 0x80 },
+      { name: 'unicode', test: (code: number) => code <= 0x10ffff },
+    ] as const;
+
+    for (const alphabet of alphabets) {
+      it(`keeps ${alphabet.name} strings within the length bounds`, () => {
+        const count = 200;
+        const result = seed.nextStringsBulkBuffer(count, 2, 6, alphabet.name);
+
+        expect(result.values).toHaveLength(count);
+        expect(result.finalSeed).toBeInstanceOf(Seed);
+
+        result.values.forEach((value) => {
+          // Lengths count characters, not UTF-16 units or bytes
+          const chars = [...value];
+          expect(chars.length).toBeGreaterThanOrEqual(2);
+          expect(chars.length).toBeLessThanOrEqual(6);
+          chars.forEach((char) => {
+            expect(alphabet.test(char.codePointAt(0) ?? -1)).toBe(true);
+          });
+        });
+      });
+    }
+
+    it('decodes multi-byte UTF-8 into well-formed strings', () => {
+      const result = seed.nextStringsBulkBuffer(500, 1, 8, 'unicode');
+      const encoder = new TextEncoder();
+      const decoder = new TextDecoder('utf-8', { fatal: true });
+
+      // Lone surrogates would not survive the round trip
+      result.values.forEach((value) => {
+        expect(decoder.decode(encoder.encode(value))).toBe(value);
+      });
+      expect(
+        result.values.some(
+          (value) => encoder.encode(value).length > [...value].length
+        )
+      ).toBe(true);
+    });
+
+    it('honours equal bounds, including empty strings', () => {
+      expect(seed.nextStringsBulkBuffer(10, 0, 0).values).toEqual(
+        Array(10).fill('')
+      );
+      seed.nextStringsBulkBuffer(10, 3, 3).values.forEach((value) => {
+        expect(value).toHaveLength(3);
+      });
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(265);
+
+      const result1 = testSeed.nextStringsBulkBuffer(50, 0, 10, 'unicode');
+      const result2 = testSeed.nextStringsBulkBuffer(50, 0, 10, 'unicode');
+
+      expect(result1.values).toEqual(result2.values);
+    });
+
+    it('rejects inverted length bounds', () => {
+      expect(() => {
+        seed.nextStringsBulkBuffer(10, 5, 2);
+      }).toThrow(/String buffer operation failed/);
+    });
+  });
+
+  describe('nextBlobsBulkBuffer()', () => {
+    it('keeps blob lengths within the bounds', () => {
+      const count = 500;
+      const result = seed.nextBlobsBulkBuffer(count, 3, 9);
+
+      expect(result.values).toHaveLength(count);
+      expect(result.finalSeed).toBeInstanceOf(Seed);
+
+      result.values.forEach((value) => {
+        expect(value).toBeInstanceOf(Uint8Array);
+        expect(value.length).toBeGreaterThanOrEqual(3);
+        expect(value.length).toBeLessThanOrEqual(9);
+      });
+      const lengths = new Set(result.values.map((value) => value.length));
+      expect(lengths.has(3)).toBe(true);
+      expect(lengths.has(9)).toBe(true);
+    });
+
+    it('honours equal bounds, including empty blobs', () => {
+      seed.nextBlobsBulkBuffer(10, 0, 0).values.forEach((value) => {
+        expect(value).toHaveLength(0);
+      });
+      seed.nextBlobsBulkBuffer(10, 16, 16).values.forEach((value) => {
+        expect(value).toHaveLength(16);
+      });
+    });
+
+    it('is deterministic with same seed', () => {
+      const testSeed = Seed.fromNumber(266);
+
+      const result1 = testSeed.nextBlobsBulkBuffer(50, 0, 32);
+      const result2 = testSeed.nextBlobsBulkBuffer(50, 0, 32);
+
+      expect(result1.values).toEqual(result2.values);
+    });
+
+    it('rejects inverted length bounds', () => {
+      expect(() => {
+        seed.nextBlobsBulkBuffer(10, 5, 2);
+      }).toThrow(/Blob buffer operation failed/);
+    });
   });
 
   describe('Integration with existing bulk API', () => {
@@ -46,6 +46,20 @@ export class Seed implements BulkSeed {
     return this.wasmSeed.gamma;
   }
 
+  /**
+   * Number of draws consumed since this seed's stream was constructed.
+   */
+  get draws(): bigint {
+    return this.wasmSeed.draws;
+  }
+
+  /**
+   * Number of draws consumed since the last split.
+   */
+  get drawsSinceSplit(): bigint {
+    return this.wasmSeed.draws_since_split;
+  }
+
   nextBounded(bound: number): [number, Seed] {
     if (
       bound === undefined ||
@@ -217,9 +231,485 @@ export class Seed implements BulkSeed {
   }
 
   /**
-   * Generate bulk random booleans using buffer API.
+   * Generate bulk random floats uniformly in [min, max) using buffer API.
+   * The range is applied in WASM, so negative ranges and spans wider than
+   * Number.MAX_VALUE keep full precision without rescaling in JS.
+   */
+  nextFloatsInRangeBulkBuffer(
+    count: number,
+    min: number,
+    max: number
+  ): {
+    values: Float64Array;
+    finalSeed: BulkSeed;
+  } {
+    const format = 1; // DataFormat::F64LE
+    const headerSize = 9;
+    const bytesPerElement = 8;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer_with_params(
+        buffer,
+        format,
+        BigInt(count),
+        new wasm.FillParams().with_float_range(min, max)
+      );
+
+      // Extract values
+      const values = new Float64Array(count);
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        values[i] = dataView.getFloat64(i * bytesPerElement, true);
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Float range buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random single-precision floats in [0, 1) range using
+   * buffer API. Values are returned as a Float32Array view of the data.
+   */
+  nextFloat32sBulkBuffer(count: number): {
+    values: Float32Array;
+    finalSeed: BulkSeed;
+  } {
+    const format = 6; // DataFormat::F32LE
+    const headerSize = 9;
+    const bytesPerElement = 4;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer(
+        buffer,
+        format,
+        BigInt(count),
+        undefined
+      );
+
+      // Extract values
+      const values = new Float32Array(count);
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        values[i] = dataView.getFloat32(i * bytesPerElement, true);
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Float32 buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random 16-bit unsigned integers using buffer API, e.g.
+   * UTF-16 code units. Values are in [0, bound) when a bound (at most
+   * 65536) is given, otherwise the full u16 range.
+   */
+  nextUint16sBulkBuffer(
+    count: number,
+    bound?: number
+  ): {
+    values: Uint16Array;
+    finalSeed: BulkSeed;
+  } {
+    const format = 8; // DataFormat::U16LE
+    const headerSize = 9;
+    const bytesPerElement = 2;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer(
+        buffer,
+        format,
+        BigInt(count),
+        bound
+      );
+
+      // Extract values
+      const values = new Uint16Array(count);
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        values[i] = dataView.getUint16(i * bytesPerElement, true);
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Uint16 buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random 64-bit unsigned integers using buffer API.
+   * Values are in [0, bound) when a bound is given, otherwise the full range.
+   */
+  nextUint64sBulkBuffer(
+    count: number,
+    bound?: bigint
+  ): {
+    values: bigint[];
+    finalSeed: BulkSeed;
+  } {
+    const format = 3; // DataFormat::U64LE
+    const headerSize = 9;
+    const bytesPerElement = 8;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer_with_params(
+        buffer,
+        format,
+        BigInt(count),
+        new wasm.FillParams().with_bound_u64(bound)
+      );
+
+      // Extract values
+      const values: bigint[] = [];
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        values.push(dataView.getBigUint64(i * bytesPerElement, true));
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Uint64 buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random 32-bit signed integers in [min, max] using buffer
+   * API. Without a range the full i32 range is used.
+   */
+  nextInt32sBulkBuffer(
+    count: number,
+    range?: { min: number; max: number }
+  ): {
+    values: number[];
+    finalSeed: BulkSeed;
+  } {
+    const format = 4; // DataFormat::I32LE
+    const headerSize = 9;
+    const bytesPerElement = 4;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+    let params = new wasm.FillParams();
+    if (range !== undefined) {
+      params = params.with_range(BigInt(range.min), BigInt(range.max));
+    }
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer_with_params(
+        buffer,
+        format,
+        BigInt(count),
+        params
+      );
+
+      // Extract values
+      const values: number[] = [];
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        values.push(dataView.getInt32(i * bytesPerElement, true));
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Int32 buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random 64-bit signed integers in [min, max] using buffer
+   * API. Without a range the full i64 range is used.
    */
-  nextBoolsBulkBuffer(count: number): {
+  nextInt64sBulkBuffer(
+    count: number,
+    range?: { min: bigint; max: bigint }
+  ): {
+    values: bigint[];
+    finalSeed: BulkSeed;
+  } {
+    const format = 5; // DataFormat::I64LE
+    const headerSize = 9;
+    const bytesPerElement = 8;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+    let params = new wasm.FillParams();
+    if (range !== undefined) {
+      params = params.with_range(range.min, range.max);
+    }
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer_with_params(
+        buffer,
+        format,
+        BigInt(count),
+        params
+      );
+
+      // Extract values
+      const values: bigint[] = [];
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        values.push(dataView.getBigInt64(i * bytesPerElement, true));
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Int64 buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random 128-bit unsigned integers using buffer API, e.g.
+   * for ids and hashes.
+   */
+  nextUint128sBulkBuffer(count: number): {
+    values: bigint[];
+    finalSeed: BulkSeed;
+  } {
+    const format = 9; // DataFormat::U128LE
+    const headerSize = 9;
+    const bytesPerElement = 16;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer(
+        buffer,
+        format,
+        BigInt(count),
+        undefined
+      );
+
+      // Extract values: low u64 then high u64
+      const values: bigint[] = [];
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        const lo = dataView.getBigUint64(i * bytesPerElement, true);
+        const hi = dataView.getBigUint64(i * bytesPerElement + 8, true);
+        values.push((hi << 64n) | lo);
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Uint128 buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random Unicode code points using buffer API. Every value
+   * is a scalar value (never a surrogate), so it can be passed straight to
+   * `String.fromCodePoint`. With `bmpOnly`, values stay below 0x10000.
+   */
+  nextCharsBulkBuffer(
+    count: number,
+    bmpOnly: boolean = false
+  ): {
+    values: Uint32Array;
+    finalSeed: BulkSeed;
+  } {
+    const format = 10; // DataFormat::CharU32
+    const headerSize = 9;
+    const bytesPerElement = 4;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer_with_params(
+        buffer,
+        format,
+        BigInt(count),
+        new wasm.FillParams().with_bmp_only(bmpOnly)
+      );
+
+      // Extract values
+      const values = new Uint32Array(count);
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        values[i] = dataView.getUint32(i * bytesPerElement, true);
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Char buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random strings using buffer API, each of minLength to
+   * maxLength characters drawn from printable ASCII, all of ASCII or every
+   * Unicode scalar value.
+   */
+  nextStringsBulkBuffer(
+    count: number,
+    minLength: number,
+    maxLength: number,
+    alphabet: 'printable' | 'ascii' | 'unicode' = 'printable'
+  ): {
+    values: string[];
+    finalSeed: BulkSeed;
+  } {
+    const headerSize = 9;
+    const alphabetSize = 1;
+    const lengthPrefix = 4;
+    const params = new wasm.StringParams()
+      .with_alphabet(
+        alphabet === 'unicode'
+          ? wasm.StringAlphabet.Unicode
+          : alphabet === 'ascii'
+            ? wasm.StringAlphabet.Ascii
+            : wasm.StringAlphabet.PrintableAscii
+      )
+      .with_length(minLength, maxLength);
+
+    try {
+      const bufferSize = Number(params.buffer_size(BigInt(count)));
+      const buffer = new Uint8Array(bufferSize);
+      const finalSeed = this.wasmSeed.fill_strings(
+        buffer,
+        BigInt(count),
+        params
+      );
+
+      // Extract values
+      const values: string[] = [];
+      const dataView = new DataView(buffer.buffer);
+      const decoder = new TextDecoder();
+      let offset = headerSize + alphabetSize;
+
+      for (let i = 0; i < count; i++) {
+        const length = dataView.getUint32(offset, true);
+        const start = offset + lengthPrefix;
+        values.push(decoder.decode(buffer.subarray(start, start + length)));
+        offset = start + length;
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`String buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random byte arrays using buffer API, each of minLength to
+   * maxLength bytes, e.g. for fuzz-style inputs.
+   */
+  nextBlobsBulkBuffer(
+    count: number,
+    minLength: number,
+    maxLength: number
+  ): {
+    values: Uint8Array[];
+    finalSeed: BulkSeed;
+  } {
+    const headerSize = 9;
+    const lengthPrefix = 4;
+
+    try {
+      const bufferSize = Number(
+        wasm.blobs_buffer_size(BigInt(count), maxLength)
+      );
+      const buffer = new Uint8Array(bufferSize);
+      const finalSeed = this.wasmSeed.fill_blobs(
+        buffer,
+        BigInt(count),
+        minLength,
+        maxLength
+      );
+
+      // Extract values
+      const values: Uint8Array[] = [];
+      const dataView = new DataView(buffer.buffer);
+      let offset = headerSize;
+
+      for (let i = 0; i < count; i++) {
+        const length = dataView.getUint32(offset, true);
+        const start = offset + lengthPrefix;
+        values.push(buffer.slice(start, start + length));
+        offset = start + length;
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Blob buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random fixed-point decimals using buffer API, as integer
+   * counts of 10^-scale units (e.g. cents at scale 2) in the inclusive
+   * range [min, max] given in whole units. Values avoid float rounding.
+   */
+  nextDecimalsBulkBuffer(
+    count: number,
+    min: bigint,
+    max: bigint,
+    scale: number
+  ): {
+    values: bigint[];
+    finalSeed: BulkSeed;
+  } {
+    const format = 20; // DataFormat::DecimalI64
+    const headerSize = 9;
+    const bytesPerElement = 8;
+    const bufferSize = headerSize + count * bytesPerElement;
+
+    const buffer = new Uint8Array(bufferSize);
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer_with_params(
+        buffer,
+        format,
+        BigInt(count),
+        new wasm.FillParams().with_range(min, max).with_scale(scale)
+      );
+
+      // Extract values
+      const values: bigint[] = [];
+      const dataView = new DataView(buffer.buffer, headerSize);
+
+      for (let i = 0; i < count; i++) {
+        values.push(dataView.getBigInt64(i * bytesPerElement, true));
+      }
+
+      return { values, finalSeed: new Seed(finalSeed) };
+    } catch (error) {
+      throw new Error(`Decimal buffer operation failed: ${error}`);
+    }
+  }
+
+  /**
+   * Generate bulk random booleans using buffer API. Each value is true with
+   * `probability` when given (e.g. for biased booleans and option
+   * generators), otherwise a fair coin flip.
+   */
+  nextBoolsBulkBuffer(
+    count: number,
+    probability?: number
+  ): {
     values: boolean[];
     finalSeed: BulkSeed;
   } {
@@ -231,11 +721,11 @@ export class Seed implements BulkSeed {
     const buffer = new Uint8Array(bufferSize);
 
     try {
-      const finalSeed = this.wasmSeed.fill_buffer(
+      const finalSeed = this.wasmSeed.fill_buffer_with_params(
         buffer,
         format,
         BigInt(count),
-        undefined
+        Seed.boolParams(probability)
       );
 
       // Extract values
@@ -251,6 +741,48 @@ export class Seed implements BulkSeed {
     }
   }
 
+  /**
+   * Generate bulk random booleans packed eight per byte using buffer API.
+   * Element `i` is bit `i % 8` of `bits[i >> 3]`; padding bits after the
+   * last element are zero. Each bit is set with `probability` when given.
+   */
+  nextBoolsPackedBulkBuffer(
+    count: number,
+    probability?: number
+  ): {
+    bits: Uint8Array;
+    finalSeed: BulkSeed;
+  } {
+    const format = 11; // DataFormat::BoolPacked
+    const headerSize = 9;
+    const bufferSize = Number(wasm.fill_buffer_size(format, BigInt(count)));
+
+    const buffer = new Uint8Array(bufferSize);
+
+    try {
+      const finalSeed = this.wasmSeed.fill_buffer_with_params(
+        buffer,
+        format,
+        BigInt(count),
+        Seed.boolParams(probability)
+      );
+
+      return {
+        bits: buffer.slice(headerSize),
+        finalSeed: new Seed(finalSeed),
+      };
+    } catch (error) {
+      throw new Error(`Packed boolean buffer operation failed: ${error}`);
+    }
+  }
+
+  private static boolParams(probability?: number): wasm.FillParams {
+    const params = new wasm.FillParams();
+    return probability === undefined
+      ? params
+      : params.with_true_probability(probability);
+  }
+
   getPerformanceInfo() {
     return {
       implementation: 'wasm',